@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+mod topic;
+use topic::Counter;
+
+use iceoryx_rs::ClientBuilder;
+use iceoryx_rs::Runtime;
+
+use std::error::Error;
+use std::thread;
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    Runtime::init("client_simple");
+
+    let client =
+        ClientBuilder::<Counter, Counter>::new("Radar", "FrontLeft", "Increment").create()?;
+
+    let mut counter = 0u32;
+    loop {
+        let mut request = client.loan()?;
+        request.counter = counter;
+        let sequence_id = request.sequence_id();
+        match client.send(request) {
+            Ok(()) => println!("Sending request: {}", counter),
+            Err(e) => println!("Could not send request: {}", e),
+        }
+
+        thread::sleep(Duration::from_millis(100));
+
+        while let Some(response) = client.take_response() {
+            if response.sequence_id() == sequence_id {
+                println!("Receiving response: {}", response.counter);
+                counter = response.counter;
+            }
+        }
+
+        thread::sleep(Duration::from_millis(900));
+    }
+}
@@ -7,10 +7,12 @@ use topic::Counter;
 
 use iceoryx_rs::PublisherBuilder;
 use iceoryx_rs::Runtime;
+use iceoryx_rs::SampleWriter;
 
 use bytes::BufMut;
 
 use std::error::Error;
+use std::io::Write;
 use std::thread;
 use std::time::Duration;
 
@@ -31,16 +33,15 @@ fn main() -> Result<(), Box<dyn Error>> {
                 sample.as_mut().put_u32_le(counter);
                 sample
             }
-            // with uninitialized slice
+            // with uninitialized slice and a writer
             1 => {
-                let mut sample = publisher.loan_uninit_slice_with_alignment(
+                let sample = publisher.loan_uninit_slice_with_alignment(
                     std::mem::size_of::<u32>(),
                     std::mem::align_of::<u32>(),
                 )?;
-                unsafe {
-                    sample.slice_assume_init_mut().put_u32_le(counter);
-                    sample.assume_init()
-                }
+                let mut writer = SampleWriter::new(sample);
+                writer.write_all(&counter.to_le_bytes())?;
+                writer.into_sample()
             }
             // transmute to concrete type
             2 => {
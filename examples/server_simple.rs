@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+mod topic;
+use topic::Counter;
+
+use iceoryx_rs::Runtime;
+use iceoryx_rs::ServerBuilder;
+
+use std::error::Error;
+use std::thread;
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    Runtime::init("server_simple");
+
+    let server =
+        ServerBuilder::<Counter, Counter>::new("Radar", "FrontLeft", "Increment").create()?;
+
+    loop {
+        if server.has_requests() {
+            while let Some(request) = server.take_request() {
+                println!("Receiving request: {}", request.counter);
+
+                let mut response = server.loan_response(&request)?;
+                response.counter = request.counter + 1;
+                server.send(response)?;
+            }
+        } else {
+            thread::sleep(Duration::from_millis(100));
+        }
+    }
+}
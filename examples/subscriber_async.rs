@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+mod topic;
+use topic::Counter;
+
+use iceoryx_rs::Runtime;
+use iceoryx_rs::SubscriberBuilder;
+
+use futures::executor::block_on;
+
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    Runtime::init("subscriber_async");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<Counter>::new("Radar", "FrontLeft", "Counter")
+            .queue_capacity(5)
+            .create_mt()?;
+
+    let mut sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    block_on(async {
+        while let Some(sample) = sample_receiver.next().await {
+            println!("Receiving: {}", sample.counter);
+        }
+    });
+
+    subscriber.unsubscribe(sample_receiver);
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+mod topic;
+use topic::Counter;
+
+use iceoryx_rs::Listener;
+use iceoryx_rs::Runtime;
+use iceoryx_rs::SubscriberBuilder;
+
+use std::error::Error;
+use std::thread;
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    Runtime::init("subscriber_listener");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<Counter>::new("Radar", "FrontLeft", "Counter")
+            .queue_capacity(5)
+            .create_mt()?;
+
+    let listener = Listener::new();
+    let _attachment =
+        listener.attach_subscriber(&subscriber, sample_receive_token, |sample_receiver| {
+            while let Some(sample) = sample_receiver.take() {
+                println!("Receiving: {}", sample.counter);
+            }
+        })?;
+
+    loop {
+        thread::sleep(Duration::from_secs(1));
+    }
+}
@@ -30,12 +30,12 @@ fn main() -> Result<(), Box<dyn Error>> {
             while let Some(sample) = sample_receiver.take() {
                 counter = match counter % 2 {
                     // as buffer
-                    0 => sample.as_ref().get_u32_le(),
+                    0 => (&sample[..]).get_u32_le(),
                     // transmute to concrete type
                     1 => unsafe { sample.try_as::<Counter>().expect("Valid data").counter },
                     _ => unreachable!(),
                 };
-                println!("Receiving: {}", sample.as_ref().get_u32_le());
+                println!("Receiving: {}", (&sample[..]).get_u32_le());
             }
         } else {
             thread::sleep(Duration::from_millis(100));
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+mod topic;
+use topic::Counter;
+
+use iceoryx_rs::st::WaitSet;
+use iceoryx_rs::Runtime;
+use iceoryx_rs::SubscriberBuilder;
+
+use std::error::Error;
+
+const FRONT_LEFT: u64 = 0;
+const FRONT_RIGHT: u64 = 1;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    Runtime::init("subscriber_wait_set");
+
+    let (front_left, front_left_token) =
+        SubscriberBuilder::<Counter>::new("Radar", "FrontLeft", "Counter")
+            .queue_capacity(5)
+            .create()?;
+    let (front_right, front_right_token) =
+        SubscriberBuilder::<Counter>::new("Radar", "FrontRight", "Counter")
+            .queue_capacity(5)
+            .create()?;
+
+    let front_left_receiver = front_left.get_sample_receiver(front_left_token);
+    let front_right_receiver = front_right.get_sample_receiver(front_right_token);
+
+    let wait_set = WaitSet::new();
+    let _front_left_attachment =
+        wait_set.attach_sample_receiver(&front_left_receiver, FRONT_LEFT)?;
+    let _front_right_attachment =
+        wait_set.attach_sample_receiver(&front_right_receiver, FRONT_RIGHT)?;
+
+    loop {
+        for notification_id in wait_set.wait() {
+            let (name, sample_receiver) = match notification_id {
+                FRONT_LEFT => ("FrontLeft", &front_left_receiver),
+                FRONT_RIGHT => ("FrontRight", &front_right_receiver),
+                _ => unreachable!(),
+            };
+
+            while let Some(sample) = sample_receiver.take() {
+                println!("Receiving from {}: {}", name, sample.counter);
+            }
+        }
+    }
+}
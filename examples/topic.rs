@@ -5,9 +5,7 @@
 use iceoryx_rs::marker::ShmSend;
 
 #[repr(C)]
-#[derive(Default)]
+#[derive(Default, ShmSend)]
 pub struct Counter {
     pub counter: u32,
 }
-
-unsafe impl ShmSend for Counter {}
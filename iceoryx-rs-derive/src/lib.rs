@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+//! Derive macros for [iceoryx-rs](https://crates.io/crates/iceoryx-rs)
+//!
+//! The macros are re-exported by `iceoryx-rs` and should be used from there.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Type};
+
+/// Derives the `ShmSend` marker trait after validating the layout of the type
+///
+/// The following requirements are checked:
+/// - the type is `#[repr(C)]` or `#[repr(transparent)]`; field-less enums can also have a primitive representation
+///   like `#[repr(u8)]`
+/// - all fields implement `ShmSend`
+/// - the type does not implement `Drop`
+///
+/// Generic type parameters get a `ShmSend` bound.
+///
+/// The `TYPE_NAME` is the name of the type followed by the `TYPE_NAME`s of the generic type arguments and the values
+/// of the const generic arguments, e.g. `Position<f32>`. The name of the type can be changed with
+/// `#[shm_send(type_name = "...")]`.
+#[proc_macro_derive(ShmSend, attributes(shm_send))]
+pub fn derive_shm_send(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_shm_send(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_shm_send(mut input: DeriveInput) -> Result<TokenStream2, Error> {
+    check_repr(&input)?;
+    let type_name = type_name(&input)?;
+    let type_name = generic_type_name(&type_name, &input.generics);
+    let field_types = field_types(&input.data);
+
+    let shm_send: syn::Path = parse_quote!(::iceoryx_rs::marker::ShmSend);
+    for param in input.generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#shm_send));
+    }
+
+    // the assertions are spanned to the fields to point to the offending field in case of an error
+    let field_assertions = field_types
+        .iter()
+        .map(|ty| quote_spanned!(ty.span()=> assert_shm_send::<#ty>();));
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        unsafe impl #impl_generics #shm_send for #name #ty_generics #where_clause {
+            const TYPE_NAME: &'static str = #type_name;
+        }
+
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_fields_are_shm_send #impl_generics () #where_clause {
+                fn assert_shm_send<T: #shm_send + ?Sized>() {}
+                #(#field_assertions)*
+            }
+
+            // a type implementing `Drop` results in conflicting implementations of this trait
+            #[allow(dead_code)]
+            trait ShmSendMustNotImplementDrop {}
+            #[allow(drop_bounds)]
+            impl<T: ::core::ops::Drop> ShmSendMustNotImplementDrop for T {}
+            impl #impl_generics ShmSendMustNotImplementDrop for #name #ty_generics #where_clause {}
+        };
+    })
+}
+
+fn check_repr(input: &DeriveInput) -> Result<(), Error> {
+    let mut has_valid_repr = false;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let is_field_less_enum = matches!(&input.data, Data::Enum(data)
+                if data.variants.iter().all(|variant| variant.fields.is_empty()));
+            let is_primitive = [
+                "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128",
+                "isize",
+            ]
+            .iter()
+            .any(|primitive| meta.path.is_ident(primitive));
+
+            if meta.path.is_ident("C")
+                || meta.path.is_ident("transparent")
+                || (is_field_less_enum && is_primitive)
+            {
+                has_valid_repr = true;
+            } else if meta.input.peek(syn::token::Paren) {
+                // skip modifiers like `align(8)` or `packed(2)`
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+
+    if has_valid_repr {
+        Ok(())
+    } else {
+        Err(Error::new(
+            input.ident.span(),
+            "ShmSend requires `#[repr(C)]` or `#[repr(transparent)]` for a defined memory layout",
+        ))
+    }
+}
+
+fn type_name(input: &DeriveInput) -> Result<String, Error> {
+    let mut type_name = input.ident.to_string();
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("shm_send"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("type_name") {
+                let name: syn::LitStr = meta.value()?.parse()?;
+                type_name = name.value();
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `shm_send` attribute; expected `type_name`"))
+            }
+        })?;
+    }
+
+    Ok(type_name)
+}
+
+/// Appends the names of the generic arguments to the `type_name` since each instantiation is a different type
+fn generic_type_name(type_name: &str, generics: &syn::Generics) -> TokenStream2 {
+    let arguments = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(param) => {
+                let ident = &param.ident;
+                Some(quote!(.push_type_name(<#ident as ::iceoryx_rs::marker::ShmSend>::TYPE_NAME)))
+            }
+            syn::GenericParam::Const(param) => {
+                let ident = &param.ident;
+                Some(quote!(.push_integer(#ident as i128)))
+            }
+            syn::GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    if arguments.is_empty() {
+        return quote!(#type_name);
+    }
+
+    let builder: syn::Path = parse_quote!(::iceoryx_rs::marker::TypeNameBuilder);
+    let separators = (0..arguments.len()).map(|i| if i == 0 { "<" } else { ", " });
+    quote! {
+        #builder::build(
+            &#builder::new(#type_name)
+                #(.push_str(#separators) #arguments)*
+                .push_str(">")
+        )
+    }
+}
+
+fn field_types(data: &Data) -> Vec<&Type> {
+    match data {
+        Data::Struct(data) => data.fields.iter().map(|field| &field.ty).collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .map(|field| &field.ty)
+            .collect(),
+        Data::Union(data) => data.fields.named.iter().map(|field| &field.ty).collect(),
+    }
+}
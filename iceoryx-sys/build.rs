@@ -4,7 +4,7 @@
 // SPDX-FileContributor: Apex.AI
 
 use std::env;
-use std::io::{Error, ErrorKind};
+use std::io::Error;
 use std::process::Command;
 
 const ICEORYX_VERSION: &str = "v2.0.3";
@@ -22,10 +22,10 @@ fn make_and_install(source_dir: &str, build_dir: &str, install_dir: &str) -> std
             .status()?
             .success()
         {
-            return Err(Error::new(
-                ErrorKind::Other,
-                format!("Could not create build dir for '{}'!", iceoryx_component),
-            ));
+            return Err(Error::other(format!(
+                "Could not create build dir for '{}'!",
+                iceoryx_component
+            )));
         }
 
         if !Command::new("cmake")
@@ -41,10 +41,10 @@ fn make_and_install(source_dir: &str, build_dir: &str, install_dir: &str) -> std
             .status()?
             .success()
         {
-            return Err(Error::new(
-                ErrorKind::Other,
-                format!("Could not run cmake for '{}'!", iceoryx_component),
-            ));
+            return Err(Error::other(format!(
+                "Could not run cmake for '{}'!",
+                iceoryx_component
+            )));
         }
 
         if !Command::new("cmake")
@@ -53,10 +53,10 @@ fn make_and_install(source_dir: &str, build_dir: &str, install_dir: &str) -> std
             .status()?
             .success()
         {
-            return Err(Error::new(
-                ErrorKind::Other,
-                format!("Could not build '{}'!", iceoryx_component),
-            ));
+            return Err(Error::other(format!(
+                "Could not build '{}'!",
+                iceoryx_component
+            )));
         }
     }
 
@@ -69,10 +69,10 @@ fn extract_archive(archive_dir: &str, source_dir: &str, version: &str) -> std::i
         .status()?
         .success()
     {
-        return Err(Error::new(
-            ErrorKind::Other,
-            format!("Could not create source dir for '{}'!", source_dir),
-        ));
+        return Err(Error::other(format!(
+            "Could not create source dir for '{}'!",
+            source_dir
+        )));
     }
 
     if !Command::new("tar")
@@ -86,13 +86,10 @@ fn extract_archive(archive_dir: &str, source_dir: &str, version: &str) -> std::i
         .status()?
         .success()
     {
-        return Err(Error::new(
-            ErrorKind::Other,
-            format!(
-                "Could not extract archive '{}' to '{}'!",
-                version, source_dir
-            ),
-        ));
+        return Err(Error::other(format!(
+            "Could not extract archive '{}' to '{}'!",
+            version, source_dir
+        )));
     }
 
     Ok(())
@@ -8,11 +8,16 @@ cpp! {{
     #include "iceoryx_posh/mepoo/chunk_header.hpp"
 
     using iox::mepoo::ChunkHeader;
+
+    static_assert(alignof(ChunkHeader) == 8, "Adjust 'ChunkHeader::MAX_USER_HEADER_ALIGNMENT' on the Rust side!");
 }}
 
 cpp_class!(pub unsafe struct ChunkHeader as "ChunkHeader");
 
 impl ChunkHeader {
+    /// The maximum alignment of a user header since it is placed directly after the `ChunkHeader`
+    pub const MAX_USER_HEADER_ALIGNMENT: usize = 8;
+
     /// Get a reference to a ChunkHeader
     ///
     /// # Safety
@@ -48,4 +53,43 @@ impl ChunkHeader {
             }) as usize
         }
     }
+
+    pub fn get_user_header_size(&self) -> usize {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ChunkHeader*"] -> u32 as "uint32_t" {
+                return this_ptr->userHeaderSize();
+            }) as usize
+        }
+    }
+
+    /// The unique ID of the publisher which sent the chunk
+    pub fn get_origin_id(&self) -> u64 {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ChunkHeader*"] -> u64 as "uint64_t" {
+                return static_cast<uint64_t>(this_ptr->originId());
+            })
+        }
+    }
+
+    /// The sequence number of the chunk which is incremented by the publisher with each sent chunk
+    pub fn get_sequence_number(&self) -> u64 {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ChunkHeader*"] -> u64 as "uint64_t" {
+                return this_ptr->sequenceNumber();
+            })
+        }
+    }
+
+    /// Returns a ptr to the user header or a null ptr if there is no user header
+    pub fn user_header(&self) -> *const c_void {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ChunkHeader*"] -> *const c_void as "const void*" {
+                return this_ptr->userHeader();
+            })
+        }
+    }
 }
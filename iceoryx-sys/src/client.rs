@@ -0,0 +1,285 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::subscriber::check_chunk;
+use crate::{AllocationError, ChunkTakeError, ClientOptions, RawSample, RawSampleMut};
+
+use std::ffi::{c_void, CString};
+use std::mem::MaybeUninit;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    NotConnected,
+    ConnectRequested,
+    Connected,
+    DisconnectRequested,
+    WaitForOffer,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientSendError {
+    NoConnectRequested,
+    ServerNotAvailable,
+    InvalidRequest,
+}
+
+cpp! {{
+    #include "iceoryx_posh/internal/popo/ports/client_port_user.hpp"
+    #include "iceoryx_posh/runtime/posh_runtime.hpp"
+
+    using iox::ConnectionState;
+    using iox::popo::AllocationError;
+    using iox::capro::IdString_t;
+    using iox::cxx::TruncateToCapacity;
+    using iox::popo::ClientOptions;
+    using iox::popo::ChunkReceiveResult;
+    using iox::popo::ClientPortUser;
+    using iox::popo::ClientSendError;
+    using iox::popo::ConsumerTooSlowPolicy;
+    using iox::popo::QueueFullPolicy;
+    using iox::popo::RequestHeader;
+    using iox::popo::ResponseHeader;
+    using iox::runtime::PoshRuntime;
+}}
+
+cpp_class!(pub unsafe struct Client as "ClientPortUser");
+
+impl Client {
+    pub fn new(
+        service: &str,
+        instance: &str,
+        event: &str,
+        options: &ClientOptions,
+    ) -> Option<Box<Self>> {
+        let service = CString::new(service).expect("CString::new failed");
+        let service = service.as_ptr();
+        let instance = CString::new(instance).expect("CString::new failed");
+        let instance = instance.as_ptr();
+        let event = CString::new(event).expect("CString::new failed");
+        let event = event.as_ptr();
+        let response_queue_capacity = options.response_queue_capacity;
+        let node_name = CString::new(&options.node_name as &str).expect("CString::new failed");
+        let node_name = node_name.as_ptr();
+        let connect_on_create = options.connect_on_create;
+        let response_queue_full_policy = options.response_queue_full_policy as u8;
+        let server_too_slow_policy = options.server_too_slow_policy as u8;
+        unsafe {
+            let raw = cpp!([service as "const char *",
+                            instance as "const char *",
+                            event as "const char *",
+                            response_queue_capacity as "uint64_t",
+                            node_name as "const char *",
+                            connect_on_create as "bool",
+                            response_queue_full_policy as "uint8_t",
+                            server_too_slow_policy as "uint8_t"]
+                            -> *mut Client as "ClientPortUser*"
+            {
+                ClientOptions options;
+                options.responseQueueCapacity = response_queue_capacity;
+                options.nodeName = IdString_t(TruncateToCapacity, node_name);
+                options.connectOnCreate = connect_on_create;
+                options.responseQueueFullPolicy = static_cast<QueueFullPolicy>(response_queue_full_policy);
+                options.serverTooSlowPolicy = static_cast<ConsumerTooSlowPolicy>(server_too_slow_policy);
+                auto portData = PoshRuntime::getInstance().getMiddlewareClient(
+                    {
+                        IdString_t(TruncateToCapacity, service),
+                        IdString_t(TruncateToCapacity, instance),
+                        IdString_t(TruncateToCapacity, event)
+                    },
+                    options
+                );
+                if (portData == nullptr) {
+                    return nullptr;
+                }
+                return new ClientPortUser(*portData);
+            });
+
+            if raw.is_null() {
+                None
+            } else {
+                Some(Box::from_raw(raw))
+            }
+        }
+    }
+
+    pub fn connect(&self) {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ClientPortUser*"] {
+                this_ptr->connect();
+            });
+        }
+    }
+
+    pub fn disconnect(&self) {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ClientPortUser*"] {
+                this_ptr->disconnect();
+            });
+        }
+    }
+
+    pub fn connection_state(&self) -> ConnectionState {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ClientPortUser*"] -> ConnectionState as "ConnectionState" {
+                return this_ptr->getConnectionState();
+            })
+        }
+    }
+
+    pub fn try_allocate_request<T>(&self) -> Result<RawSampleMut<MaybeUninit<T>>, AllocationError> {
+        let size = std::mem::size_of::<T>() as u32;
+        let align = std::mem::align_of::<T>() as u32;
+        unsafe {
+            self.try_allocate_request_chunk(size, align)
+                .map(|payload| payload.cast::<MaybeUninit<T>>())
+        }
+    }
+
+    unsafe fn try_allocate_request_chunk(
+        &self,
+        size: u32,
+        align: u32,
+    ) -> Result<RawSampleMut<c_void>, AllocationError> {
+        let this_ptr = self as *const Self;
+        let mut allocation_error = AllocationError::UndefinedError;
+        let allocation_error_ptr = &mut allocation_error as *mut AllocationError;
+        let payload = cpp!([this_ptr as "ClientPortUser*",
+                            size as "uint32_t",
+                            align as "uint32_t",
+                            allocation_error_ptr as "AllocationError*"]
+                            -> *mut std::ffi::c_void as "void*"
+        {
+            auto allocResult = this_ptr->allocateRequest(size, align);
+            if (allocResult.has_error()) {
+                *allocation_error_ptr = allocResult.get_error();
+                return nullptr;
+            } else {
+                return allocResult.value()->getUserPayload();
+            }
+        });
+
+        if !payload.is_null() {
+            Ok(RawSampleMut::new_unchecked(payload))
+        } else {
+            Err(allocation_error)
+        }
+    }
+
+    pub fn release_request<T: ?Sized>(&self, sample: RawSampleMut<T>) {
+        unsafe {
+            let this_ptr = self as *const Self;
+            let payload = sample.cast::<c_void>().as_payload_ptr();
+            cpp!([this_ptr as "ClientPortUser*", payload as "void*"] {
+                auto header = RequestHeader::fromPayload(payload);
+                this_ptr->releaseRequest(header);
+            });
+        }
+    }
+
+    pub fn send_request<T: ?Sized>(&self, sample: RawSampleMut<T>) -> Result<(), ClientSendError> {
+        let payload = sample.cast::<c_void>().as_payload_ptr();
+        let mut send_error = ClientSendError::InvalidRequest;
+        unsafe {
+            let this_ptr = self as *const Self;
+            let send_error_ptr = &mut send_error as *mut ClientSendError;
+            let success = cpp!([this_ptr as "ClientPortUser*", payload as "void*", send_error_ptr as "ClientSendError*"] -> bool as "bool" {
+                auto header = RequestHeader::fromPayload(payload);
+                auto sendResult = this_ptr->sendRequest(header);
+                if (sendResult.has_error()) {
+                    *send_error_ptr = sendResult.get_error();
+                    return false;
+                }
+                return true;
+            });
+
+            if success {
+                Ok(())
+            } else {
+                Err(send_error)
+            }
+        }
+    }
+
+    pub fn has_responses(&self) -> bool {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ClientPortUser*"] -> bool as "bool" {
+                return this_ptr->hasNewResponses();
+            })
+        }
+    }
+
+    pub fn clear_responses(&self) {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ClientPortUser*"] {
+                this_ptr->releaseQueuedResponses();
+            });
+        }
+    }
+
+    /// Takes a response with a `T`
+    ///
+    /// Responses with a mismatching payload are released and the mismatch is returned as error.
+    pub fn try_take_response<T>(&self) -> Result<Option<RawSample<T>>, ChunkTakeError> {
+        unsafe {
+            let payload = match self.try_get_response()? {
+                Some(payload) => payload,
+                None => return Ok(None),
+            };
+
+            let check = check_chunk::<T, ()>(payload.chunk_header(), |payload_size| {
+                payload_size == std::mem::size_of::<T>()
+            });
+            if let Err(e) = check {
+                self.release_response(payload);
+                return Err(e);
+            }
+
+            Ok(Some(payload.cast::<T>()))
+        }
+    }
+
+    unsafe fn try_get_response(&self) -> Result<Option<RawSample<c_void>>, ChunkTakeError> {
+        let this_ptr = self as *const Self;
+        let mut too_many_held_in_parallel = false;
+        let too_many_held_in_parallel_ptr = &mut too_many_held_in_parallel as *mut bool;
+        let payload = cpp!([this_ptr as "ClientPortUser*", too_many_held_in_parallel_ptr as "bool*"] -> *const std::ffi::c_void as "const void*" {
+            auto getResponseResult = this_ptr->getResponse();
+
+            if (getResponseResult.has_error()) {
+                *too_many_held_in_parallel_ptr = getResponseResult.get_error() == ChunkReceiveResult::TOO_MANY_CHUNKS_HELD_IN_PARALLEL;
+                return nullptr;
+            }
+
+            return getResponseResult.value()->getUserPayload();
+        });
+
+        if let Some(payload) = RawSample::new(payload) {
+            return Ok(Some(payload));
+        }
+
+        if too_many_held_in_parallel {
+            Err(ChunkTakeError::TooManyChunksHeldInParallel)
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn release_response<T: ?Sized>(&self, sample: RawSample<T>) {
+        unsafe {
+            let this_ptr = self as *const Self;
+            let payload = sample.cast::<c_void>().as_payload_ptr();
+            cpp!([this_ptr as "ClientPortUser*", payload as "const void*"] {
+                auto header = ResponseHeader::fromPayload(payload);
+                this_ptr->releaseResponse(header);
+            });
+        }
+    }
+}
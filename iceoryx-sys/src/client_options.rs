@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::{ConsumerTooSlowPolicy, QueueFullPolicy};
+
+use std::marker::PhantomData;
+
+pub struct ClientOptions {
+    pub response_queue_capacity: u64,
+    pub node_name: String,
+    pub connect_on_create: bool,
+    pub response_queue_full_policy: QueueFullPolicy,
+    pub server_too_slow_policy: ConsumerTooSlowPolicy,
+    _phantom: PhantomData<()>,
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            response_queue_capacity: 16,
+            node_name: String::new(),
+            connect_on_create: true,
+            response_queue_full_policy: QueueFullPolicy::DiscardOldestData,
+            server_too_slow_policy: ConsumerTooSlowPolicy::DiscardOldestData,
+            _phantom: PhantomData,
+        }
+    }
+}
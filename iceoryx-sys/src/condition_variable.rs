@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+// SPDX-FileContributor: Apex.AI
+
+use std::time::Duration;
+
+cpp! {{
+    #include "iceoryx_posh/internal/popo/building_blocks/condition_listener.hpp"
+    #include "iceoryx_posh/internal/popo/building_blocks/condition_notifier.hpp"
+    #include "iceoryx_posh/internal/popo/building_blocks/condition_variable_data.hpp"
+    #include "iceoryx_posh/runtime/posh_runtime.hpp"
+
+    using iox::popo::ConditionListener;
+    using iox::popo::ConditionNotifier;
+    using iox::runtime::PoshRuntime;
+
+    static_assert(iox::MAX_NUMBER_OF_NOTIFIERS == 256, "Adjust 'ConditionVariable::MAX_NOTIFICATIONS' on the Rust side!");
+
+    class ConditionVariable {
+      public:
+        ConditionVariable()
+          : m_data(*PoshRuntime::getInstance().getMiddlewareConditionVariable())
+        {}
+
+        ~ConditionVariable() {
+            m_data.m_toBeDestroyed.store(true, std::memory_order_relaxed);
+            m_data.m_semaphore.post().or_else([](auto) {
+                iox::LogFatal() << "Could not get ConditionVariableData from RouDi! Terminating!";
+                std::terminate();
+            });
+        }
+
+        void timedWait(const iox::units::Duration& timeToWait) {
+            m_data.m_semaphore.timedWait(timeToWait).or_else([](auto) {
+                iox::LogFatal() << "Could not wait on semaphore! Potentially corrupted! Terminating!";
+                std::terminate();
+            }).value();
+        }
+
+        iox::popo::ConditionVariableData& data() {
+            return m_data;
+        }
+
+      private:
+        iox::popo::ConditionVariableData& m_data;
+    };
+}}
+
+cpp_class!(pub unsafe struct ConditionVariable as "ConditionVariable");
+
+impl ConditionVariable {
+    /// The maximum number of notification indices which can be used with a single condition variable
+    pub const MAX_NOTIFICATIONS: usize = 256;
+
+    pub fn new() -> Box<Self> {
+        unsafe {
+            let raw = cpp!([] -> *mut ConditionVariable as "ConditionVariable*"
+            {
+                return new ConditionVariable;
+            });
+
+            Box::from_raw(raw)
+        }
+    }
+
+    pub fn timed_wait(&self, timeout: Duration) {
+        unsafe {
+            let this_ptr = self as *const Self;
+            let timeout_ns = timeout.as_nanos() as u64;
+            cpp!([this_ptr as "ConditionVariable*", timeout_ns as "uint64_t"] {
+                this_ptr->timedWait(iox::units::Duration::fromNanoseconds(timeout_ns));
+            });
+        }
+    }
+
+    /// Wakes up a waiter and marks the `notification_index` as notified
+    pub fn notify(&self, notification_index: u64) {
+        debug_assert!(
+            (notification_index as usize) < Self::MAX_NOTIFICATIONS,
+            "The notification index must be smaller than 'MAX_NOTIFICATIONS'!"
+        );
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ConditionVariable*", notification_index as "uint64_t"] {
+                ConditionNotifier(this_ptr->data(), notification_index).notify();
+            });
+        }
+    }
+
+    /// Blocks until a notification occurs and returns the notified indices
+    pub fn wait_for_notifications(&self) -> Vec<u64> {
+        self.wait_for_notifications_impl(None)
+    }
+
+    /// Blocks until a notification occurs or the timeout elapsed and returns the notified indices
+    pub fn timed_wait_for_notifications(&self, timeout: Duration) -> Vec<u64> {
+        self.wait_for_notifications_impl(Some(timeout))
+    }
+
+    fn wait_for_notifications_impl(&self, timeout: Option<Duration>) -> Vec<u64> {
+        let mut notifications = Vec::<u64>::with_capacity(Self::MAX_NOTIFICATIONS);
+        unsafe {
+            let this_ptr = self as *const Self;
+            let notifications_ptr = notifications.as_mut_ptr();
+            let has_timeout = timeout.is_some();
+            let timeout_ns = timeout.map_or(0, |timeout| timeout.as_nanos() as u64);
+            let number_of_notifications = cpp!([this_ptr as "ConditionVariable*",
+                                                notifications_ptr as "uint64_t*",
+                                                has_timeout as "bool",
+                                                timeout_ns as "uint64_t"]
+                                                -> usize as "size_t"
+            {
+                ConditionListener listener(this_ptr->data());
+                auto notifications = has_timeout
+                    ? listener.timedWait(iox::units::Duration::fromNanoseconds(timeout_ns))
+                    : listener.wait();
+                for (size_t i = 0; i < notifications.size(); ++i) {
+                    notifications_ptr[i] = notifications[i];
+                }
+                return notifications.size();
+            });
+            notifications.set_len(number_of_notifications);
+        }
+        notifications
+    }
+}
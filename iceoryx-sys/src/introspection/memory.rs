@@ -70,7 +70,7 @@ impl MemorySegment {
         }
     }
 
-    pub fn mempools(&self) -> MemPoolInfoContainer {
+    pub fn mempools(&self) -> MemPoolInfoContainer<'_> {
         MemPoolInfoContainer {
             memory_segment: self,
             mempool_index: 0,
@@ -124,7 +124,7 @@ pub struct MemPoolIntrospectionTopic {
 }
 
 impl MemPoolIntrospectionTopic {
-    pub fn memory_segments(&self) -> MemorySegmentContainer {
+    pub fn memory_segments(&self) -> MemorySegmentContainer<'_> {
         MemorySegmentContainer {
             memory_segments: self,
             segment_index: 0,
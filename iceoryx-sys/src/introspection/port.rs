@@ -165,14 +165,14 @@ pub struct PortIntrospectionTopic {
 }
 
 impl PortIntrospectionTopic {
-    pub fn subscriber_ports(&self) -> SubscriberPortIntrospectionContainer {
+    pub fn subscriber_ports(&self) -> SubscriberPortIntrospectionContainer<'_> {
         SubscriberPortIntrospectionContainer {
             parent: self,
             index: 0,
         }
     }
 
-    pub fn publisher_ports(&self) -> PublisherPortIntrospectionContainer {
+    pub fn publisher_ports(&self) -> PublisherPortIntrospectionContainer<'_> {
         PublisherPortIntrospectionContainer {
             parent: self,
             index: 0,
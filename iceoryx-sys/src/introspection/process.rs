@@ -39,6 +39,18 @@ impl ProcessIntrospectionData {
         }
     }
 
+    pub fn nodes(&self) -> Vec<String> {
+        (0..self.node_count())
+            .filter_map(|index| unsafe {
+                let this_ptr = self as *const Self;
+                let name = cpp!([this_ptr as "const ProcessIntrospectionData*", index as "size_t"] -> *const c_char as "const char*" {
+                    return this_ptr->m_nodes[index].c_str();
+                });
+                CStr::from_ptr(name).to_str().ok().map(|name| name.to_string())
+            })
+            .collect()
+    }
+
     pub fn node_count(&self) -> usize {
         unsafe {
             let this_ptr = self as *const Self;
@@ -62,7 +74,7 @@ pub struct ProcessIntrospectionTopic {
 }
 
 impl ProcessIntrospectionTopic {
-    pub fn processes(&self) -> ProcessIntrospectionContainer {
+    pub fn processes(&self) -> ProcessIntrospectionContainer<'_> {
         ProcessIntrospectionContainer {
             parent: self,
             index: 0,
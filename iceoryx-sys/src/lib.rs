@@ -2,7 +2,7 @@
 // SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
 // SPDX-FileContributor: Mathias Kraus
 
-#![recursion_limit = "256"]
+#![recursion_limit = "512"]
 
 #[macro_use]
 extern crate cpp;
@@ -12,8 +12,31 @@ pub mod introspection;
 mod chunk_header;
 pub use chunk_header::ChunkHeader;
 
+mod client;
+pub use client::Client;
+pub use client::ClientSendError;
+pub use client::ConnectionState;
+
+mod client_options;
+pub use client_options::ClientOptions;
+
+mod condition_variable;
+pub use condition_variable::ConditionVariable;
+
+mod log;
+pub use log::set_log_level;
+pub use log::set_log_sink;
+pub use log::LogLevel;
+pub use log::LogSink;
+pub use log::LogSinkError;
+
+mod node;
+pub use node::Node;
+
 mod publisher;
+pub use publisher::AllocationError;
 pub use publisher::Publisher;
+pub use publisher::PublisherArc;
 
 mod publisher_options;
 pub use publisher_options::PublisherOptions;
@@ -22,15 +45,38 @@ mod queue_policy;
 pub use queue_policy::ConsumerTooSlowPolicy;
 pub use queue_policy::QueueFullPolicy;
 
+mod rpc_header;
+pub use rpc_header::RequestHeader;
+pub use rpc_header::ResponseHeader;
+
+mod roudi;
+pub use roudi::MonitoringMode;
+pub use roudi::RouDi;
+pub use roudi::RouDiConfig;
+pub use roudi::RouDiLaunchError;
+
 mod runtime;
 pub use runtime::Runtime;
+pub use runtime::RuntimeInitError;
 
 mod sample;
 pub use sample::RawSample;
 pub use sample::RawSampleMut;
 
+mod server;
+pub use server::Server;
+pub use server::ServerSendError;
+
+mod server_options;
+pub use server_options::ServerOptions;
+
+mod service_discovery;
+pub use service_discovery::MessagingPattern;
+pub use service_discovery::ServiceDiscovery;
+
 mod subscriber;
-pub use subscriber::ConditionVariable;
+pub use subscriber::ChunkReceiveResult;
+pub use subscriber::ChunkTakeError;
 pub use subscriber::SubscribeState;
 pub use subscriber::Subscriber;
 pub use subscriber::SubscriberArc;
@@ -41,6 +87,7 @@ pub use subscriber::SubscriberWeakRef;
 mod subscriber_options;
 pub use subscriber_options::SubscriberOptions;
 
-// TODO add `testing` feature flag
+#[cfg(feature = "testing")]
 mod roudi_environment;
+#[cfg(feature = "testing")]
 pub use roudi_environment::RouDiEnvironment;
@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::Runtime;
+
+use std::os::raw::c_char;
+use std::sync::OnceLock;
+
+cpp! {{
+    #include "iceoryx_hoofs/log/logcommon.hpp"
+    #include "iceoryx_hoofs/log/logmanager.hpp"
+
+    #include <cstring>
+    #include <iostream>
+    #include <streambuf>
+    #include <string>
+
+    using iox::log::LogLevel;
+    using iox::log::LogLevelColor;
+    using iox::log::LogLevelOutput;
+    using iox::log::LogLevelText;
+    using iox::log::LogManager;
+}}
+
+cpp! {{
+    using LogSinkCallback = void (*)(uint8_t level, const char* message, uint64_t length);
+
+    // the loggers of all iceoryx log contexts write each enabled log entry as a single formatted line to 'std::clog';
+    // this stream buffer replaces the one of 'std::clog', extracts the level and the message from these lines and
+    // forwards them to the callback; other lines are passed through to the original stream buffer
+    class ForwardingStreamBuffer : public std::streambuf {
+      public:
+        ForwardingStreamBuffer(LogSinkCallback callback, std::streambuf* original) noexcept
+            : m_callback(callback)
+            , m_original(original) {
+        }
+
+      protected:
+        int_type overflow(int_type character) override {
+            if (!traits_type::eq_int_type(character, traits_type::eof())) {
+                append(traits_type::to_char_type(character));
+            }
+            return traits_type::not_eof(character);
+        }
+
+        std::streamsize xsputn(const char* characters, std::streamsize count) override {
+            for (std::streamsize i = 0; i < count; ++i) {
+                append(characters[i]);
+            }
+            return count;
+        }
+
+      private:
+        // the loggers are used concurrently and each thread assembles its own line
+        static std::string& line() noexcept {
+            thread_local std::string line;
+            return line;
+        }
+
+        void append(char character) {
+            auto& currentLine = line();
+            currentLine.push_back(character);
+            if (character == '\n') {
+                forward(currentLine);
+                currentLine.clear();
+            }
+        }
+
+        // a line has the form '<gray><timestamp> <level color><level text><reset>: <message>\n'
+        void forward(const std::string& currentLine) {
+            constexpr const char* TIMESTAMP_COLOR{"\033[0;90m"};
+            constexpr const char* SEPARATOR{"\033[m: "};
+            constexpr uint8_t NUMBER_OF_LEVELS{7U};
+
+            if (currentLine.compare(0, std::strlen(TIMESTAMP_COLOR), TIMESTAMP_COLOR) == 0) {
+                for (uint8_t level = 1U; level < NUMBER_OF_LEVELS; ++level) {
+                    auto marker = std::string(LogLevelColor[level]) + LogLevelText[level] + SEPARATOR;
+                    auto position = currentLine.find(marker);
+                    if (position != std::string::npos) {
+                        auto messageStart = position + marker.size();
+                        // the message is forwarded without the line break
+                        m_callback(level, currentLine.data() + messageStart, currentLine.size() - messageStart - 1U);
+                        return;
+                    }
+                }
+            }
+
+            m_original->sputn(currentLine.data(), static_cast<std::streamsize>(currentLine.size()));
+        }
+
+        LogSinkCallback m_callback;
+        std::streambuf* m_original;
+    };
+}}
+
+/// The log level of iceoryx
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// No log output at all
+    Off = 0,
+    /// Errors which lead to the termination of the application
+    Fatal,
+    /// Errors the application can recover from
+    Error,
+    /// Unexpected but non-critical events
+    Warn,
+    /// General information
+    Info,
+    /// Information for debugging
+    Debug,
+    /// Detailed information for debugging
+    Verbose,
+}
+
+impl LogLevel {
+    fn from_u8(level: u8) -> Self {
+        match level {
+            0 => LogLevel::Off,
+            1 => LogLevel::Fatal,
+            2 => LogLevel::Error,
+            3 => LogLevel::Warn,
+            4 => LogLevel::Info,
+            5 => LogLevel::Debug,
+            _ => LogLevel::Verbose,
+        }
+    }
+}
+
+/// Sets the log level of all the iceoryx loggers
+pub fn set_log_level(level: LogLevel) {
+    let level = level as u8;
+    unsafe {
+        cpp!([level as "uint8_t"] {
+            LogManager::GetLogManager().SetDefaultLogLevel(static_cast<LogLevel>(level), LogLevelOutput::kHideLogLevel);
+        });
+    }
+}
+
+/// The function which receives the log messages of iceoryx
+pub type LogSink = fn(LogLevel, &str);
+
+static LOG_SINK: OnceLock<LogSink> = OnceLock::new();
+
+extern "C" fn forward_to_log_sink(level: u8, message: *const c_char, length: u64) {
+    if let Some(sink) = LOG_SINK.get() {
+        // SAFETY: the message is valid for the duration of the call and has the given length
+        let message = unsafe { std::slice::from_raw_parts(message as *const u8, length as usize) };
+        sink(LogLevel::from_u8(level), &String::from_utf8_lossy(message));
+    }
+}
+
+/// The reason why the log sink could not be set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogSinkError {
+    /// A sink was already set
+    AlreadySet,
+    /// The runtime is already initialized and iceoryx might log from other threads
+    RuntimeAlreadyInitialized,
+}
+
+/// Redirects the log output of iceoryx from stderr to the `sink`
+///
+/// The sink can only be set once and only before the runtime is initialized.
+///
+/// The loggers of all iceoryx components write to `std::clog`, whose stream buffer is replaced by one which passes
+/// the level and the message of each log entry to the sink. Other output to `std::clog` is passed through to
+/// stderr.
+pub fn set_log_sink(sink: LogSink) -> Result<(), LogSinkError> {
+    // the runtime cannot be initialized while the lock is held
+    let _lock = Runtime::lock();
+    if Runtime::is_initialized() {
+        return Err(LogSinkError::RuntimeAlreadyInitialized);
+    }
+    LOG_SINK.set(sink).map_err(|_| LogSinkError::AlreadySet)?;
+
+    let callback = forward_to_log_sink as extern "C" fn(u8, *const c_char, u64);
+    unsafe {
+        cpp!([callback as "LogSinkCallback"] {
+            // the stream buffer is leaked since 'std::clog' is used until the process terminates
+            std::clog.rdbuf(new ForwardingStreamBuffer(callback, std::clog.rdbuf()));
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
+cpp! {{
+    #include "iceoryx_posh/internal/runtime/node_data.hpp"
+    #include "iceoryx_posh/internal/runtime/node_property.hpp"
+    #include "iceoryx_posh/runtime/node.hpp"
+    #include "iceoryx_posh/runtime/posh_runtime.hpp"
+
+    using iox::runtime::NodeData;
+    using iox::runtime::NodeProperty;
+
+    // the node of iceoryx cannot be created from the node data, which is required to detect errors
+    class RuntimeNode : public iox::runtime::Node {
+      public:
+        explicit RuntimeNode(NodeData* const data) noexcept
+            : Node(data) {
+        }
+
+        // contrary to `getNodeName` this does not return a copy
+        const char* name() const noexcept {
+            return m_data->m_nodeName.c_str();
+        }
+    };
+}}
+
+cpp_class!(pub unsafe struct Node as "RuntimeNode");
+
+impl Node {
+    /// Registers a node with the name `name` at `RouDi`
+    ///
+    /// Returns `None` if `RouDi` could not create the node, e.g. since the maximum number of nodes is reached.
+    pub fn new(name: &str) -> Option<Box<Self>> {
+        let name = CString::new(name).ok()?;
+        let name = name.as_ptr();
+        unsafe {
+            let raw = cpp!([name as "const char*"] -> *mut Node as "RuntimeNode*" {
+                auto data = iox::runtime::PoshRuntime::getInstance().createNode(
+                    NodeProperty(iox::NodeName_t(iox::cxx::TruncateToCapacity, name), 0U));
+                if (data == nullptr) {
+                    return nullptr;
+                }
+                return new RuntimeNode(data);
+            });
+
+            if raw.is_null() {
+                None
+            } else {
+                Some(Box::from_raw(raw))
+            }
+        }
+    }
+
+    pub fn name(&self) -> Option<String> {
+        unsafe {
+            let this_ptr = self as *const Self;
+            let name = cpp!([this_ptr as "const RuntimeNode*"] -> *const c_char as "const char*" {
+                return this_ptr->name();
+            });
+            CStr::from_ptr(name)
+                .to_str()
+                .map_or(None, |name| Some(name.to_string()))
+        }
+    }
+}
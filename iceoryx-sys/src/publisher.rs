@@ -5,23 +5,62 @@
 use crate::{PublisherOptions, RawSampleMut};
 
 use std::ffi::{c_void, CString};
+use std::fmt;
 use std::mem::MaybeUninit;
+use std::sync::Arc;
+
+pub type PublisherArc = Arc<Box<Publisher>>;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllocationError {
+    UndefinedError,
+    /// There is no mempool at all or no mempool with chunks large enough for the requested size
+    NoMempoolsAvailable,
+    RunningOutOfChunks,
+    TooManyChunksAllocatedInParallel,
+    InvalidParameterForUserPayloadOrUserHeader,
+    InvalidParameterForRequestHeader,
+}
 
 cpp! {{
     #include "iceoryx_posh/internal/popo/ports/publisher_port_user.hpp"
+    #include "iceoryx_posh/internal/roudi/service_registry.hpp"
     #include "iceoryx_posh/runtime/posh_runtime.hpp"
 
     using iox::capro::IdString_t;
     using iox::cxx::TruncateToCapacity;
+    using iox::popo::AllocationError;
     using iox::popo::ConsumerTooSlowPolicy;
     using iox::popo::PublisherOptions;
     using iox::popo::PublisherPortUser;
+    using iox::roudi::ServiceRegistry;
     using iox::runtime::PoshRuntime;
 }}
 
+cpp! {{
+    #include "iceoryx_versions.hpp"
+
+    // the connected subscribers of a publisher are only accessible via the protected members of the base port
+    // and there is no public API to count them; the layout of these members is specific to the iceoryx version
+    static_assert(ICEORYX_VERSION_MAJOR == 2 && ICEORYX_VERSION_MINOR == 0,
+                  "Accessing the members of the publisher port needs to be reviewed for this iceoryx version");
+
+    struct PublisherPortMembers : public iox::popo::BasePort {
+        static const iox::popo::PublisherPortData* of(const PublisherPortUser& port) noexcept {
+            using GetMembers = const iox::popo::BasePortData* (iox::popo::BasePort::*)() const;
+            auto members = (port.*static_cast<GetMembers>(&PublisherPortMembers::getMembers))();
+            return static_cast<const iox::popo::PublisherPortData*>(members);
+        }
+    };
+}}
+
 cpp_class!(pub unsafe struct Publisher as "PublisherPortUser");
 
 impl Publisher {
+    /// The maximum number of publishers which can exist at the same time, i.e. the number of publisher ports of `RouDi`
+    pub const MAX_PUBLISHERS: usize = 512;
+
     pub fn new(
         service: &str,
         instance: &str,
@@ -100,6 +139,36 @@ impl Publisher {
         }
     }
 
+    /// The number of publishers which offer the service of this publisher according to `registry`
+    ///
+    /// # Safety
+    ///
+    /// `registry` must be the payload of a sample of the `ServiceDiscovery/RouDi_ID/ServiceRegistry` topic.
+    pub unsafe fn number_of_offers_in_service_registry(&self, registry: &[u8]) -> u64 {
+        let this_ptr = self as *const Self;
+        let registry_ptr = registry.as_ptr();
+        let registry_size = registry.len();
+        cpp!([this_ptr as "PublisherPortUser*",
+              registry_ptr as "const void*",
+              registry_size as "size_t"]
+              -> u64 as "uint64_t"
+        {
+            if (registry_size < sizeof(ServiceRegistry)) {
+                return 0U;
+            }
+            auto registry = static_cast<const ServiceRegistry*>(registry_ptr);
+            auto service = this_ptr->getCaProServiceDescription();
+            uint64_t numberOfOffers{0U};
+            registry->find(service.getServiceIDString(),
+                           service.getInstanceIDString(),
+                           service.getEventIDString(),
+                           [&](const auto& entry) {
+                               numberOfOffers += entry.publisherCount;
+                           });
+            return numberOfOffers;
+        })
+    }
+
     pub fn has_subscribers(&self) -> bool {
         unsafe {
             let this_ptr = self as *const Self;
@@ -109,40 +178,77 @@ impl Publisher {
         }
     }
 
-    pub fn try_allocate<T>(&self) -> Option<RawSampleMut<MaybeUninit<T>>> {
+    /// The number of subscribers which are connected to the publisher
+    pub fn number_of_subscribers(&self) -> u64 {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "PublisherPortUser*"] -> u64 as "uint64_t" {
+                auto members = PublisherPortMembers::of(*this_ptr);
+                // this is the inter-process lock of the chunk distributor; the public 'hasSubscribers' and
+                // 'sendChunk' take the same lock from the process of the publisher and RouDi takes it when it
+                // connects or disconnects subscribers, therefore it is safe to take it here
+                std::lock_guard<const iox::popo::PublisherPortData::ChunkDistributorData_t> lock(members->m_chunkSenderData);
+                return members->m_chunkSenderData.m_queues.size();
+            })
+        }
+    }
+
+    /// Allocates a chunk for a `T` and a user header `H`
+    ///
+    /// A zero-sized `H` results in a chunk without user header.
+    pub fn try_allocate<T, H>(&self) -> Result<RawSampleMut<MaybeUninit<T>>, AllocationError> {
         let size = std::mem::size_of::<T>() as u32;
         let align = std::mem::align_of::<T>() as u32;
         unsafe {
-            self.try_allocate_chunk(size, align)
+            self.try_allocate_chunk::<H>(size, align)
                 .map(|payload| payload.cast::<MaybeUninit<T>>())
         }
     }
 
-    pub fn try_allocate_slice<T>(
+    /// Allocates a chunk for a slice of `T` and a user header `H`
+    ///
+    /// A zero-sized `H` results in a chunk without user header.
+    pub fn try_allocate_slice<T, H>(
         &self,
         len: u32,
         align: u32,
-    ) -> Option<RawSampleMut<[MaybeUninit<T>]>> {
+    ) -> Result<RawSampleMut<[MaybeUninit<T>]>, AllocationError> {
         unsafe {
             if align < std::mem::align_of::<T>() as u32 {
-                return None;
+                return Err(AllocationError::InvalidParameterForUserPayloadOrUserHeader);
             }
 
             let size = len * std::mem::size_of::<T>() as u32;
-            self.try_allocate_chunk(size, align).map(|payload| {
+            self.try_allocate_chunk::<H>(size, align).map(|payload| {
                 RawSampleMut::slice_from_raw_parts(payload.cast::<MaybeUninit<T>>(), len as usize)
             })
         }
     }
 
-    unsafe fn try_allocate_chunk(&self, size: u32, align: u32) -> Option<RawSampleMut<c_void>> {
+    unsafe fn try_allocate_chunk<H>(
+        &self,
+        size: u32,
+        align: u32,
+    ) -> Result<RawSampleMut<c_void>, AllocationError> {
         let this_ptr = self as *const Self;
-        let payload = cpp!([this_ptr as "PublisherPortUser*", size as "uint32_t", align as "uint32_t"] -> *mut std::ffi::c_void as "void*" {
+        let user_header_size = std::mem::size_of::<H>() as u32;
+        let user_header_align = std::mem::align_of::<H>() as u32;
+        let mut allocation_error = AllocationError::UndefinedError;
+        let allocation_error_ptr = &mut allocation_error as *mut AllocationError;
+        let payload = cpp!([this_ptr as "PublisherPortUser*",
+                            size as "uint32_t",
+                            align as "uint32_t",
+                            user_header_size as "uint32_t",
+                            user_header_align as "uint32_t",
+                            allocation_error_ptr as "AllocationError*"]
+                            -> *mut std::ffi::c_void as "void*"
+        {
             auto allocResult = this_ptr->tryAllocateChunk(size,
                                                           align,
-                                                          iox::CHUNK_NO_USER_HEADER_SIZE,
-                                                          iox::CHUNK_NO_USER_HEADER_ALIGNMENT);
+                                                          user_header_size,
+                                                          user_header_align);
             if (allocResult.has_error()) {
+                *allocation_error_ptr = allocResult.get_error();
                 return nullptr;
             } else {
                 return allocResult.value()->userPayload();
@@ -150,9 +256,9 @@ impl Publisher {
         });
 
         if !payload.is_null() {
-            Some(RawSampleMut::new_unchecked(payload))
+            Ok(RawSampleMut::new_unchecked(payload))
         } else {
-            None
+            Err(allocation_error)
         }
     }
 
@@ -178,3 +284,11 @@ impl Publisher {
         }
     }
 }
+
+impl fmt::Debug for Publisher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Publisher")
+            .field("_opaque", &self._opaque)
+            .finish()
+    }
+}
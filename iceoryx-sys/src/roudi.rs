@@ -0,0 +1,238 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use std::ffi::CString;
+use std::sync::{Mutex, MutexGuard};
+
+cpp! {{
+    #include "iceoryx_hoofs/cxx/generic_raii.hpp"
+    #include "iceoryx_hoofs/posix_wrapper/file_lock.hpp"
+    #include "iceoryx_hoofs/posix_wrapper/posix_access_rights.hpp"
+    #include "iceoryx_posh/iceoryx_posh_config.hpp"
+    #include "iceoryx_posh/internal/log/posh_logging.hpp"
+    #include "iceoryx_posh/internal/roudi/roudi.hpp"
+    #include "iceoryx_posh/roudi/iceoryx_roudi_components.hpp"
+
+    #include <grp.h>
+
+    using iox::RouDiConfig_t;
+    using iox::mepoo::MePooConfig;
+    using iox::posix::FileLock;
+    using iox::posix::FileLockError;
+    using iox::mepoo::SegmentConfig;
+    using iox::posix::PosixGroup;
+    using iox::roudi::IceOryxRouDiComponents;
+    using iox::roudi::MonitoringMode;
+}}
+
+cpp! {{
+    // RouDi with the memory and port management of the iox-roudi daemon; the runtime messages as well as
+    // the monitoring and discovery are processed by background threads of RouDi
+    class EmbeddedRouDi {
+      public:
+        EmbeddedRouDi(const RouDiConfig_t& config, MonitoringMode monitoringMode) noexcept
+            : m_components(config)
+            , m_roudi(m_components.rouDiMemoryManager,
+                      m_components.portManager,
+                      iox::roudi::RouDi::RoudiStartupParameters{monitoringMode, true}) {
+        }
+
+        static bool& isActive() noexcept {
+            static bool isActive{false};
+            return isActive;
+        }
+
+      private:
+        // the first member is destroyed last, i.e. the flag is reset when RouDi is completely shut down
+        iox::cxx::GenericRAII m_activeFlag{[] { isActive() = true; }, [] { isActive() = false; }};
+        IceOryxRouDiComponents m_components;
+        iox::roudi::RouDi m_roudi;
+    };
+}}
+
+/// Serializes the launch and shutdown of the embedded `RouDi`
+static ROUDI_LOCK: Mutex<()> = Mutex::new(());
+
+pub(crate) fn lock() -> MutexGuard<'static, ()> {
+    ROUDI_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Controls whether `RouDi` monitors the registered applications
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitoringMode {
+    /// Applications which stop sending heartbeats are removed and their resources are released
+    On,
+    /// Applications are not monitored and are only removed when they deregister
+    Off,
+}
+
+/// The reason why `RouDi` could not be launched
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouDiLaunchError {
+    /// There is already a `RouDi` running, either in this or in another process
+    AlreadyRunning,
+    /// The file lock which protects the resources of `RouDi` could not be created, e.g. due to missing access rights
+    LockUnavailable,
+}
+
+cpp_class!(pub unsafe struct RouDiConfig as "RouDiConfig_t");
+
+impl RouDiConfig {
+    /// Creates a config with one segment for the group of the current process and the default mempools
+    pub fn with_defaults() -> Self {
+        unsafe {
+            cpp!([] -> RouDiConfig as "RouDiConfig_t" {
+                return RouDiConfig_t().setDefaults();
+            })
+        }
+    }
+
+    /// Adds a shared memory segment which can be read by `reader_group` and written by `writer_group`
+    ///
+    /// `None` uses the group of the current process. Returns `false` if the maximum number of segments is
+    /// reached or a group name is too long.
+    pub fn add_segment(&mut self, reader_group: Option<&str>, writer_group: Option<&str>) -> bool {
+        let Some(reader_group) = Self::group_name(reader_group) else {
+            return false;
+        };
+        let Some(writer_group) = Self::group_name(writer_group) else {
+            return false;
+        };
+        let reader_group = reader_group.as_ptr();
+        let writer_group = writer_group.as_ptr();
+        unsafe {
+            let this_ptr = self as *mut Self;
+            cpp!([this_ptr as "RouDiConfig_t*", reader_group as "const char*", writer_group as "const char*"] -> bool as "bool" {
+                auto groupName = [](const char* name) {
+                    return name[0] == '\0' ? PosixGroup::getGroupOfCurrentProcess().getName()
+                                           : PosixGroup::string_t(iox::cxx::TruncateToCapacity, name);
+                };
+                return this_ptr->m_sharedMemorySegments.emplace_back(groupName(reader_group), groupName(writer_group), MePooConfig());
+            })
+        }
+    }
+
+    /// Adds a mempool with `chunk_count` chunks of `chunk_size` bytes to the last segment
+    ///
+    /// Returns `false` if there is no segment or the maximum number of mempools is reached.
+    pub fn add_mempool(&mut self, chunk_size: u32, chunk_count: u32) -> bool {
+        unsafe {
+            let this_ptr = self as *mut Self;
+            cpp!([this_ptr as "RouDiConfig_t*", chunk_size as "uint32_t", chunk_count as "uint32_t"] -> bool as "bool" {
+                if (this_ptr->m_sharedMemorySegments.empty()) {
+                    return false;
+                }
+                auto& mempools = this_ptr->m_sharedMemorySegments.back().m_mempoolConfig.m_mempoolConfig;
+                return mempools.emplace_back(chunk_size, chunk_count);
+            })
+        }
+    }
+
+    /// Whether a POSIX group with the name `group_name` exists
+    pub fn does_group_exist(group_name: &str) -> bool {
+        let Ok(group_name) = CString::new(group_name) else {
+            return false;
+        };
+        let group_name = group_name.as_ptr();
+        unsafe {
+            cpp!([group_name as "const char*"] -> bool as "bool" {
+                return getgrnam(group_name) != nullptr;
+            })
+        }
+    }
+
+    /// An empty name stands for the group of the current process
+    fn group_name(name: Option<&str>) -> Option<CString> {
+        const MAX_GROUP_NAME_LENGTH: usize = 100;
+        match name {
+            None => Some(CString::default()),
+            Some(name) if name.is_empty() || name.len() > MAX_GROUP_NAME_LENGTH => None,
+            Some(name) => CString::new(name).ok(),
+        }
+    }
+}
+
+cpp_class!(pub unsafe struct RouDi as "EmbeddedRouDi");
+
+impl RouDi {
+    /// Launches `RouDi` in the current process
+    ///
+    /// Fails if a `RouDi` is already running, either in this or in another process, or its file lock cannot be created.
+    pub fn launch(
+        config: &RouDiConfig,
+        monitoring_mode: MonitoringMode,
+    ) -> Result<Box<Self>, RouDiLaunchError> {
+        let _lock = lock();
+
+        if Self::is_embedded() {
+            return Err(RouDiLaunchError::AlreadyRunning);
+        }
+        Self::check_roudi_lock()?;
+
+        unsafe {
+            let config = config as *const RouDiConfig;
+            let raw = cpp!([config as "const RouDiConfig_t*", monitoring_mode as "MonitoringMode"] -> *mut RouDi as "EmbeddedRouDi*" {
+                auto optimizedConfig = *config;
+                optimizedConfig.optimize();
+                return new EmbeddedRouDi(optimizedConfig, monitoring_mode);
+            });
+
+            Ok(Box::from_raw(raw))
+        }
+    }
+
+    /// Shuts down the embedded `RouDi`
+    ///
+    /// The applications which are still registered are terminated.
+    pub fn shutdown(self: Box<Self>) {
+        let _lock = lock();
+        drop(self);
+    }
+
+    /// Leaks the embedded `RouDi` which keeps running until the process terminates
+    ///
+    /// This is used if it cannot be shut down since the shutdown would terminate the registered runtime of this
+    /// process.
+    pub fn leak(self: Box<Self>) {
+        unsafe {
+            cpp!([] {
+                iox::LogWarn() << "RouDi is not shut down since the runtime of this process is still registered!";
+            });
+        }
+        std::mem::forget(self);
+    }
+
+    /// Each `RouDi` holds a file lock to protect its resources; acquiring the lock a second time is fatal
+    ///
+    /// Only a lock held by another `RouDi` means that `RouDi` is already running.
+    pub(crate) fn check_roudi_lock() -> Result<(), RouDiLaunchError> {
+        let lock_state = unsafe {
+            cpp!([] -> u8 as "uint8_t" {
+                auto lock = FileLock::create(iox::roudi::ROUDI_LOCK_NAME);
+                if (!lock.has_error()) {
+                    return 0;
+                }
+                return lock.get_error() == FileLockError::LOCKED_BY_OTHER_PROCESS ? 1 : 2;
+            })
+        };
+
+        match lock_state {
+            0 => Ok(()),
+            1 => Err(RouDiLaunchError::AlreadyRunning),
+            _ => Err(RouDiLaunchError::LockUnavailable),
+        }
+    }
+
+    /// Whether there is a `RouDi` running in the current process which was launched with [`launch`](Self::launch)
+    pub fn is_embedded() -> bool {
+        unsafe {
+            cpp!([] -> bool as "bool" {
+                return EmbeddedRouDi::isActive();
+            })
+        }
+    }
+}
@@ -2,23 +2,134 @@
 // SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
 // SPDX-FileContributor: Mathias Kraus
 
+use crate::roudi::{self, RouDi, RouDiLaunchError};
+use crate::RouDiConfig;
+
+use std::time::Duration;
+
 cpp! {{
+    #include "iceoryx_posh/internal/roudi/roudi.hpp"
+    #include "iceoryx_posh/roudi/iceoryx_roudi_components.hpp"
     #include "iceoryx_posh/testing/roudi_environment/roudi_environment.hpp"
 
+    #include <chrono>
+
     using iox::roudi::RouDiEnvironment;
 }}
 
-cpp_class!(pub unsafe struct RouDiEnvironment as "RouDiEnvironment");
+cpp! {{
+    // RouDi which can run the discovery loop on demand; the process manager is protected by a lock and
+    // therefore the discovery loop does not interfere with the one of the RouDi thread
+    class DiscoveryTriggerRouDi : public iox::roudi::RouDi {
+      public:
+        using RouDi::RouDi;
+
+        void triggerDiscoveryLoop() noexcept {
+            m_prcMgr->run();
+        }
+    };
+
+    // same as the RouDiEnvironment of iceoryx but with access to RouDi
+    class TestRouDiEnvironment : public RouDiEnvironment {
+      public:
+        explicit TestRouDiEnvironment(const iox::RouDiConfig_t& config) noexcept
+            : RouDiEnvironment(BaseCTor::BASE)
+            , m_components(config)
+            , m_roudi(m_components.rouDiMemoryManager,
+                      m_components.portManager,
+                      iox::roudi::RouDi::RoudiStartupParameters{iox::roudi::MonitoringMode::OFF, false}) {
+        }
+
+        ~TestRouDiEnvironment() {
+            // the runtimes must be cleaned up before RouDi is destroyed
+            CleanupRuntimes();
+        }
+
+        void triggerDiscoveryLoop() noexcept {
+            m_roudi.triggerDiscoveryLoop();
+        }
+
+      private:
+        iox::roudi::IceOryxRouDiComponents m_components;
+        DiscoveryTriggerRouDi m_roudi;
+    };
+}}
+
+cpp_class!(pub unsafe struct RouDiEnvironment as "TestRouDiEnvironment");
 
 impl RouDiEnvironment {
+    /// Creates a `RouDi` with the default config for the use in tests
+    ///
+    /// Panics if a `RouDi` is already running, either in this or in another process, or its file lock cannot be created.
     pub fn new() -> Box<Self> {
+        Self::with_config(&RouDiConfig::with_defaults())
+    }
+
+    /// Creates a `RouDi` with the memory layout of `config` for the use in tests
+    ///
+    /// Panics if a `RouDi` is already running, either in this or in another process, or its file lock cannot be created.
+    pub fn with_config(config: &RouDiConfig) -> Box<Self> {
+        match Self::try_with_config(config) {
+            Ok(roudi) => roudi,
+            Err(RouDiLaunchError::AlreadyRunning) => panic!("There is already a RouDi running!"),
+            Err(RouDiLaunchError::LockUnavailable) => {
+                panic!("The file lock of RouDi cannot be created!")
+            }
+        }
+    }
+
+    /// Creates a `RouDi` with the memory layout of `config` for the use in tests
+    ///
+    /// Fails if a `RouDi` is already running, either in this or in another process, or its file lock cannot be created.
+    pub fn try_with_config(config: &RouDiConfig) -> Result<Box<Self>, RouDiLaunchError> {
+        let _lock = roudi::lock();
+
+        if RouDi::is_embedded() {
+            return Err(RouDiLaunchError::AlreadyRunning);
+        }
+        RouDi::check_roudi_lock()?;
+
         unsafe {
-            let raw = cpp!([] -> *mut RouDiEnvironment as "RouDiEnvironment*"
+            let config = config as *const RouDiConfig;
+            let raw = cpp!([config as "const iox::RouDiConfig_t*"] -> *mut RouDiEnvironment as "TestRouDiEnvironment*"
             {
-                return new RouDiEnvironment();
+                auto optimizedConfig = *config;
+                optimizedConfig.optimize();
+                return new TestRouDiEnvironment(optimizedConfig);
             });
 
-            Box::from_raw(raw)
+            Ok(Box::from_raw(raw))
+        }
+    }
+
+    /// Sets the time [`inter_op_wait`](Self::inter_op_wait) waits
+    pub fn set_inter_op_waiting_time(&mut self, waiting_time: Duration) {
+        let waiting_time_ms = waiting_time.as_millis() as u64;
+        unsafe {
+            let this_ptr = self as *mut Self;
+            cpp!([this_ptr as "TestRouDiEnvironment*", waiting_time_ms as "uint64_t"] {
+                this_ptr->SetInterOpWaitingTime(std::chrono::milliseconds(waiting_time_ms));
+            });
+        }
+    }
+
+    /// Waits the time which is needed for `RouDi` to process requests
+    pub fn inter_op_wait(&self) {
+        unsafe {
+            let this_ptr = self as *const Self as *mut Self;
+            cpp!([this_ptr as "TestRouDiEnvironment*"] {
+                this_ptr->InterOpWait();
+            });
+        }
+    }
+
+    /// Runs the discovery loop of `RouDi` and returns when it is finished
+    pub fn trigger_discovery_loop(&self) {
+        unsafe {
+            let this_ptr = self as *const Self as *mut Self;
+            cpp!([this_ptr as "TestRouDiEnvironment*"] {
+                this_ptr->triggerDiscoveryLoop();
+            });
         }
     }
 }
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use std::ffi::c_void;
+
+cpp! {{
+    #include "iceoryx_posh/popo/rpc_header.hpp"
+
+    using iox::popo::RequestHeader;
+    using iox::popo::ResponseHeader;
+}}
+
+cpp_class!(pub unsafe struct RequestHeader as "RequestHeader");
+cpp_class!(pub unsafe struct ResponseHeader as "ResponseHeader");
+
+impl RequestHeader {
+    /// Get a reference to a RequestHeader
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `payload` is non-null and points to the payload of a request
+    pub unsafe fn from_user_payload_unchecked<'a>(payload: *const c_void) -> &'a Self {
+        unsafe {
+            let request_header = cpp!([payload as "void*"] -> *const c_void as "const void*" {
+                return RequestHeader::fromPayload(payload);
+            });
+            debug_assert!(
+                !request_header.is_null(),
+                "The RequestHeader ptr should always be non-null when the payload ptr was non-null!"
+            );
+            &*(request_header.cast::<Self>())
+        }
+    }
+
+    pub fn get_sequence_id(&self) -> i64 {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "const RequestHeader*"] -> i64 as "int64_t" {
+                return this_ptr->getSequenceId();
+            })
+        }
+    }
+
+    pub fn set_sequence_id(&self, sequence_id: i64) {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "RequestHeader*", sequence_id as "int64_t"] {
+                this_ptr->setSequenceId(sequence_id);
+            });
+        }
+    }
+}
+
+impl ResponseHeader {
+    /// Get a reference to a ResponseHeader
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `payload` is non-null and points to the payload of a response
+    pub unsafe fn from_user_payload_unchecked<'a>(payload: *const c_void) -> &'a Self {
+        unsafe {
+            let response_header = cpp!([payload as "void*"] -> *const c_void as "const void*" {
+                return ResponseHeader::fromPayload(payload);
+            });
+            debug_assert!(
+                !response_header.is_null(),
+                "The ResponseHeader ptr should always be non-null when the payload ptr was non-null!"
+            );
+            &*(response_header.cast::<Self>())
+        }
+    }
+
+    pub fn get_sequence_id(&self) -> i64 {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "const ResponseHeader*"] -> i64 as "int64_t" {
+                return this_ptr->getSequenceId();
+            })
+        }
+    }
+
+    pub fn has_server_error(&self) -> bool {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "const ResponseHeader*"] -> bool as "bool" {
+                return this_ptr->hasServerError();
+            })
+        }
+    }
+
+    pub fn set_server_error(&self) {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ResponseHeader*"] {
+                this_ptr->setServerError();
+            });
+        }
+    }
+}
@@ -2,19 +2,105 @@
 // SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
 // SPDX-FileContributor: Mathias Kraus
 
-use std::ffi::CString;
+use crate::LogLevel;
+
+use std::ffi::{CStr, CString};
+use std::sync::{Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
 
 cpp! {{
-    #include "iceoryx_hoofs/log/logmanager.hpp"
+    #include "iceoryx_hoofs/cxx/helplets.hpp"
+    #include "iceoryx_posh/iceoryx_posh_types.hpp"
+    #include "iceoryx_posh/internal/runtime/ipc_interface_user.hpp"
+    #include "iceoryx_posh/internal/runtime/posh_runtime_impl.hpp"
     #include "iceoryx_posh/runtime/posh_runtime.hpp"
 
+    #include <cstring>
+    #include <memory>
+
     using iox::RuntimeName_t;
     using iox::cxx::TruncateToCapacity;
-    using iox::log::LogManager;
-    using iox::log::LogLevelOutput;
+    using iox::runtime::IpcInterfaceUser;
     using iox::runtime::PoshRuntime;
+    using iox::runtime::PoshRuntimeImpl;
+    using iox::runtime::RuntimeLocation;
+}}
+
+cpp! {{
+    // the runtime of the default factory lives until the process terminates; this runtime is installed with
+    // a custom factory and can be destroyed, which deregisters it from RouDi
+    class ManagedRuntime : public PoshRuntimeImpl {
+      public:
+        static bool isDefaultFactoryActive() noexcept {
+            return getRuntimeFactory() == defaultRuntimeFactory;
+        }
+
+        static bool& isDefaultRuntimeInitialized() noexcept {
+            static bool isInitialized{false};
+            return isInitialized;
+        }
+
+        static bool isActive() noexcept {
+            return instance() != nullptr;
+        }
+
+        static void init(const RuntimeName_t& name) noexcept {
+            // with an embedded RouDi or an active custom factory, e.g. the one of the RouDiEnvironment, RouDi runs
+            // in the same process
+            auto location = isDefaultFactoryActive() && !EmbeddedRouDi::isActive()
+                                ? RuntimeLocation::SEPARATE_PROCESS_FROM_ROUDI
+                                : RuntimeLocation::SAME_PROCESS_LIKE_ROUDI;
+            instance().reset(new ManagedRuntime(name, location));
+            previousFactory() = getRuntimeFactory();
+            setRuntimeFactory(managedRuntimeFactory);
+        }
+
+        static void shutdown() noexcept {
+            setRuntimeFactory(previousFactory());
+            instance().reset();
+        }
+
+      private:
+        ManagedRuntime(const RuntimeName_t& name, RuntimeLocation location) noexcept
+            : PoshRuntimeImpl(iox::cxx::make_optional<const RuntimeName_t*>(&name), location) {
+        }
+
+        static std::unique_ptr<ManagedRuntime>& instance() noexcept {
+            static std::unique_ptr<ManagedRuntime> runtime;
+            return runtime;
+        }
+
+        static factory_t& previousFactory() noexcept {
+            static factory_t factory{nullptr};
+            return factory;
+        }
+
+        static PoshRuntime& managedRuntimeFactory(iox::cxx::optional<const RuntimeName_t*>) noexcept {
+            return *instance();
+        }
+    };
 }}
 
+/// The interval to check whether `RouDi` became available
+const ROUDI_POLLING_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Serializes the initialization and shutdown of the runtime
+static RUNTIME_LOCK: Mutex<()> = Mutex::new(());
+
+/// Reasons for a failed registration at `RouDi`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeInitError {
+    /// The name is empty, too long or contains characters which are not allowed in file names
+    InvalidName,
+    /// `RouDi` was not available within the waiting timeout
+    RouDiNotAvailable,
+    /// An application with the same name is currently registered at `RouDi`
+    NameAlreadyRegistered,
+    /// The process is already registered at `RouDi`
+    RuntimeAlreadyInitialized,
+}
+
 /// The runtime that is needed for each application to communicate with the `RouDi` daemon
 pub struct Runtime {}
 
@@ -24,14 +110,173 @@ impl Runtime {
     /// The call to this function is required in order to create publisher and subscriber and must be done early
     /// in the application startup. There cannot be two application with the same `app_name` be registered
     /// simultaneously at `RouDi`.
-    pub fn init(app_name: &str) {
+    ///
+    /// The runtime stays registered until the process terminates. The iceoryx log output is restricted to
+    /// `log_level`.
+    pub fn init(app_name: &str, log_level: LogLevel) {
+        let app_name = CString::new(app_name).expect("CString::new failed");
+        let _lock = Self::lock();
+        crate::log::set_log_level(log_level);
+        let app_name = app_name.as_ptr();
+        unsafe {
+            cpp!([app_name as "const char *"] {
+                // the runtime of the default factory assumes that RouDi runs in a separate process
+                if (EmbeddedRouDi::isActive()) {
+                    ManagedRuntime::init(RuntimeName_t(TruncateToCapacity, app_name));
+                    return;
+                }
+                if (ManagedRuntime::isDefaultFactoryActive()) {
+                    ManagedRuntime::isDefaultRuntimeInitialized() = true;
+                }
+                PoshRuntime::initRuntime(RuntimeName_t(TruncateToCapacity, app_name));
+            });
+        }
+    }
+
+    /// Same as [`init`](Self::init) but checks the preconditions for the registration instead of terminating
+    ///
+    /// The `app_name` is checked for validity, `RouDi` is awaited for at most `roudi_waiting_timeout` and
+    /// it is checked that no other application with the same name is running. If `RouDi` terminates after
+    /// these checks but before the registration is finished, iceoryx will still terminate the application.
+    ///
+    /// Contrary to [`init`](Self::init), the runtime can be deregistered with [`shutdown`](Self::shutdown).
+    /// Only one of these runtimes can exist at a time and not at all when [`init`](Self::init) was already
+    /// called.
+    pub fn try_init(
+        app_name: &str,
+        roudi_waiting_timeout: Duration,
+        log_level: LogLevel,
+    ) -> Result<(), RuntimeInitError> {
+        let app_name = CString::new(app_name).map_err(|_| RuntimeInitError::InvalidName)?;
+        if !Self::is_valid_app_name(&app_name) {
+            return Err(RuntimeInitError::InvalidName);
+        }
+
+        let deadline = Instant::now() + roudi_waiting_timeout;
+        while !Self::is_roudi_available() {
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(RuntimeInitError::RouDiNotAvailable);
+            }
+            thread::sleep(ROUDI_POLLING_INTERVAL.min(deadline - now));
+        }
+
+        let _lock = Self::lock();
+
+        if Self::is_initialized() {
+            return Err(RuntimeInitError::RuntimeAlreadyInitialized);
+        }
+
+        if Self::is_app_running(&app_name) {
+            return Err(RuntimeInitError::NameAlreadyRegistered);
+        }
+
+        crate::log::set_log_level(log_level);
+        let app_name = app_name.as_ptr();
+        unsafe {
+            cpp!([app_name as "const char *"] {
+                ManagedRuntime::init(RuntimeName_t(TruncateToCapacity, app_name));
+            });
+        }
+        Ok(())
+    }
+
+    /// Deregisters the runtime created with [`try_init`](Self::try_init) from `RouDi`
+    ///
+    /// Afterwards, [`try_init`](Self::try_init) can be called again.
+    ///
+    /// # Safety
+    ///
+    /// All ports and condition variables created with the runtime must already be destroyed.
+    pub unsafe fn shutdown() {
+        let _lock = Self::lock();
+        cpp!([] {
+            if (ManagedRuntime::isActive()) {
+                ManagedRuntime::shutdown();
+            }
+        });
+    }
+
+    /// The name of the application which is registered at `RouDi`
+    ///
+    /// The runtime must be initialized.
+    pub fn app_name() -> String {
+        // the capacity of the `RuntimeName_t` and the null terminator
+        let mut app_name = [0u8; 101];
+        let app_name_ptr = app_name.as_mut_ptr();
+        unsafe {
+            cpp!([app_name_ptr as "char*"] {
+                auto appName = PoshRuntime::getInstance().getInstanceName();
+                static_assert(decltype(appName)::capacity() == 100U, "The buffer must fit the app name");
+                std::memcpy(app_name_ptr, appName.c_str(), appName.size() + 1U);
+            });
+        }
+        CStr::from_bytes_until_nul(&app_name)
+            .map(|app_name| app_name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+
+    /// Makes the runtime with `app_name` accessible from the current thread
+    ///
+    /// This is only required for the runtime of the `RouDiEnvironment`, which emulates a process per thread,
+    /// if ports are created in a thread which did not initialize the runtime. For the other runtimes, this
+    /// has no effect.
+    pub fn attach_current_thread(app_name: &str) {
         let app_name = CString::new(app_name).expect("CString::new failed");
         let app_name = app_name.as_ptr();
         unsafe {
             cpp!([app_name as "const char *"] {
-                iox::log::LogManager::GetLogManager().SetDefaultLogLevel(iox::log::LogLevel::kWarn, LogLevelOutput::kHideLogLevel);
                 PoshRuntime::initRuntime(RuntimeName_t(TruncateToCapacity, app_name));
             });
         }
     }
+
+    pub(crate) fn lock() -> MutexGuard<'static, ()> {
+        RUNTIME_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Whether the process is registered at `RouDi`
+    pub fn is_initialized() -> bool {
+        unsafe {
+            cpp!([] -> bool as "bool" {
+                return ManagedRuntime::isActive()
+                    || (ManagedRuntime::isDefaultFactoryActive() && ManagedRuntime::isDefaultRuntimeInitialized());
+            })
+        }
+    }
+
+    fn is_valid_app_name(app_name: &CStr) -> bool {
+        let app_name_length = app_name.to_bytes().len() as u64;
+        let app_name = app_name.as_ptr();
+        unsafe {
+            cpp!([app_name as "const char *", app_name_length as "uint64_t"] -> bool as "bool" {
+                if (app_name_length > RuntimeName_t::capacity()) {
+                    return false;
+                }
+                return iox::cxx::isValidFileName(RuntimeName_t(TruncateToCapacity, app_name));
+            })
+        }
+    }
+
+    fn is_roudi_available() -> bool {
+        unsafe {
+            cpp!([] -> bool as "bool" {
+                IpcInterfaceUser roudiChannel(iox::roudi::IPC_CHANNEL_ROUDI_NAME);
+                return roudiChannel.isInitialized();
+            })
+        }
+    }
+
+    /// An IPC channel can only be opened if the application which created it is still running
+    fn is_app_running(app_name: &CStr) -> bool {
+        let app_name = app_name.as_ptr();
+        unsafe {
+            cpp!([app_name as "const char *"] -> bool as "bool" {
+                IpcInterfaceUser appChannel(RuntimeName_t(TruncateToCapacity, app_name));
+                return appChannel.isInitialized();
+            })
+        }
+    }
 }
@@ -6,7 +6,6 @@ use crate::ChunkHeader;
 
 use std::ffi::c_void;
 use std::fmt;
-use std::slice;
 
 /// A `*const T` non-zero sample pointer to the user payload.
 #[repr(transparent)]
@@ -76,7 +75,7 @@ impl<T> RawSample<[T]> {
     #[inline]
     pub fn slice_from_raw_parts(sample: RawSample<T>, len: usize) -> RawSample<[T]> {
         // SAFETY: `self.as_payload_ptr` returns a non-null ptr
-        unsafe { Self::new_unchecked(slice::from_raw_parts(sample.as_payload_ptr(), len)) }
+        unsafe { Self::new_unchecked(std::ptr::slice_from_raw_parts(sample.as_payload_ptr(), len)) }
     }
 
     /// Returns the length of a non-null raw slice.
@@ -85,7 +84,7 @@ impl<T> RawSample<[T]> {
     #[inline]
     pub fn len(self) -> usize {
         // SAFETY: `self.as_payload_ptr` returns a non-null ptr
-        unsafe { (*self.as_payload_ptr()).len() }
+        unsafe { (&*self.as_payload_ptr()).len() }
     }
 }
 
@@ -185,7 +184,12 @@ impl<T> RawSampleMut<[T]> {
     #[inline]
     pub fn slice_from_raw_parts(sample: RawSampleMut<T>, len: usize) -> RawSampleMut<[T]> {
         // SAFETY: `self.as_payload_mut_ptr` returns a non-null ptr
-        unsafe { Self::new_unchecked(slice::from_raw_parts_mut(sample.as_payload_mut_ptr(), len)) }
+        unsafe {
+            Self::new_unchecked(std::ptr::slice_from_raw_parts_mut(
+                sample.as_payload_mut_ptr(),
+                len,
+            ))
+        }
     }
 
     /// Returns the length of a non-null raw slice.
@@ -194,7 +198,7 @@ impl<T> RawSampleMut<[T]> {
     #[inline]
     pub fn len(self) -> usize {
         // SAFETY: `self.as_payload_ptr` returns a non-null ptr
-        unsafe { (*self.as_payload_ptr()).len() }
+        unsafe { (&*self.as_payload_ptr()).len() }
     }
 }
 
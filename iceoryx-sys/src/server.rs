@@ -0,0 +1,290 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::subscriber::check_chunk;
+use crate::{AllocationError, ChunkTakeError, RawSample, RawSampleMut, ServerOptions};
+
+use std::ffi::{c_void, CString};
+use std::mem::MaybeUninit;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerSendError {
+    NotOffered,
+    ClientNotAvailable,
+    InvalidResponse,
+}
+
+cpp! {{
+    #include "iceoryx_posh/internal/popo/ports/server_port_user.hpp"
+    #include "iceoryx_posh/runtime/posh_runtime.hpp"
+
+    using iox::capro::IdString_t;
+    using iox::popo::AllocationError;
+    using iox::cxx::TruncateToCapacity;
+    using iox::popo::ConsumerTooSlowPolicy;
+    using iox::popo::QueueFullPolicy;
+    using iox::popo::RequestHeader;
+    using iox::popo::ResponseHeader;
+    using iox::popo::ServerOptions;
+    using iox::popo::ServerPortUser;
+    using iox::popo::ServerRequestResult;
+    using iox::popo::ServerSendError;
+    using iox::runtime::PoshRuntime;
+}}
+
+cpp_class!(pub unsafe struct Server as "ServerPortUser");
+
+impl Server {
+    pub fn new(
+        service: &str,
+        instance: &str,
+        event: &str,
+        options: &ServerOptions,
+    ) -> Option<Box<Self>> {
+        let service = CString::new(service).expect("CString::new failed");
+        let service = service.as_ptr();
+        let instance = CString::new(instance).expect("CString::new failed");
+        let instance = instance.as_ptr();
+        let event = CString::new(event).expect("CString::new failed");
+        let event = event.as_ptr();
+        let request_queue_capacity = options.request_queue_capacity;
+        let node_name = CString::new(&options.node_name as &str).expect("CString::new failed");
+        let node_name = node_name.as_ptr();
+        let offer_on_create = options.offer_on_create;
+        let request_queue_full_policy = options.request_queue_full_policy as u8;
+        let client_too_slow_policy = options.client_too_slow_policy as u8;
+        unsafe {
+            let raw = cpp!([service as "const char *",
+                            instance as "const char *",
+                            event as "const char *",
+                            request_queue_capacity as "uint64_t",
+                            node_name as "const char *",
+                            offer_on_create as "bool",
+                            request_queue_full_policy as "uint8_t",
+                            client_too_slow_policy as "uint8_t"]
+                            -> *mut Server as "ServerPortUser*"
+            {
+                ServerOptions options;
+                options.requestQueueCapacity = request_queue_capacity;
+                options.nodeName = IdString_t(TruncateToCapacity, node_name);
+                options.offerOnCreate = offer_on_create;
+                options.requestQueueFullPolicy = static_cast<QueueFullPolicy>(request_queue_full_policy);
+                options.clientTooSlowPolicy = static_cast<ConsumerTooSlowPolicy>(client_too_slow_policy);
+                auto portData = PoshRuntime::getInstance().getMiddlewareServer(
+                    {
+                        IdString_t(TruncateToCapacity, service),
+                        IdString_t(TruncateToCapacity, instance),
+                        IdString_t(TruncateToCapacity, event)
+                    },
+                    options
+                );
+                if (portData == nullptr) {
+                    return nullptr;
+                }
+                return new ServerPortUser(*portData);
+            });
+
+            if raw.is_null() {
+                None
+            } else {
+                Some(Box::from_raw(raw))
+            }
+        }
+    }
+
+    pub fn offer(&self) {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ServerPortUser*"] {
+                this_ptr->offer();
+            });
+        }
+    }
+
+    pub fn stop_offer(&self) {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ServerPortUser*"] {
+                this_ptr->stopOffer();
+            });
+        }
+    }
+
+    pub fn is_offered(&self) -> bool {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ServerPortUser*"] -> bool as "bool" {
+                return this_ptr->isOffered();
+            })
+        }
+    }
+
+    pub fn has_clients(&self) -> bool {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ServerPortUser*"] -> bool as "bool" {
+                return this_ptr->hasClients();
+            })
+        }
+    }
+
+    pub fn has_requests(&self) -> bool {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ServerPortUser*"] -> bool as "bool" {
+                return this_ptr->hasNewRequests();
+            })
+        }
+    }
+
+    pub fn clear_requests(&self) {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "ServerPortUser*"] {
+                this_ptr->releaseQueuedRequests();
+            });
+        }
+    }
+
+    /// Takes a request with a `T`
+    ///
+    /// Requests with a mismatching payload are released and the mismatch is returned as error.
+    pub fn try_take_request<T>(&self) -> Result<Option<RawSample<T>>, ChunkTakeError> {
+        unsafe {
+            let payload = match self.try_get_request()? {
+                Some(payload) => payload,
+                None => return Ok(None),
+            };
+
+            let check = check_chunk::<T, ()>(payload.chunk_header(), |payload_size| {
+                payload_size == std::mem::size_of::<T>()
+            });
+            if let Err(e) = check {
+                self.release_request(payload);
+                return Err(e);
+            }
+
+            Ok(Some(payload.cast::<T>()))
+        }
+    }
+
+    unsafe fn try_get_request(&self) -> Result<Option<RawSample<c_void>>, ChunkTakeError> {
+        let this_ptr = self as *const Self;
+        let mut too_many_held_in_parallel = false;
+        let too_many_held_in_parallel_ptr = &mut too_many_held_in_parallel as *mut bool;
+        let payload = cpp!([this_ptr as "ServerPortUser*", too_many_held_in_parallel_ptr as "bool*"] -> *const std::ffi::c_void as "const void*" {
+            auto getRequestResult = this_ptr->getRequest();
+
+            if (getRequestResult.has_error()) {
+                *too_many_held_in_parallel_ptr = getRequestResult.get_error() == ServerRequestResult::TOO_MANY_REQUESTS_HELD_IN_PARALLEL;
+                return nullptr;
+            }
+
+            return getRequestResult.value()->getUserPayload();
+        });
+
+        if let Some(payload) = RawSample::new(payload) {
+            return Ok(Some(payload));
+        }
+
+        if too_many_held_in_parallel {
+            Err(ChunkTakeError::TooManyChunksHeldInParallel)
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn release_request<T: ?Sized>(&self, sample: RawSample<T>) {
+        unsafe {
+            let this_ptr = self as *const Self;
+            let payload = sample.cast::<c_void>().as_payload_ptr();
+            cpp!([this_ptr as "ServerPortUser*", payload as "const void*"] {
+                auto header = RequestHeader::fromPayload(payload);
+                this_ptr->releaseRequest(header);
+            });
+        }
+    }
+
+    pub fn try_allocate_response<T, R: ?Sized>(
+        &self,
+        request: RawSample<R>,
+    ) -> Result<RawSampleMut<MaybeUninit<T>>, AllocationError> {
+        let size = std::mem::size_of::<T>() as u32;
+        let align = std::mem::align_of::<T>() as u32;
+        unsafe {
+            self.try_allocate_response_chunk(request.cast::<c_void>(), size, align)
+                .map(|payload| payload.cast::<MaybeUninit<T>>())
+        }
+    }
+
+    unsafe fn try_allocate_response_chunk(
+        &self,
+        request: RawSample<c_void>,
+        size: u32,
+        align: u32,
+    ) -> Result<RawSampleMut<c_void>, AllocationError> {
+        let this_ptr = self as *const Self;
+        let request_payload = request.as_payload_ptr();
+        let mut allocation_error = AllocationError::UndefinedError;
+        let allocation_error_ptr = &mut allocation_error as *mut AllocationError;
+        let payload = cpp!([this_ptr as "ServerPortUser*",
+                            request_payload as "const void*",
+                            size as "uint32_t",
+                            align as "uint32_t",
+                            allocation_error_ptr as "AllocationError*"]
+                            -> *mut std::ffi::c_void as "void*"
+        {
+            auto requestHeader = RequestHeader::fromPayload(request_payload);
+            auto allocResult = this_ptr->allocateResponse(requestHeader, size, align);
+            if (allocResult.has_error()) {
+                *allocation_error_ptr = allocResult.get_error();
+                return nullptr;
+            } else {
+                return allocResult.value()->getUserPayload();
+            }
+        });
+
+        if !payload.is_null() {
+            Ok(RawSampleMut::new_unchecked(payload))
+        } else {
+            Err(allocation_error)
+        }
+    }
+
+    pub fn release_response<T: ?Sized>(&self, sample: RawSampleMut<T>) {
+        unsafe {
+            let this_ptr = self as *const Self;
+            let payload = sample.cast::<c_void>().as_payload_ptr();
+            cpp!([this_ptr as "ServerPortUser*", payload as "void*"] {
+                auto header = ResponseHeader::fromPayload(payload);
+                this_ptr->releaseResponse(header);
+            });
+        }
+    }
+
+    pub fn send_response<T: ?Sized>(&self, sample: RawSampleMut<T>) -> Result<(), ServerSendError> {
+        let payload = sample.cast::<c_void>().as_payload_ptr();
+        let mut send_error = ServerSendError::InvalidResponse;
+        unsafe {
+            let this_ptr = self as *const Self;
+            let send_error_ptr = &mut send_error as *mut ServerSendError;
+            let success = cpp!([this_ptr as "ServerPortUser*", payload as "void*", send_error_ptr as "ServerSendError*"] -> bool as "bool" {
+                auto header = ResponseHeader::fromPayload(payload);
+                auto sendResult = this_ptr->sendResponse(header);
+                if (sendResult.has_error()) {
+                    *send_error_ptr = sendResult.get_error();
+                    return false;
+                }
+                return true;
+            });
+
+            if success {
+                Ok(())
+            } else {
+                Err(send_error)
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::{ConsumerTooSlowPolicy, QueueFullPolicy};
+
+use std::marker::PhantomData;
+
+pub struct ServerOptions {
+    pub request_queue_capacity: u64,
+    pub node_name: String,
+    pub offer_on_create: bool,
+    pub request_queue_full_policy: QueueFullPolicy,
+    pub client_too_slow_policy: ConsumerTooSlowPolicy,
+    _phantom: PhantomData<()>,
+}
+
+impl Default for ServerOptions {
+    fn default() -> Self {
+        Self {
+            request_queue_capacity: 1024,
+            node_name: String::new(),
+            offer_on_create: true,
+            request_queue_full_policy: QueueFullPolicy::DiscardOldestData,
+            client_too_slow_policy: ConsumerTooSlowPolicy::DiscardOldestData,
+            _phantom: PhantomData,
+        }
+    }
+}
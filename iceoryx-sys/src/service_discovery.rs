@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::introspection::ServiceDescription;
+
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::ptr;
+
+cpp! {{
+    #include "iceoryx_posh/runtime/service_discovery.hpp"
+
+    #include <vector>
+
+    using iox::capro::IdString_t;
+    using iox::cxx::TruncateToCapacity;
+    using iox::popo::MessagingPattern;
+    using iox::runtime::ServiceDiscovery;
+
+    using ServiceContainer = std::vector<iox::capro::ServiceDescription>;
+
+    iox::cxx::optional<IdString_t> toOptionalIdString(const char* id) {
+        if (id == nullptr) {
+            return iox::cxx::nullopt;
+        }
+        return IdString_t(TruncateToCapacity, id);
+    }
+}}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessagingPattern {
+    PubSub,
+    ReqRes,
+}
+
+fn id_to_string(id: *const c_char) -> Option<String> {
+    unsafe {
+        CStr::from_ptr(id)
+            .to_str()
+            .map_or(None, |id| Some(id.to_string()))
+    }
+}
+
+cpp_class!(unsafe struct ServiceContainer as "ServiceContainer");
+
+impl ServiceContainer {
+    fn len(&self) -> usize {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "const ServiceContainer*"] -> usize as "size_t" {
+                return this_ptr->size();
+            })
+        }
+    }
+
+    fn service_id(&self, index: usize) -> *const c_char {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "const ServiceContainer*", index as "size_t"] -> *const c_char as "const char*" {
+                return (*this_ptr)[index].getServiceIDString().c_str();
+            })
+        }
+    }
+
+    fn instance_id(&self, index: usize) -> *const c_char {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "const ServiceContainer*", index as "size_t"] -> *const c_char as "const char*" {
+                return (*this_ptr)[index].getInstanceIDString().c_str();
+            })
+        }
+    }
+
+    fn event_id(&self, index: usize) -> *const c_char {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "const ServiceContainer*", index as "size_t"] -> *const c_char as "const char*" {
+                return (*this_ptr)[index].getEventIDString().c_str();
+            })
+        }
+    }
+
+    fn service_description(&self, index: usize) -> Option<ServiceDescription> {
+        match (
+            id_to_string(self.service_id(index)),
+            id_to_string(self.instance_id(index)),
+            id_to_string(self.event_id(index)),
+        ) {
+            (Some(service_id), Some(instance_id), Some(event_id)) => Some(ServiceDescription {
+                service_id,
+                instance_id,
+                event_id,
+            }),
+            _ => None,
+        }
+    }
+}
+
+cpp_class!(pub unsafe struct ServiceDiscovery as "ServiceDiscovery");
+
+impl ServiceDiscovery {
+    pub fn new() -> Box<Self> {
+        unsafe {
+            let raw = cpp!([] -> *mut ServiceDiscovery as "ServiceDiscovery*"
+            {
+                return new ServiceDiscovery;
+            });
+
+            Box::from_raw(raw)
+        }
+    }
+
+    /// Searches all services with the given messaging pattern which match the provided `service`, `instance`
+    /// and `event`; `None` corresponds to a wildcard
+    pub fn find_service(
+        &self,
+        service: Option<&str>,
+        instance: Option<&str>,
+        event: Option<&str>,
+        pattern: MessagingPattern,
+    ) -> Vec<ServiceDescription> {
+        let service = service.map(|id| CString::new(id).expect("CString::new failed"));
+        let service = service.as_ref().map_or(ptr::null(), |id| id.as_ptr());
+        let instance = instance.map(|id| CString::new(id).expect("CString::new failed"));
+        let instance = instance.as_ref().map_or(ptr::null(), |id| id.as_ptr());
+        let event = event.map(|id| CString::new(id).expect("CString::new failed"));
+        let event = event.as_ref().map_or(ptr::null(), |id| id.as_ptr());
+
+        let services = ServiceContainer::default();
+        unsafe {
+            let this_ptr = self as *const Self;
+            let services_ptr = &services as *const ServiceContainer;
+            cpp!([this_ptr as "ServiceDiscovery*",
+                  services_ptr as "ServiceContainer*",
+                  service as "const char*",
+                  instance as "const char*",
+                  event as "const char*",
+                  pattern as "MessagingPattern"]
+            {
+                this_ptr->findService(toOptionalIdString(service),
+                                      toOptionalIdString(instance),
+                                      toOptionalIdString(event),
+                                      [&](auto& serviceDescription) {
+                                          services_ptr->push_back(serviceDescription);
+                                      },
+                                      pattern);
+            });
+        }
+
+        (0..services.len())
+            .filter_map(|index| services.service_description(index))
+            .collect()
+    }
+}
@@ -3,11 +3,10 @@
 // SPDX-FileContributor: Mathias Kraus
 // SPDX-FileContributor: Apex.AI
 
-use crate::{RawSample, SubscriberOptions};
+use crate::{ChunkHeader, ConditionVariable, RawSample, SubscriberOptions};
 
 use std::ffi::{c_void, CString};
 use std::fmt;
-use std::time::Duration;
 
 use std::rc::Rc;
 use std::sync::Arc;
@@ -22,6 +21,28 @@ pub enum SubscribeState {
     WaitForOffer,
 }
 
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkReceiveResult {
+    TooManyChunksHeldInParallel,
+    NoChunkAvailable,
+}
+
+/// The reason why a chunk could not be taken from the receiver queue
+///
+/// The chunk is released in all cases, for `TooManyChunksHeldInParallel` by iceoryx itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkTakeError {
+    /// The maximum number of chunks held in parallel is exhausted
+    TooManyChunksHeldInParallel,
+    /// The size of the user header does not match
+    UserHeaderSizeMismatch { expected: usize, actual: usize },
+    /// The size of the payload does not match; for slices the payload size must be a multiple of `expected`
+    PayloadSizeMismatch { expected: usize, actual: usize },
+    /// The alignment of the payload is smaller than the required one
+    AlignmentMismatch { required: usize, actual: usize },
+}
+
 pub trait SubscriberStrongRef: Clone {
     fn new(ffi_sub: Box<Subscriber>) -> Self;
 
@@ -72,49 +93,19 @@ impl SubscriberStrongRef for SubscriberArc {
 
 cpp! {{
     #include "iceoryx_posh/internal/popo/ports/subscriber_port_user.hpp"
-    #include "iceoryx_posh/internal/popo/building_blocks/condition_variable_data.hpp"
     #include "iceoryx_posh/runtime/posh_runtime.hpp"
 
     using iox::SubscribeState;
     using iox::capro::IdString_t;
     using iox::cxx::TruncateToCapacity;
+    using iox::popo::ChunkReceiveResult;
     using iox::popo::QueueFullPolicy;
     using iox::popo::SubscriberOptions;
     using iox::popo::SubscriberPortUser;
     using iox::runtime::PoshRuntime;
-
-    class ConditionVariable {
-      public:
-        ConditionVariable()
-          : m_data(*PoshRuntime::getInstance().getMiddlewareConditionVariable())
-        {}
-
-        ~ConditionVariable() {
-            m_data.m_toBeDestroyed.store(true, std::memory_order_relaxed);
-            m_data.m_semaphore.post().or_else([](auto) {
-                iox::LogFatal() << "Could not get ConditionVariableData from RouDi! Terminating!";
-                std::terminate();
-            });
-        }
-
-        void timedWait(const iox::units::Duration& timeToWait) {
-            m_data.m_semaphore.timedWait(timeToWait).or_else([](auto) {
-                iox::LogFatal() << "Could not wait on semaphore! Potentially corrupted! Terminating!";
-                std::terminate();
-            }).value();
-        }
-
-        iox::popo::ConditionVariableData& data() {
-            return m_data;
-        }
-
-      private:
-        iox::popo::ConditionVariableData& m_data;
-    };
 }}
 
 cpp_class!(pub unsafe struct Subscriber as "SubscriberPortUser");
-cpp_class!(pub unsafe struct ConditionVariable as "ConditionVariable");
 
 impl Subscriber {
     pub fn new(
@@ -201,15 +192,17 @@ impl Subscriber {
         }
     }
 
-    pub fn set_condition_variable(&self, condition_variable: &ConditionVariable) {
+    pub fn set_condition_variable(
+        &self,
+        condition_variable: &ConditionVariable,
+        notification_index: u64,
+    ) {
         unsafe {
             let this_ptr = self as *const Self;
             let condition_variable = condition_variable as *const ConditionVariable;
-            cpp!([this_ptr as "SubscriberPortUser*", condition_variable as "ConditionVariable*"] {
+            cpp!([this_ptr as "SubscriberPortUser*", condition_variable as "ConditionVariable*", notification_index as "uint64_t"] {
                 if(!this_ptr->isConditionVariableSet()) {
-                    // currently the condition variable is used only for one subscriber and therefore the index is set to 0
-                    constexpr uint64_t NOTIFICATION_INDEX{0};
-                    this_ptr->setConditionVariable(condition_variable->data(), NOTIFICATION_INDEX);
+                    this_ptr->setConditionVariable(condition_variable->data(), notification_index);
                 }
             });
         }
@@ -242,7 +235,15 @@ impl Subscriber {
         }
     }
 
-    // TODO has_chunks_lost_since_last_call
+    /// Checks whether chunks were discarded due to a full queue since the last call
+    pub fn has_lost_chunks(&self) -> bool {
+        unsafe {
+            let this_ptr = self as *const Self;
+            cpp!([this_ptr as "SubscriberPortUser*"] -> bool as "bool" {
+                return this_ptr->hasLostChunksSinceLastCall();
+            })
+        }
+    }
 
     pub fn clear(&self) {
         unsafe {
@@ -253,37 +254,66 @@ impl Subscriber {
         }
     }
 
-    pub fn try_take<T>(&self) -> Option<RawSample<T>> {
-        unsafe { self.try_get_chunk().map(|payload| payload.cast::<T>()) }
+    /// Takes a chunk with a `T` and a user header `H`
+    ///
+    /// A zero-sized `H` accepts chunks with any user header and a zero-sized `T` accepts any payload, which is
+    /// used for opaque C++ types like the introspection topics. Chunks with a mismatching user header or payload
+    /// are released and the mismatch is returned as error.
+    pub fn try_take<T, H>(&self) -> Result<Option<RawSample<T>>, ChunkTakeError> {
+        unsafe {
+            let payload = match self.try_get_chunk()? {
+                Some(payload) => payload,
+                None => return Ok(None),
+            };
+
+            let check = check_chunk::<T, H>(payload.chunk_header(), |payload_size| {
+                std::mem::size_of::<T>() == 0 || payload_size == std::mem::size_of::<T>()
+            });
+            if let Err(e) = check {
+                self.release(payload);
+                return Err(e);
+            }
+
+            Ok(Some(payload.cast::<T>()))
+        }
     }
 
-    pub fn try_take_slice<T>(&self) -> Option<RawSample<[T]>> {
+    /// Takes a chunk with a slice of `T` and a user header `H`
+    ///
+    /// A zero-sized `H` accepts chunks with any user header. Chunks with a mismatching user header or payload
+    /// are released and the mismatch is returned as error.
+    pub fn try_take_slice<T, H>(&self) -> Result<Option<RawSample<[T]>>, ChunkTakeError> {
         unsafe {
-            let payload = self.try_get_chunk()?;
-
-            let chunk_header = payload.chunk_header();
-            let payload_size = chunk_header.get_user_payload_size();
-            let payload_alignment = chunk_header.get_user_payload_alignment();
-            let len = payload_size / std::mem::size_of::<T>();
+            let payload = match self.try_get_chunk()? {
+                Some(payload) => payload,
+                None => return Ok(None),
+            };
 
-            if payload_size % std::mem::size_of::<T>() == 0
-                && payload_alignment >= std::mem::align_of::<T>()
-            {
-                Some(RawSample::slice_from_raw_parts(payload.cast::<T>(), len))
-            } else {
-                // TODO return Result<Option<T>>
+            let check = check_chunk::<T, H>(payload.chunk_header(), |payload_size| {
+                payload_size % std::mem::size_of::<T>() == 0
+            });
+            if let Err(e) = check {
                 self.release(payload);
-                None
+                return Err(e);
             }
+
+            let len = payload.chunk_header().get_user_payload_size() / std::mem::size_of::<T>();
+            Ok(Some(RawSample::slice_from_raw_parts(
+                payload.cast::<T>(),
+                len,
+            )))
         }
     }
 
-    unsafe fn try_get_chunk(&self) -> Option<RawSample<c_void>> {
+    unsafe fn try_get_chunk(&self) -> Result<Option<RawSample<c_void>>, ChunkTakeError> {
         let this_ptr = self as *const Self;
-        let payload = cpp!([this_ptr as "SubscriberPortUser*"] -> *const std::ffi::c_void as "const void*" {
+        let mut receive_result = ChunkReceiveResult::NoChunkAvailable;
+        let receive_result_ptr = &mut receive_result as *mut ChunkReceiveResult;
+        let payload = cpp!([this_ptr as "SubscriberPortUser*", receive_result_ptr as "ChunkReceiveResult*"] -> *const std::ffi::c_void as "const void*" {
             auto getChunkResult = this_ptr->tryGetChunk();
 
             if (getChunkResult.has_error()) {
+                *receive_result_ptr = getChunkResult.get_error();
                 return nullptr;
             }
 
@@ -291,9 +321,14 @@ impl Subscriber {
         });
 
         if !payload.is_null() {
-            Some(RawSample::new_unchecked(payload))
-        } else {
-            None
+            return Ok(Some(RawSample::new_unchecked(payload)));
+        }
+
+        match receive_result {
+            ChunkReceiveResult::NoChunkAvailable => Ok(None),
+            ChunkReceiveResult::TooManyChunksHeldInParallel => {
+                Err(ChunkTakeError::TooManyChunksHeldInParallel)
+            }
         }
     }
 
@@ -317,25 +352,36 @@ impl fmt::Debug for Subscriber {
     }
 }
 
-impl ConditionVariable {
-    pub fn new() -> Box<Self> {
-        unsafe {
-            let raw = cpp!([] -> *mut ConditionVariable as "ConditionVariable*"
-            {
-                return new ConditionVariable;
-            });
+/// Checks whether the user header and payload of a chunk match `H` and `T`
+///
+/// A zero-sized `H` accepts chunks with any user header.
+pub(crate) fn check_chunk<T, H>(
+    chunk_header: &ChunkHeader,
+    is_valid_payload_size: impl Fn(usize) -> bool,
+) -> Result<(), ChunkTakeError> {
+    let user_header_size = chunk_header.get_user_header_size();
+    if std::mem::size_of::<H>() != 0 && user_header_size != std::mem::size_of::<H>() {
+        return Err(ChunkTakeError::UserHeaderSizeMismatch {
+            expected: std::mem::size_of::<H>(),
+            actual: user_header_size,
+        });
+    }
 
-            Box::from_raw(raw)
-        }
+    let payload_alignment = chunk_header.get_user_payload_alignment();
+    if payload_alignment < std::mem::align_of::<T>() {
+        return Err(ChunkTakeError::AlignmentMismatch {
+            required: std::mem::align_of::<T>(),
+            actual: payload_alignment,
+        });
     }
 
-    pub fn timed_wait(&self, timeout: Duration) {
-        unsafe {
-            let this_ptr = self as *const Self;
-            let timeout_ns = timeout.as_nanos() as u64;
-            cpp!([this_ptr as "ConditionVariable*", timeout_ns as "uint64_t"] {
-                this_ptr->timedWait(iox::units::Duration::fromNanoseconds(timeout_ns));
-            });
-        }
+    let payload_size = chunk_header.get_user_payload_size();
+    if !is_valid_payload_size(payload_size) {
+        return Err(ChunkTakeError::PayloadSizeMismatch {
+            expected: std::mem::size_of::<T>(),
+            actual: payload_size,
+        });
     }
+
+    Ok(())
 }
@@ -0,0 +1,263 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use super::{RequestMut, Response};
+use crate::marker::ShmSend;
+use crate::node::{self, NodeResource};
+use crate::runtime::RuntimeResource;
+use crate::sample::take_skipping_mismatches;
+use crate::service_name;
+use crate::ConnectionState;
+use crate::ConsumerTooSlowPolicy;
+use crate::IceoryxError;
+use crate::QueueFullPolicy;
+use crate::ServiceName;
+
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+/// Create a client with custom options
+///
+/// # Example
+/// ```
+/// # use iceoryx_rs::Runtime;
+/// use iceoryx_rs::ClientBuilder;
+/// # use ffi::RouDiEnvironment;
+/// #
+/// # use anyhow::{anyhow, Result};
+/// # fn main() -> Result<()> {
+/// # let _roudi = RouDiEnvironment::new();
+/// #
+/// # Runtime::init("basic_request_response");
+///
+/// let client = ClientBuilder::<u64, u64>::new("all", "glory", "hypnotoad")
+///         .response_queue_capacity(5)
+///         .create()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ClientBuilder<'a, Req: ShmSend, Res: ShmSend> {
+    service: &'a str,
+    instance: &'a str,
+    event: &'a str,
+    options: ffi::ClientOptions,
+    node: NodeResource,
+    phantom: PhantomData<(Req, Res)>,
+}
+
+impl<'a, Req: ShmSend, Res: ShmSend> ClientBuilder<'a, Req, Res> {
+    /// Creates a new `ClientBuilder`
+    ///
+    /// The parameter `service`, `instance` and `event` are used to specify the name of the service. They are
+    /// validated like a [`ServiceName`] when the client is created.
+    pub fn new(service: &'a str, instance: &'a str, event: &'a str) -> Self {
+        Self {
+            service,
+            instance,
+            event,
+            options: ffi::ClientOptions::default(),
+            node: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates a new `ClientBuilder` for the service with the `service_name`
+    pub fn from_service_name(service_name: &'a ServiceName) -> Self {
+        Self::new(
+            service_name.service(),
+            service_name.instance(),
+            service_name.event(),
+        )
+    }
+
+    /// The size of the queue where responses are stored before they are passed to the user
+    pub fn response_queue_capacity(mut self, size: u64) -> Self {
+        self.options.response_queue_capacity = size;
+        self
+    }
+
+    /// The name of the node where the client should belong to
+    ///
+    /// The node name is shown in the port introspection. Use [`Node::client_builder`](crate::Node::client_builder)
+    /// to create a client which belongs to a node that is registered at `RouDi`.
+    ///
+    /// The name is validated like the name of a [`Node`](crate::Node) when the client is created. It is empty by
+    /// default, i.e. the client does not belong to a node.
+    pub fn node_name(mut self, name: &str) -> Self {
+        self.options.node_name = name.to_string();
+        self
+    }
+
+    pub(crate) fn node(mut self, name: &str, node: NodeResource) -> Self {
+        self.options.node_name = name.to_string();
+        self.node = node;
+        self
+    }
+
+    /// Set the behavior of a full response queue
+    ///
+    /// By default the server discards the oldest responses to make room for new responses.
+    pub fn response_queue_full_policy(mut self, queue_full_policy: QueueFullPolicy) -> Self {
+        self.options.response_queue_full_policy = queue_full_policy;
+        self
+    }
+
+    /// Sets the policy on how to proceed when the server is too slow in processing the requests
+    ///
+    /// By default the oldest requests are removed from the server queue and the latest ones added.
+    pub fn server_too_slow_policy(mut self, server_too_slow_policy: ConsumerTooSlowPolicy) -> Self {
+        self.options.server_too_slow_policy = server_too_slow_policy;
+        self
+    }
+
+    /// Create a new [`Client`]
+    ///
+    /// The client requested to be connected when this method returns. If there is a matching server
+    /// which offers its service, the client is immediately connected.
+    ///
+    /// # Panics
+    ///
+    /// [`Runtime::init`](crate::Runtime::init) must have been called otherwise this method will panic.
+    pub fn create(mut self) -> Result<Client<Req, Res>, IceoryxError> {
+        self.options.connect_on_create = true;
+        self.create_client()
+    }
+
+    /// Create a new [`Client`] which did not yet request to be connected
+    ///
+    /// No requests can be sent until [`Client::connect`] is called.
+    ///
+    /// # Panics
+    ///
+    /// [`Runtime::init`](crate::Runtime::init) must have been called otherwise this method will panic.
+    pub fn create_without_connect(mut self) -> Result<Client<Req, Res>, IceoryxError> {
+        self.options.connect_on_create = false;
+        self.create_client()
+    }
+
+    fn create_client(self) -> Result<Client<Req, Res>, IceoryxError> {
+        service_name::validate(self.service, self.instance, self.event)?;
+        node::validate_port_node_name(&self.options.node_name)?;
+        let ffi_client = ffi::Client::new(self.service, self.instance, self.event, &self.options)
+            .ok_or(IceoryxError::ClientCreationFailed)?;
+
+        Ok(Client {
+            ffi_client,
+            _node: self.node,
+            _runtime_resource: RuntimeResource::new(),
+            next_sequence_id: AtomicI64::new(0),
+            phantom: PhantomData,
+        })
+    }
+}
+
+/// A client which sends requests of type `Req` to a server and receives responses of type `Res`
+pub struct Client<Req: ShmSend, Res: ShmSend> {
+    ffi_client: Box<ffi::Client>,
+    _node: NodeResource,
+    _runtime_resource: RuntimeResource,
+    next_sequence_id: AtomicI64,
+    phantom: PhantomData<(Req, Res)>,
+}
+
+impl<Req: ShmSend, Res: ShmSend> Client<Req, Res> {
+    /// Requests to connect to a server
+    ///
+    /// It might take up to 50 milliseconds until `RouDi` runs its discovery loop and the client
+    /// will be connected to the server.
+    pub fn connect(&self) {
+        self.ffi_client.connect();
+    }
+
+    /// Disconnects from the server
+    ///
+    /// It might take up to 50 milliseconds until `RouDi` runs its discovery loop and this takes effect.
+    pub fn disconnect(&self) {
+        self.ffi_client.disconnect();
+    }
+
+    /// The current connection state
+    pub fn connection_state(&self) -> ConnectionState {
+        self.ffi_client.connection_state()
+    }
+
+    /// Sends a request to the server
+    ///
+    /// The request is consumed, even if sending fails.
+    pub fn send(&self, request: RequestMut<Req>) -> Result<(), IceoryxError> {
+        let (data, ffi_client) = request.into_raw_parts();
+        ffi_client
+            .send_request(data)
+            .map_err(IceoryxError::SendRequestFailed)
+    }
+
+    /// Checks whether there are responses in the response queue
+    pub fn has_responses(&self) -> bool {
+        self.ffi_client.has_responses()
+    }
+
+    /// Takes a response from the response queue
+    ///
+    /// If the response queue is empty or too many responses are held in parallel, `None` will be returned. Responses
+    /// which do not match `Res` are discarded and the next response of the queue is taken instead. Use
+    /// [`try_take_response`](Self::try_take_response) to get the reason why a response could not be taken.
+    pub fn take_response(&self) -> Option<Response<'_, Res>> {
+        take_skipping_mismatches(|| self.try_take_response())
+    }
+
+    /// Takes a response from the response queue and reports why a response could not be taken
+    ///
+    /// Returns `Ok(None)` if the response queue is empty. Responses which do not match are released and one of the
+    /// following errors is returned
+    /// - [`IceoryxError::TooManySamplesHoldInParallel`] if too many responses are held at the same time
+    /// - [`IceoryxError::PayloadSizeMismatch`] if the payload size does not match the size of `Res`
+    /// - [`IceoryxError::AlignmentMismatch`] if the payload alignment is smaller than the alignment of `Res`
+    pub fn try_take_response(&self) -> Result<Option<Response<'_, Res>>, IceoryxError> {
+        Ok(self
+            .ffi_client
+            .try_take_response::<Res>()?
+            .map(|data| Response::new(data, &self.ffi_client)))
+    }
+
+    /// Clears the response queue and release all the responses from the queue
+    pub fn clear_responses(&self) {
+        self.ffi_client.clear_responses();
+    }
+
+    /// Loan an uninitialized request
+    ///
+    /// Same as [`loan`](Self::loan) but with uninitialized data.
+    pub fn loan_uninit(&self) -> Result<RequestMut<'_, MaybeUninit<Req>>, IceoryxError> {
+        let data = self.ffi_client.try_allocate_request::<Req>().map_err(|e| {
+            IceoryxError::from_allocation_error(
+                e,
+                std::mem::size_of::<Req>(),
+                std::mem::align_of::<Req>(),
+            )
+        })?;
+
+        let mut request = RequestMut::new(data, &self.ffi_client);
+        request.set_sequence_id(self.next_sequence_id.fetch_add(1, Ordering::Relaxed));
+        Ok(request)
+    }
+}
+
+impl<Req: ShmSend + Default, Res: ShmSend> Client<Req, Res> {
+    /// Loan a request
+    ///
+    /// The loaned request is initialized with the default value of the type and has a consecutive
+    /// sequence ID assigned. If default initialization is not desired or the type does not implement
+    /// the `Default` trait, [`loan_uninit`](Self::loan_uninit) can be used.
+    ///
+    /// The errors are the same as for [`Publisher::loan`](crate::Publisher::loan).
+    pub fn loan(&self) -> Result<RequestMut<'_, Req>, IceoryxError> {
+        let mut request = self.loan_uninit()?;
+
+        unsafe {
+            request.as_mut_ptr().write(Req::default());
+            Ok(request.assume_init())
+        }
+    }
+}
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+//! Codecs to transfer `serde` serializable types with `[u8]` publisher and subscriber
+//!
+//! This module is only available with the `serde` feature. Types which are not [`ShmSend`](crate::marker::ShmSend),
+//! e.g. a config with `String`s, can be published with
+//! [`Publisher::publish_serialized`](crate::Publisher::publish_serialized) and received with
+//! [`Sample::deserialize`](crate::Sample::deserialize). The serialization format is defined by the [`Codec`];
+//! `Postcard` and `Bincode` are available with the `postcard` and `bincode` feature.
+
+use crate::IceoryxError;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "bincode")]
+use std::convert::TryFrom;
+
+/// The serialization format used to transfer a value in a `[u8]` sample
+///
+/// The size of the sample is determined by [`serialized_size`](Codec::serialized_size). If the codec can compute
+/// the size upfront, the value is serialized directly into the loaned sample with
+/// [`serialize_into`](Codec::serialize_into). Otherwise it is serialized with [`serialize`](Codec::serialize)
+/// into a buffer which is copied into the sample.
+pub trait Codec {
+    /// The exact size of the serialized `value` or `None` if it is not known without serializing the value
+    fn serialized_size<T: Serialize + ?Sized>(&self, value: &T) -> Option<usize> {
+        let _ = value;
+        None
+    }
+
+    /// Serializes `value` into `buffer`, which has the size returned by [`serialized_size`](Codec::serialized_size)
+    fn serialize_into<T: Serialize + ?Sized>(
+        &self,
+        value: &T,
+        buffer: &mut [u8],
+    ) -> Result<(), IceoryxError>;
+
+    /// Serializes `value` into a new buffer
+    fn serialize<T: Serialize + ?Sized>(&self, value: &T) -> Result<Vec<u8>, IceoryxError>;
+
+    /// Deserializes a `T` from `bytes`
+    fn deserialize<'de, T: Deserialize<'de>>(&self, bytes: &'de [u8]) -> Result<T, IceoryxError>;
+}
+
+impl<C: Codec> Codec for &C {
+    fn serialized_size<T: Serialize + ?Sized>(&self, value: &T) -> Option<usize> {
+        (*self).serialized_size(value)
+    }
+
+    fn serialize_into<T: Serialize + ?Sized>(
+        &self,
+        value: &T,
+        buffer: &mut [u8],
+    ) -> Result<(), IceoryxError> {
+        (*self).serialize_into(value, buffer)
+    }
+
+    fn serialize<T: Serialize + ?Sized>(&self, value: &T) -> Result<Vec<u8>, IceoryxError> {
+        (*self).serialize(value)
+    }
+
+    fn deserialize<'de, T: Deserialize<'de>>(&self, bytes: &'de [u8]) -> Result<T, IceoryxError> {
+        (*self).deserialize(bytes)
+    }
+}
+
+#[cfg(any(feature = "postcard", feature = "bincode"))]
+fn serialization_failed(error: impl std::fmt::Display) -> IceoryxError {
+    IceoryxError::SerializationFailed {
+        reason: error.to_string(),
+    }
+}
+
+#[cfg(any(feature = "postcard", feature = "bincode"))]
+fn deserialization_failed(error: impl std::fmt::Display) -> IceoryxError {
+    IceoryxError::DeserializationFailed {
+        reason: error.to_string(),
+    }
+}
+
+/// Codec for the [postcard](https://crates.io/crates/postcard) format
+///
+/// This is only available with the `postcard` feature.
+///
+/// # Example
+/// ```
+/// # use iceoryx_rs::Runtime;
+/// use iceoryx_rs::codec::Postcard;
+/// use iceoryx_rs::{PublisherBuilder, SubscriberBuilder};
+/// # use ffi::RouDiEnvironment;
+///
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Config {
+///     name: String,
+///     rate: u32,
+/// }
+///
+/// # use anyhow::{anyhow, Result};
+/// # fn main() -> Result<()> {
+/// # let _roudi = RouDiEnvironment::new();
+/// #
+/// # Runtime::init("codec");
+/// let publisher = PublisherBuilder::<[u8]>::new("all", "glory", "hypnotoad").create()?;
+/// let (subscriber, sample_receive_token) =
+///     SubscriberBuilder::<[u8]>::new("all", "glory", "hypnotoad").create()?;
+/// let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+///
+/// let config = Config { name: "hypnotoad".to_string(), rate: 42 };
+/// publisher.publish_serialized(&config, Postcard)?;
+///
+/// let sample = sample_receiver.take().ok_or_else(|| anyhow!("No sample received"))?;
+/// assert_eq!(sample.deserialize::<Config>(Postcard)?, config);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "postcard")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Postcard;
+
+#[cfg(feature = "postcard")]
+impl Codec for Postcard {
+    fn serialized_size<T: Serialize + ?Sized>(&self, value: &T) -> Option<usize> {
+        postcard::experimental::serialized_size(value).ok()
+    }
+
+    fn serialize_into<T: Serialize + ?Sized>(
+        &self,
+        value: &T,
+        buffer: &mut [u8],
+    ) -> Result<(), IceoryxError> {
+        let len = buffer.len();
+        match postcard::to_slice(value, buffer) {
+            Ok(serialized) if serialized.len() == len => Ok(()),
+            Ok(serialized) => Err(serialization_failed(format!(
+                "serialized {} bytes into a buffer of {} bytes",
+                serialized.len(),
+                len
+            ))),
+            Err(e) => Err(serialization_failed(e)),
+        }
+    }
+
+    fn serialize<T: Serialize + ?Sized>(&self, value: &T) -> Result<Vec<u8>, IceoryxError> {
+        postcard::to_stdvec(value).map_err(serialization_failed)
+    }
+
+    fn deserialize<'de, T: Deserialize<'de>>(&self, bytes: &'de [u8]) -> Result<T, IceoryxError> {
+        postcard::from_bytes(bytes).map_err(deserialization_failed)
+    }
+}
+
+/// Codec for the [bincode](https://crates.io/crates/bincode) format with its default options
+///
+/// This is only available with the `bincode` feature.
+#[cfg(feature = "bincode")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Bincode;
+
+#[cfg(feature = "bincode")]
+impl Codec for Bincode {
+    fn serialized_size<T: Serialize + ?Sized>(&self, value: &T) -> Option<usize> {
+        bincode::serialized_size(value)
+            .ok()
+            .and_then(|size| usize::try_from(size).ok())
+    }
+
+    fn serialize_into<T: Serialize + ?Sized>(
+        &self,
+        value: &T,
+        buffer: &mut [u8],
+    ) -> Result<(), IceoryxError> {
+        let mut writer = &mut buffer[..];
+        bincode::serialize_into(&mut writer, value).map_err(serialization_failed)?;
+        if writer.is_empty() {
+            Ok(())
+        } else {
+            Err(serialization_failed(format!(
+                "{} bytes of the buffer were not written",
+                writer.len()
+            )))
+        }
+    }
+
+    fn serialize<T: Serialize + ?Sized>(&self, value: &T) -> Result<Vec<u8>, IceoryxError> {
+        bincode::serialize(value).map_err(serialization_failed)
+    }
+
+    fn deserialize<'de, T: Deserialize<'de>>(&self, bytes: &'de [u8]) -> Result<T, IceoryxError> {
+        bincode::deserialize(bytes).map_err(deserialization_failed)
+    }
+}
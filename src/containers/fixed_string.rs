@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::marker::ShmSend;
+use crate::IceoryxError;
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+/// A UTF-8 string with a fixed capacity of `N` bytes
+///
+/// The memory layout is the same as `iox::cxx::string<N>`, i.e. a null-terminated char array with
+/// `N + 1` elements followed by the length as `uint64_t`.
+///
+/// Since the data might be written by a C++ application, the content is not guaranteed to be valid UTF-8.
+/// In this case, [`as_str`](Self::as_str) returns the longest valid prefix and [`as_bytes`](Self::as_bytes)
+/// can be used to access the raw data.
+///
+/// # Example
+/// ```
+/// use iceoryx_rs::containers::FixedString;
+///
+/// use std::convert::TryFrom;
+///
+/// # use anyhow::{anyhow, Result};
+/// # fn main() -> Result<()> {
+/// let mut name = FixedString::<16>::try_from("hypno")?;
+/// name.push_str("toad")?;
+/// assert_eq!(&*name, "hypnotoad");
+/// # Ok(())
+/// # }
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, ShmSend)]
+pub struct FixedString<const N: usize> {
+    data: [u8; N],
+    // the null-terminator of the C++ string; `[u8; N + 1]` cannot be used with const generics
+    terminator: u8,
+    len: u64,
+}
+
+impl<const N: usize> FixedString<N> {
+    /// Creates a new and empty `FixedString`
+    pub const fn new() -> Self {
+        Self {
+            data: [0; N],
+            terminator: 0,
+            len: 0,
+        }
+    }
+
+    /// Creates a `FixedString` from `value` and truncates it to the capacity if necessary
+    ///
+    /// The truncation happens on a char boundary.
+    pub fn from_str_truncated(value: &str) -> Self {
+        let mut len = value.len().min(N);
+        while !value.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        let mut string = Self::new();
+        string.push_str(&value[..len]).expect("A truncated string");
+        string
+    }
+
+    /// The maximum number of bytes the `FixedString` can hold
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// The number of bytes of the string
+    pub fn len(&self) -> usize {
+        // the length might be corrupted if the data was written by a C++ application
+        (self.len as usize).min(N)
+    }
+
+    /// Checks whether the string is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The raw bytes of the string without the null-terminator
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data[..self.len()]
+    }
+
+    /// The string slice
+    ///
+    /// If the data is not valid UTF-8, which can only happen when it was written by a C++ application,
+    /// the longest valid prefix is returned.
+    pub fn as_str(&self) -> &str {
+        let bytes = self.as_bytes();
+        match std::str::from_utf8(bytes) {
+            Ok(string) => string,
+            // SAFETY: `valid_up_to` is the length of the valid UTF-8 prefix
+            Err(e) => unsafe { std::str::from_utf8_unchecked(&bytes[..e.valid_up_to()]) },
+        }
+    }
+
+    /// Appends a char to the end of the string
+    ///
+    /// If there is not enough capacity, the char is returned as error.
+    pub fn push(&mut self, c: char) -> Result<(), char> {
+        let mut buffer = [0; 4];
+        self.push_str(c.encode_utf8(&mut buffer)).map_err(|_| c)
+    }
+
+    /// Appends a string slice to the end of the string
+    ///
+    /// If there is not enough capacity, the string is not modified.
+    pub fn push_str(&mut self, value: &str) -> Result<(), IceoryxError> {
+        let len = self.len();
+        let required = len + value.len();
+        if required > N {
+            return Err(IceoryxError::CapacityExceeded {
+                capacity: N,
+                required,
+            });
+        }
+
+        self.data[len..required].copy_from_slice(value.as_bytes());
+        self.set_len(required);
+        Ok(())
+    }
+
+    /// Removes the last char and returns it
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        self.set_len(self.as_str().len() - c.len_utf8());
+        Some(c)
+    }
+
+    /// Shortens the string to `len` bytes
+    ///
+    /// If `len` is greater than the current length, this has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` does not lie on a char boundary.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            assert!(
+                self.as_str().is_char_boundary(len),
+                "The new length must lie on a char boundary!"
+            );
+            self.set_len(len);
+        }
+    }
+
+    /// Removes all the content of the string
+    pub fn clear(&mut self) {
+        self.set_len(0);
+    }
+
+    fn set_len(&mut self, len: usize) {
+        // the null-terminator is required by the C++ string
+        if len < N {
+            self.data[len] = 0;
+        } else {
+            self.terminator = 0;
+        }
+        self.len = len as u64;
+    }
+}
+
+impl<const N: usize> Default for FixedString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for FixedString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for FixedString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for FixedString<N> {
+    type Error = IceoryxError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut string = Self::new();
+        string.push_str(value)?;
+        Ok(string)
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<FixedString<M>> for FixedString<N> {
+    fn eq(&self, other: &FixedString<M>) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> Eq for FixedString<N> {}
+
+impl<const N: usize> PartialEq<str> for FixedString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for FixedString<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> Hash for FixedString<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::marker::ShmSend;
+use crate::IceoryxError;
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+
+/// A vector with a fixed capacity of `N` elements
+///
+/// The memory layout is the same as `iox::cxx::vector<T, N>`, i.e. an array with `N` elements followed
+/// by the length as `uint64_t`.
+///
+/// Since `T` is [`ShmSend`] and therefore does not implement `Drop`, removing elements does not need to
+/// drop them.
+///
+/// # Example
+/// ```
+/// use iceoryx_rs::containers::FixedVec;
+///
+/// # use anyhow::{anyhow, Result};
+/// # fn main() -> Result<()> {
+/// let mut values = FixedVec::<u32, 4>::new();
+/// values.push(13).map_err(|_| anyhow!("Capacity exceeded"))?;
+/// values.extend_from_slice(&[37, 42])?;
+/// assert_eq!(&*values, &[13, 37, 42]);
+/// # Ok(())
+/// # }
+/// ```
+#[repr(C)]
+#[derive(ShmSend)]
+pub struct FixedVec<T: ShmSend, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    len: u64,
+}
+
+impl<T: ShmSend, const N: usize> FixedVec<T, N> {
+    /// Creates a new and empty `FixedVec`
+    pub const fn new() -> Self {
+        Self {
+            data: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// The maximum number of elements the `FixedVec` can hold
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// The number of elements in the vector
+    pub fn len(&self) -> usize {
+        // the length might be corrupted if the data was written by a C++ application
+        (self.len as usize).min(N)
+    }
+
+    /// Checks whether the vector is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks whether the capacity is exhausted
+    pub fn is_full(&self) -> bool {
+        self.len() == N
+    }
+
+    /// The slice with all elements of the vector
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` elements are initialized
+        unsafe { std::slice::from_raw_parts(self.data.as_ptr().cast::<T>(), self.len()) }
+    }
+
+    /// The mutable slice with all elements of the vector
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.len();
+        // SAFETY: the first `len` elements are initialized
+        unsafe { std::slice::from_raw_parts_mut(self.data.as_mut_ptr().cast::<T>(), len) }
+    }
+
+    /// Appends an element to the end of the vector
+    ///
+    /// If there is not enough capacity, the element is returned as error.
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        let len = self.len();
+        if len == N {
+            return Err(value);
+        }
+
+        self.data[len] = MaybeUninit::new(value);
+        self.len = (len + 1) as u64;
+        Ok(())
+    }
+
+    /// Removes the last element and returns it
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.len().checked_sub(1)?;
+        self.len = len as u64;
+        // SAFETY: the element was initialized and is not accessible by the vector anymore
+        Some(unsafe { self.data[len].as_ptr().read() })
+    }
+
+    /// Shortens the vector to `len` elements
+    ///
+    /// If `len` is greater than the current length, this has no effect.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.len = len as u64;
+        }
+    }
+
+    /// Removes all elements of the vector
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<T: ShmSend + Clone, const N: usize> FixedVec<T, N> {
+    /// Appends all elements of a slice to the end of the vector
+    ///
+    /// If there is not enough capacity, the vector is not modified.
+    pub fn extend_from_slice(&mut self, values: &[T]) -> Result<(), IceoryxError> {
+        let len = self.len();
+        let required = len + values.len();
+        if required > N {
+            return Err(IceoryxError::CapacityExceeded {
+                capacity: N,
+                required,
+            });
+        }
+
+        for (element, value) in self.data[len..required].iter_mut().zip(values) {
+            *element = MaybeUninit::new(value.clone());
+        }
+        self.len = required as u64;
+        Ok(())
+    }
+}
+
+impl<T: ShmSend, const N: usize> Default for FixedVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ShmSend + Clone, const N: usize> Clone for FixedVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut vec = Self::new();
+        vec.extend_from_slice(self.as_slice())
+            .expect("A vector with the same capacity");
+        vec
+    }
+}
+
+impl<T: ShmSend, const N: usize> Deref for FixedVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T: ShmSend, const N: usize> DerefMut for FixedVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: ShmSend, const N: usize> AsRef<[T]> for FixedVec<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: ShmSend + Clone, const N: usize> TryFrom<&[T]> for FixedVec<T, N> {
+    type Error = IceoryxError;
+
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        let mut vec = Self::new();
+        vec.extend_from_slice(values)?;
+        Ok(vec)
+    }
+}
+
+impl<'a, T: ShmSend, const N: usize> IntoIterator for &'a FixedVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<'a, T: ShmSend, const N: usize> IntoIterator for &'a mut FixedVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T: ShmSend + fmt::Debug, const N: usize> fmt::Debug for FixedVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T: ShmSend + PartialEq, const N: usize, const M: usize> PartialEq<FixedVec<T, M>>
+    for FixedVec<T, N>
+{
+    fn eq(&self, other: &FixedVec<T, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: ShmSend + Eq, const N: usize> Eq for FixedVec<T, N> {}
+
+impl<T: ShmSend + PartialEq, const N: usize> PartialEq<[T]> for FixedVec<T, N> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
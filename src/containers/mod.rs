@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+//! Containers with a fixed capacity which can be transferred via shared memory
+//!
+//! The containers have the same memory layout as their counterparts from the iceoryx C++ library and can
+//! be used to communicate with C++ applications.
+
+mod fixed_string;
+pub use fixed_string::FixedString;
+
+mod fixed_vec;
+pub use fixed_vec::FixedVec;
@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::st;
+use crate::IceoryxError;
+use crate::SampleReceiverWaitState;
+use crate::SubscribeState;
+use crate::SubscriberBuilder;
+
+use std::time::{Duration, Instant};
+
+/// A subscriber to the service registry which `RouDi` publishes whenever a service is offered or stopped offering
+///
+/// iceoryx does not notify about changes of the subscription state but these changes are only processed by the
+/// discovery loop of `RouDi`. A subscriber which requests to subscribe after it was created is connected by the
+/// discovery loop and receives the last service registry as history at this point, i.e. the reception of this
+/// sample signals that the discovery loop ran.
+pub(crate) struct ServiceRegistryWatcher {
+    sample_receiver: st::SampleReceiver<[u8]>,
+    _subscriber: st::Subscriber<[u8]>,
+}
+
+impl ServiceRegistryWatcher {
+    /// Creates a watcher which receives the current service registry and all subsequent changes
+    pub(crate) fn new() -> Result<Self, IceoryxError> {
+        let (subscriber, sample_receive_token) = Self::builder().create()?;
+        Ok(Self {
+            sample_receiver: subscriber.get_sample_receiver(sample_receive_token),
+            _subscriber: subscriber,
+        })
+    }
+
+    /// Creates a watcher which receives the service registry after the next run of the discovery loop of `RouDi`
+    pub(crate) fn after_next_discovery_loop() -> Result<Self, IceoryxError> {
+        let (subscriber, sample_receive_token) =
+            Self::builder().create_without_subscribe()?.subscribe();
+        Ok(Self {
+            sample_receiver: subscriber.get_sample_receiver(sample_receive_token),
+            _subscriber: subscriber,
+        })
+    }
+
+    /// Requests to subscribe again after the discovery loop disconnected the subscriber
+    ///
+    /// The queue is cleared to not mistake a stale service registry for the one received after the next run of the
+    /// discovery loop.
+    fn resubscribe(&self) {
+        self.sample_receiver.clear();
+        self.sample_receiver.ffi_sub().as_ref().subscribe();
+    }
+
+    fn unsubscribe(&self) {
+        self.sample_receiver.ffi_sub().as_ref().unsubscribe();
+    }
+
+    fn is_disconnected(&self) -> bool {
+        self.sample_receiver.subscription_state() == SubscribeState::NotSubscribed
+    }
+
+    /// Notifies `condition_variable` with `notification_index` when a service registry is received
+    pub(crate) fn attach_condition_variable(
+        &self,
+        condition_variable: &ffi::ConditionVariable,
+        notification_index: u64,
+    ) -> Result<(), IceoryxError> {
+        self.sample_receiver
+            .attach_condition_variable(condition_variable, notification_index)?;
+
+        // a service registry which was received before the attachment does not trigger a notification
+        if self.sample_receiver.has_data() {
+            condition_variable.notify(notification_index);
+        }
+
+        Ok(())
+    }
+
+    fn builder() -> SubscriberBuilder<'static, [u8]> {
+        SubscriberBuilder::<[u8]>::new("ServiceDiscovery", "RouDi_ID", "ServiceRegistry")
+            .queue_capacity(1)
+            .history_request(1)
+    }
+
+    fn take_registry(&self) -> Option<st::Sample<[u8]>> {
+        self.sample_receiver.take()
+    }
+
+    /// Blocks until a service registry is received or the `deadline` passed
+    pub(crate) fn wait_for_registry(&self, deadline: Instant) -> Option<st::Sample<[u8]>> {
+        loop {
+            if let Some(registry) = self.take_registry() {
+                return Some(registry);
+            }
+
+            let timeout = deadline.checked_duration_since(Instant::now())?;
+            match self.sample_receiver.wait_for_samples(timeout) {
+                SampleReceiverWaitState::SamplesAvailable => continue,
+                _ => return None,
+            }
+        }
+    }
+}
+
+/// Blocks until `is_processed` returns `true` for a received service registry or the `timeout` elapsed
+///
+/// This is used for the offer state which is part of the service registry.
+pub(crate) fn wait_for_service_registry(
+    timeout: Duration,
+    mut is_processed: impl FnMut(&[u8]) -> bool,
+) -> Result<(), IceoryxError> {
+    let deadline = Instant::now() + timeout;
+    let watcher = ServiceRegistryWatcher::new()?;
+    while let Some(registry) = watcher.wait_for_registry(deadline) {
+        if is_processed(&registry) {
+            return Ok(());
+        }
+    }
+
+    Err(IceoryxError::DiscoveryTimeout { timeout })
+}
+
+/// Signals each run of the discovery loop of `RouDi` by reusing a few [`ServiceRegistryWatcher`]
+///
+/// A watcher signals only the run of the discovery loop which connects it. It can request to subscribe again once the
+/// discovery loop processed its unsubscribe request, otherwise both requests cancel each other out. Therefore a
+/// disconnected spare watcher takes over for the next run and a new one is only created if there is none.
+pub(crate) struct DiscoveryLoopWatcher<'c> {
+    active: ServiceRegistryWatcher,
+    spares: Vec<ServiceRegistryWatcher>,
+    has_signaled: bool,
+    condition_variable: Option<(&'c ffi::ConditionVariable, u64)>,
+}
+
+impl<'c> DiscoveryLoopWatcher<'c> {
+    /// Creates a watcher which signals the next run of the discovery loop
+    pub(crate) fn new() -> Result<Self, IceoryxError> {
+        Ok(Self {
+            active: ServiceRegistryWatcher::after_next_discovery_loop()?,
+            spares: Vec::new(),
+            has_signaled: false,
+            condition_variable: None,
+        })
+    }
+
+    /// Notifies `condition_variable` with `notification_index` after each run of the discovery loop
+    ///
+    /// [`watch_next_discovery_loop_if_signaled`](Self::watch_next_discovery_loop_if_signaled) needs to be called
+    /// after each notification to be notified after the next run.
+    pub(crate) fn attach_condition_variable(
+        &mut self,
+        condition_variable: &'c ffi::ConditionVariable,
+        notification_index: u64,
+    ) -> Result<(), IceoryxError> {
+        for watcher in self.spares.iter().chain(std::iter::once(&self.active)) {
+            watcher.attach_condition_variable(condition_variable, notification_index)?;
+        }
+        self.condition_variable = Some((condition_variable, notification_index));
+
+        Ok(())
+    }
+
+    /// Watches the next run of the discovery loop if the last one was signaled
+    ///
+    /// The received service registries are drained and the watcher keeps signaling the last run until this is called.
+    pub(crate) fn watch_next_discovery_loop_if_signaled(&mut self) -> Result<(), IceoryxError> {
+        while self.active.take_registry().is_some() {
+            self.has_signaled = true;
+        }
+
+        if self.has_signaled {
+            self.watch_next_discovery_loop()?;
+        }
+
+        Ok(())
+    }
+
+    /// Blocks until the discovery loop ran since the last signal or the `deadline` passed
+    ///
+    /// Returns `false` if the `deadline` passed.
+    pub(crate) fn wait(&mut self, deadline: Instant) -> Result<bool, IceoryxError> {
+        if self.has_signaled {
+            self.watch_next_discovery_loop()?;
+        }
+
+        self.has_signaled = self.active.wait_for_registry(deadline).is_some();
+        Ok(self.has_signaled)
+    }
+
+    fn watch_next_discovery_loop(&mut self) -> Result<(), IceoryxError> {
+        let next = match self
+            .spares
+            .iter()
+            .position(ServiceRegistryWatcher::is_disconnected)
+        {
+            Some(index) => {
+                let spare = self.spares.swap_remove(index);
+                spare.resubscribe();
+                spare
+            }
+            None => {
+                let watcher = ServiceRegistryWatcher::after_next_discovery_loop()?;
+                if let Some((condition_variable, notification_index)) = self.condition_variable {
+                    watcher.attach_condition_variable(condition_variable, notification_index)?;
+                }
+                watcher
+            }
+        };
+
+        let previous = std::mem::replace(&mut self.active, next);
+        previous.unsubscribe();
+        self.spares.push(previous);
+        self.has_signaled = false;
+
+        Ok(())
+    }
+}
+
+/// Blocks until `is_processed` returns `true` or the `timeout` elapsed
+///
+/// The condition is checked after each run of the discovery loop of `RouDi`. This is used for the subscription
+/// state which can only change in the discovery loop.
+pub(crate) fn wait_for_discovery_loop(
+    timeout: Duration,
+    mut is_processed: impl FnMut() -> bool,
+) -> Result<(), IceoryxError> {
+    if is_processed() {
+        return Ok(());
+    }
+
+    let deadline = Instant::now() + timeout;
+    let mut watcher = DiscoveryLoopWatcher::new()?;
+    while watcher.wait(deadline)? {
+        if is_processed() {
+            return Ok(());
+        }
+    }
+
+    Err(IceoryxError::DiscoveryTimeout { timeout })
+}
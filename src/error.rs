@@ -2,14 +2,45 @@
 // SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
 // SPDX-FileContributor: Mathias Kraus
 
+use crate::TypeIdentity;
+use crate::{ClientSendError, ServerSendError};
+
 use thiserror::Error;
 
+use std::time::Duration;
+
 /// Error which can occur when using iceoryx
 #[derive(Error, Debug)]
 pub enum IceoryxError {
     /// Loaning a sample failed, e.g. due to exhausted memory pools.
     #[error("Could not loan sample")]
     LoanSampleFailed,
+    /// All chunks of the memory pool for the requested size are in use
+    #[error("Memory pools exhausted! Requested size: {size}; Requested alignment: {alignment} ")]
+    MempoolsExhausted {
+        /// The requested payload size
+        size: usize,
+        /// The requested payload alignment
+        alignment: usize,
+    },
+    /// There is no memory pool with chunks large enough for the requested size
+    #[error(
+        "No memory pool for requested size! Requested size: {size}; Requested alignment: {alignment} "
+    )]
+    NoMempoolForRequestedSize {
+        /// The requested payload size
+        size: usize,
+        /// The requested payload alignment
+        alignment: usize,
+    },
+    /// The maximum number of samples loaned by a single publisher at the same time is exhausted
+    #[error("Number of allowed samples to loan is exhausted! Requested size: {size}; Requested alignment: {alignment} ")]
+    TooManySamplesLoanedInParallel {
+        /// The requested payload size
+        size: usize,
+        /// The requested payload alignment
+        alignment: usize,
+    },
     /// The requested alignment is invalid, e.g. smaller than required by the underlying type
     #[error("Invalid alignment! Requested: {requested}; Min required: {min_required} ")]
     InvalidAlignment {
@@ -18,13 +49,224 @@ pub enum IceoryxError {
         /// The required minimal alignment
         min_required: usize,
     },
+    /// The alignment of the user header is invalid, i.e. larger than supported by the chunk
+    #[error(
+        "Invalid user header alignment! Requested: {requested}; Max supported: {max_supported} "
+    )]
+    InvalidUserHeaderAlignment {
+        /// The requested alignment
+        requested: usize,
+        /// The supported maximal alignment
+        max_supported: usize,
+    },
     /// Creation of the publisher failed, e.g. due to exhausted resources
     #[error("Could not create publisher")]
     PublisherCreationFailed,
     /// Creation of the subscriber failed, e.g. due to exhausted resources
     #[error("Could not create subscriber")]
     SubscriberCreationFailed,
+    /// Creation of the client failed, e.g. due to exhausted resources
+    #[error("Could not create client")]
+    ClientCreationFailed,
+    /// Creation of the server failed, e.g. due to exhausted resources
+    #[error("Could not create server")]
+    ServerCreationFailed,
+    /// Creation of the node failed, e.g. since the maximum number of nodes is reached
+    #[error("Could not create node")]
+    NodeCreationFailed,
+    /// Sending a request failed, e.g. since the client is not connected to a server
+    #[error("Could not send request: {0:?}")]
+    SendRequestFailed(ClientSendError),
+    /// Sending a response failed, e.g. since the client is not available anymore
+    #[error("Could not send response: {0:?}")]
+    SendResponseFailed(ServerSendError),
+    /// The maximum number of attachments, e.g. to a `WaitSet`, is exhausted
+    #[error("Number of allowed attachments is exhausted")]
+    TooManyAttachments,
+    /// The `SampleReceiver` is already attached, e.g. to a `WaitSet`
+    #[error("Already attached")]
+    AlreadyAttached,
     /// The number of maximum number of samples hold in parallel is exhausted
     #[error("Number of allowed samples to hold is exhausted")]
     TooManySamplesHoldInParallel,
+    /// The size of the user header of a received sample does not match the expected user header
+    #[error("User header size mismatch! Expected: {expected}; Received: {received} ")]
+    UserHeaderSizeMismatch {
+        /// The size of the expected user header
+        expected: usize,
+        /// The size of the user header of the received sample
+        received: usize,
+    },
+    /// The payload size of a received sample does not match the size of the expected type
+    #[error("Payload size mismatch! Expected: {expected}; Received: {received} ")]
+    PayloadSizeMismatch {
+        /// The size of the expected type; for slices the payload size must be a multiple of the element size
+        expected: usize,
+        /// The payload size of the received sample
+        received: usize,
+    },
+    /// The payload alignment of a received sample is smaller than required by the expected type
+    #[error("Alignment mismatch! Min required: {min_required}; Received: {received} ")]
+    AlignmentMismatch {
+        /// The required minimal alignment
+        min_required: usize,
+        /// The payload alignment of the received sample
+        received: usize,
+    },
+    /// The type of a received sample does not match the type of the subscriber
+    #[error("Type mismatch! Expected: {expected}; Received: {received} ")]
+    TypeMismatch {
+        /// The type identity of the subscriber
+        expected: TypeIdentity,
+        /// The type identity of the received sample
+        received: TypeIdentity,
+    },
+    /// The capacity of a fixed size container is too small for the requested content
+    #[error("Capacity exceeded! Capacity: {capacity}; Required: {required} ")]
+    CapacityExceeded {
+        /// The capacity of the container
+        capacity: usize,
+        /// The required capacity
+        required: usize,
+    },
+    /// `RouDi` was not available within the waiting timeout of the runtime registration
+    #[error("RouDi not available! Waiting timeout: {timeout:?} ")]
+    RouDiNotAvailable {
+        /// The timeout for waiting on `RouDi`
+        timeout: Duration,
+    },
+    /// An application with the same name is already registered at `RouDi`
+    #[error("Name already registered! Name: {name} ")]
+    NameAlreadyRegistered {
+        /// The name of the application
+        name: String,
+    },
+    /// The name of the application or node is empty, too long or contains characters which are not allowed
+    #[error("Invalid name! Name: {name} ")]
+    InvalidName {
+        /// The name of the application or node
+        name: String,
+    },
+    /// The process is already registered at `RouDi`
+    #[error("Runtime already initialized")]
+    RuntimeAlreadyInitialized,
+    /// The runtime cannot be shut down since objects created with it are still alive
+    #[error("Runtime in use! Name: {name}; Alive objects: {count} ")]
+    RuntimeInUse {
+        /// The name of the application
+        name: String,
+        /// The number of alive objects which were created with the runtime
+        count: usize,
+    },
+    /// There is already a `RouDi` running, either in the current or in another process
+    #[error("RouDi already running")]
+    RouDiAlreadyRunning,
+    /// The file lock which protects the resources of `RouDi` could not be created, e.g. due to missing access rights
+    #[error("RouDi lock unavailable")]
+    RouDiLockUnavailable,
+    /// `RouDi` cannot be shut down since the runtime of the current process is still registered at it
+    #[error("RouDi in use")]
+    RouDiInUse,
+    /// The `RouDi` config cannot be used, e.g. since it exceeds the limits of iceoryx
+    #[error("Invalid RouDi config! Reason: {reason} ")]
+    InvalidRouDiConfig {
+        /// The reason why the config is invalid
+        reason: String,
+    },
+    /// The name of a service is empty, too long or contains characters which are not allowed
+    #[error("Invalid service name! Name: {name}; Reason: {reason} ")]
+    InvalidServiceName {
+        /// The name of the service in the form `service/instance/event`
+        name: String,
+        /// The reason why the name is invalid
+        reason: String,
+    },
+    /// `RouDi` did not process an offer or subscription request within the waiting timeout
+    #[error("Discovery timeout! Waiting timeout: {timeout:?} ")]
+    DiscoveryTimeout {
+        /// The timeout for waiting on `RouDi`
+        timeout: Duration,
+    },
+    /// A value could not be serialized by a codec of the `serde` feature
+    #[error("Serialization failed! Reason: {reason} ")]
+    SerializationFailed {
+        /// The error reported by the codec
+        reason: String,
+    },
+    /// The payload of a sample could not be deserialized by a codec of the `serde` feature
+    #[error("Deserialization failed! Reason: {reason} ")]
+    DeserializationFailed {
+        /// The error reported by the codec
+        reason: String,
+    },
+    /// The payload of a sample is not a valid FlatBuffer of the `flatbuffers` feature
+    #[error("Invalid FlatBuffer! Reason: {reason} ")]
+    InvalidFlatBuffer {
+        /// The reason why the FlatBuffer is invalid
+        reason: String,
+    },
+}
+
+impl From<ffi::ChunkTakeError> for IceoryxError {
+    fn from(error: ffi::ChunkTakeError) -> Self {
+        match error {
+            ffi::ChunkTakeError::TooManyChunksHeldInParallel => {
+                IceoryxError::TooManySamplesHoldInParallel
+            }
+            ffi::ChunkTakeError::UserHeaderSizeMismatch { expected, actual } => {
+                IceoryxError::UserHeaderSizeMismatch {
+                    expected,
+                    received: actual,
+                }
+            }
+            ffi::ChunkTakeError::PayloadSizeMismatch { expected, actual } => {
+                IceoryxError::PayloadSizeMismatch {
+                    expected,
+                    received: actual,
+                }
+            }
+            ffi::ChunkTakeError::AlignmentMismatch { required, actual } => {
+                IceoryxError::AlignmentMismatch {
+                    min_required: required,
+                    received: actual,
+                }
+            }
+        }
+    }
+}
+
+impl From<ffi::RouDiLaunchError> for IceoryxError {
+    fn from(error: ffi::RouDiLaunchError) -> Self {
+        match error {
+            ffi::RouDiLaunchError::AlreadyRunning => IceoryxError::RouDiAlreadyRunning,
+            ffi::RouDiLaunchError::LockUnavailable => IceoryxError::RouDiLockUnavailable,
+        }
+    }
+}
+
+impl IceoryxError {
+    /// Converts the allocation error of a publisher, client or server with the requested `size` and `alignment`
+    /// of the payload
+    pub(crate) fn from_allocation_error(
+        error: ffi::AllocationError,
+        size: usize,
+        alignment: usize,
+    ) -> Self {
+        match error {
+            ffi::AllocationError::RunningOutOfChunks => {
+                IceoryxError::MempoolsExhausted { size, alignment }
+            }
+            ffi::AllocationError::NoMempoolsAvailable => {
+                IceoryxError::NoMempoolForRequestedSize { size, alignment }
+            }
+            ffi::AllocationError::TooManyChunksAllocatedInParallel => {
+                IceoryxError::TooManySamplesLoanedInParallel { size, alignment }
+            }
+            ffi::AllocationError::UndefinedError
+            | ffi::AllocationError::InvalidParameterForUserPayloadOrUserHeader
+            | ffi::AllocationError::InvalidParameterForRequestHeader => {
+                IceoryxError::LoanSampleFailed
+            }
+        }
+    }
 }
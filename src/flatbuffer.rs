@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+//! Zero-copy [FlatBuffers](https://crates.io/crates/flatbuffers) with `[u8]` publisher and subscriber
+//!
+//! This module is only available with the `flatbuffers` feature. The message is built with a
+//! `FlatBufferBuilder` from [`Publisher::flatbuffer_builder`](crate::Publisher::flatbuffer_builder) directly in
+//! the loaned chunk and published with [`Publisher::publish_flatbuffer`](crate::Publisher::publish_flatbuffer).
+//! The subscriber reads the message in place with [`Sample::flatbuffer_root`](crate::Sample::flatbuffer_root).
+//!
+//! Since a FlatBuffer is built from the back to the front, the finished message is located at the end of the
+//! chunk. Instead of moving the message to the front, its start is stored in a prefix of 8 bytes at the
+//! beginning of the payload, i.e. the payload is not a plain FlatBuffer. Use
+//! [`Sample::flatbuffer_data`](crate::Sample::flatbuffer_data) to get the plain FlatBuffer.
+//!
+//! # Example
+//! ```
+//! # use iceoryx_rs::Runtime;
+//! use iceoryx_rs::{PublisherBuilder, SubscriberBuilder};
+//! # use ffi::RouDiEnvironment;
+//!
+//! # use anyhow::{anyhow, Result};
+//! # fn main() -> Result<()> {
+//! # let _roudi = RouDiEnvironment::new();
+//! #
+//! # Runtime::init("flatbuffer");
+//! let publisher = PublisherBuilder::<[u8]>::new("all", "glory", "hypnotoad").create()?;
+//! let (subscriber, sample_receive_token) =
+//!     SubscriberBuilder::<[u8]>::new("all", "glory", "hypnotoad").create()?;
+//! let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+//!
+//! let mut builder = publisher.flatbuffer_builder(1024)?;
+//! let greeting = builder.create_string("All glory to the hypnotoad");
+//! builder.finish_minimal(greeting);
+//! publisher.publish_flatbuffer(builder);
+//!
+//! let sample = sample_receiver.take().ok_or_else(|| anyhow!("No sample received"))?;
+//! assert_eq!(sample.flatbuffer_root::<&str>()?, "All glory to the hypnotoad");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::marker::ShmSend;
+use crate::user_header::NoUserHeader;
+use crate::IceoryxError;
+use crate::Publisher;
+use crate::SampleMut;
+
+use std::convert::TryFrom;
+use std::ops::{Deref, DerefMut};
+
+/// The size of the prefix which stores the start of the finished FlatBuffer as little endian `u32`
+const PREFIX_SIZE: usize = 8;
+
+/// The alignment of the chunk; this is the largest alignment of the FlatBuffers scalars
+const ALIGNMENT: usize = 8;
+
+/// The capacity of the first chunk if the builder was created with a capacity of 0
+const MIN_CAPACITY: usize = 64;
+
+/// FlatBuffers allocator which places the message directly in a chunk loaned from a publisher
+///
+/// If the capacity of the chunk is exhausted, a chunk with twice the capacity is loaned, the message is
+/// moved and the previous chunk is released. This is a copy in the shared memory, therefore the initial
+/// capacity should be large enough for the message. The `FlatBufferBuilder` panics if no chunk can be loaned.
+pub struct ChunkAllocator<'a, H: ShmSend + Default = NoUserHeader> {
+    publisher: &'a Publisher<[u8], H>,
+    sample: SampleMut<'a, [u8], H>,
+}
+
+impl<'a, H: ShmSend + Default> ChunkAllocator<'a, H> {
+    pub(crate) fn new(
+        publisher: &'a Publisher<[u8], H>,
+        capacity: usize,
+    ) -> Result<Self, IceoryxError> {
+        Ok(Self {
+            publisher,
+            sample: loan_chunk(publisher, capacity)?,
+        })
+    }
+
+    /// Stores the start of the message in the prefix and returns the sample with the finished message
+    pub(crate) fn into_sample(
+        self,
+        publisher: &Publisher<[u8], H>,
+        head: usize,
+    ) -> SampleMut<'a, [u8], H> {
+        assert!(
+            std::ptr::eq(self.publisher, publisher),
+            "The FlatBufferBuilder was created by a different publisher"
+        );
+        let head = u32::try_from(head).expect("FlatBuffers are limited to 2 GB");
+
+        let mut sample = self.sample;
+        sample[..4].copy_from_slice(&head.to_le_bytes());
+        sample
+    }
+}
+
+fn loan_chunk<H: ShmSend + Default>(
+    publisher: &Publisher<[u8], H>,
+    capacity: usize,
+) -> Result<SampleMut<'_, [u8], H>, IceoryxError> {
+    // the FlatBuffers are aligned relative to the end of the buffer
+    let capacity = capacity.div_ceil(ALIGNMENT) * ALIGNMENT;
+    publisher.loan_slice_with_alignment(PREFIX_SIZE + capacity, ALIGNMENT)
+}
+
+impl<'a, H: ShmSend + Default> Deref for ChunkAllocator<'a, H> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.sample[PREFIX_SIZE..]
+    }
+}
+
+impl<'a, H: ShmSend + Default> DerefMut for ChunkAllocator<'a, H> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.sample[PREFIX_SIZE..]
+    }
+}
+
+// SAFETY: the buffer grows and the old content is moved to the end of the new buffer
+unsafe impl<'a, H: ShmSend + Default> flatbuffers::Allocator for ChunkAllocator<'a, H> {
+    type Error = IceoryxError;
+
+    fn grow_downwards(&mut self) -> Result<(), Self::Error> {
+        let len = self.len();
+        let mut sample = loan_chunk(self.publisher, (len * 2).max(MIN_CAPACITY))?;
+        let new_len = sample.len() - PREFIX_SIZE;
+        sample[PREFIX_SIZE + new_len - len..].copy_from_slice(&self[..]);
+        // the previous chunk is released when the sample is dropped
+        self.sample = sample;
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        self.sample.len() - PREFIX_SIZE
+    }
+}
+
+/// Returns the plain FlatBuffer from the payload of a sample published with `publish_flatbuffer`
+pub(crate) fn finished_data(payload: &[u8]) -> Result<&[u8], IceoryxError> {
+    if payload.len() < PREFIX_SIZE {
+        return Err(IceoryxError::InvalidFlatBuffer {
+            reason: format!(
+                "the payload of {} bytes is smaller than the prefix",
+                payload.len()
+            ),
+        });
+    }
+
+    let mut head = [0u8; 4];
+    head.copy_from_slice(&payload[..4]);
+    let head = u32::from_le_bytes(head) as usize;
+
+    payload[PREFIX_SIZE..]
+        .get(head..)
+        .ok_or_else(|| IceoryxError::InvalidFlatBuffer {
+            reason: format!("the start {} is out of the payload", head),
+        })
+}
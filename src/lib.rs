@@ -13,7 +13,7 @@
 //! `Getting started` section on [iceoryx.io](https://iceoryx.io) or the
 //! [README.md](https://github.com/eclipse-iceoryx/iceoryx/blob/master/README.md) of the main project.
 //!
-//! The Rust bindings are a work in progress and currently support only the pub-sub messaging pattern.
+//! The Rust bindings are a work in progress and currently support the pub-sub and request-response messaging pattern.
 //! Upcoming releases will close the gap and the goal is to have the Rust bindings as a first class citizen
 //! in the iceoryx ecosystem.
 //!
@@ -22,21 +22,44 @@
 //!
 //! # Limitations
 //!
-//! Currently, only a subset of Eclipse iceoryx v2.0 is supported and some features are missing.
+//! Currently, only a subset of Eclipse iceoryx v2.0 is supported.
 //!
 //! - [x] pub-sub messaging pattern
-//! - [ ] user defined header for pub-sub data
-//! - [ ] request-response messaging pattern
-//! - [ ] `Listener` and `WaitSet`
-//! - [ ] lookup of available services aka `ServiceDiscovery`
+//! - [x] request-response messaging pattern
+//! - [x] user defined header for pub-sub data
+//! - [x] type identity check for pub-sub data with `TypeIdentity`
+//! - [x] `Listener`
+//! - [x] `WaitSet`
+//! - [x] `async`/`await` for the `SampleReceiver` with the `async` feature
+//! - [x] lookup of available services aka `ServiceDiscovery`
 //! - [x] accessing introspection topics like memory usage and available publisher and subscriber
-
+//! - [x] `RouDi` embedded in the application process
+//! - [x] configurable log level and forwarding of the iceoryx log output to the `log` crate with the `log` feature
+//! - [x] test harness with `RouDi` running in the test process with the `testing` feature
+//! - [x] transfer of `serde` serializable types with `[u8]` publisher and subscriber with the `serde` feature
+//! - [x] zero-copy FlatBuffers with `[u8]` publisher and subscriber with the `flatbuffers` feature
+//! - [x] `std::io` and, with the `bytes` feature, `bytes` adapter for `[u8]` samples with `SampleWriter` and `SampleReader`
+
+// enables the derive macros to refer to `::iceoryx_rs` from within this crate
+extern crate self as iceoryx_rs;
+
+#[cfg(feature = "serde")]
+pub mod codec;
+pub mod containers;
+#[cfg(feature = "flatbuffers")]
+pub mod flatbuffer;
 pub mod introspection;
 pub mod marker;
+pub mod roudi;
+
+mod discovery;
 
 mod error;
 pub use error::IceoryxError;
 
+mod node;
+pub use node::Node;
+
 mod publisher;
 pub use publisher::InactivePublisher;
 pub use publisher::Publisher;
@@ -47,25 +70,77 @@ pub use subscriber::InactiveSubscriber;
 pub use subscriber::Subscriber;
 pub use subscriber::SubscriberBuilder;
 
+mod runtime;
+pub use runtime::Runtime;
+pub use runtime::RuntimeBuilder;
+pub use runtime::RuntimeHandle;
+
 mod sample_mut;
 pub use sample_mut::SampleMut;
 
+mod sample_io;
+pub use sample_io::SampleReader;
+pub use sample_io::SampleWriter;
+
+mod client;
+pub use client::Client;
+pub use client::ClientBuilder;
+
+mod server;
+pub use server::Server;
+pub use server::ServerBuilder;
+
+mod request;
+pub use request::Request;
+pub use request::RequestMut;
+
+mod response;
+pub use response::Response;
+pub use response::ResponseMut;
+
 mod sample;
 pub use sample::Sample;
 pub use sample::SampleReceiver;
 pub use sample::SampleReceiverWaitState;
 
+mod listener;
+pub use listener::Listener;
+pub use listener::PublisherListenerAttachment;
+pub use listener::SubscriberListenerAttachment;
+
+mod service_discovery;
+pub use service_discovery::ServiceDiscovery;
+
+mod service_name;
+pub use service_name::ServiceName;
+
+#[cfg(feature = "async")]
+mod reactor;
+
+mod type_identity;
+pub use type_identity::TypeIdentity;
+
+mod user_header;
+pub use user_header::NoUserHeader;
+
+mod wait_set;
+pub use wait_set::SampleReceiverAttachment;
+pub use wait_set::UserTrigger;
+pub use wait_set::WaitSet;
+
 pub mod st {
     //! Single-threaded restricted subscriber
 
     use super::*;
 
     /// A [`Sample`](sample::Sample) from a single-threaded subscriber
-    pub type Sample<T> = sample::Sample<T, ffi::SubscriberRc>;
+    pub type Sample<T, H = NoUserHeader> = sample::Sample<T, ffi::SubscriberRc, H>;
     /// A [`SampleReceiver`](sample::SampleReceiver) from a single-threaded subscriber
-    pub type SampleReceiver<T> = sample::SampleReceiver<T, ffi::SubscriberRc>;
+    pub type SampleReceiver<T, H = NoUserHeader> = sample::SampleReceiver<T, ffi::SubscriberRc, H>;
     /// A single-threaded [`Subscriber`](subscriber::Subscriber)
-    pub type Subscriber<T> = subscriber::Subscriber<T, ffi::SubscriberRc>;
+    pub type Subscriber<T, H = NoUserHeader> = subscriber::Subscriber<T, ffi::SubscriberRc, H>;
+    /// A [`WaitSet`](wait_set::WaitSet) for single-threaded subscriber
+    pub type WaitSet = wait_set::WaitSet<ffi::SubscriberRc>;
 }
 
 pub mod mt {
@@ -74,26 +149,29 @@ pub mod mt {
     use super::*;
 
     /// A [`Sample`](sample::Sample) from a multi-threaded subscriber
-    pub type Sample<T> = sample::Sample<T, ffi::SubscriberArc>;
+    pub type Sample<T, H = NoUserHeader> = sample::Sample<T, ffi::SubscriberArc, H>;
     /// A [`SampleReceiver`](sample::SampleReceiver) from a multi-threaded subscriber
-    pub type SampleReceiver<T> = sample::SampleReceiver<T, ffi::SubscriberArc>;
+    pub type SampleReceiver<T, H = NoUserHeader> = sample::SampleReceiver<T, ffi::SubscriberArc, H>;
     /// A multi-threaded [`Subscriber`](subscriber::Subscriber)
-    pub type Subscriber<T> = subscriber::Subscriber<T, ffi::SubscriberArc>;
+    pub type Subscriber<T, H = NoUserHeader> = subscriber::Subscriber<T, ffi::SubscriberArc, H>;
+    /// A [`WaitSet`](wait_set::WaitSet) for multi-threaded subscriber
+    pub type WaitSet = wait_set::WaitSet<ffi::SubscriberArc>;
 }
 
 // re-exports from iceoryx-sys
+pub use ffi::ClientSendError;
+pub use ffi::ConnectionState;
 pub use ffi::ConsumerTooSlowPolicy;
+pub use ffi::LogLevel;
+pub use ffi::MessagingPattern;
 pub use ffi::QueueFullPolicy;
 pub use ffi::RawSample;
 pub use ffi::RawSampleMut;
-pub use ffi::Runtime;
+pub use ffi::ServerSendError;
 pub use ffi::SubscribeState;
 
-#[cfg(test)]
-pub(crate) mod testing {
-    #[cfg(test)]
-    pub(crate) use ffi::RouDiEnvironment;
-}
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,370 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::discovery::DiscoveryLoopWatcher;
+use crate::marker::ShmSend;
+use crate::mt;
+use crate::runtime::RuntimeResource;
+use crate::subscriber::SampleReceiverToken;
+use crate::IceoryxError;
+use crate::NoUserHeader;
+use crate::Publisher;
+use crate::SampleReceiver;
+
+use std::any::Any;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+
+/// The notification index to wake up the background thread, e.g. to shut down
+const WAKE_UP_NOTIFICATION_INDEX: usize = Listener::CAPACITY;
+
+/// The notification index to check the subscription state of the attached publishers after the discovery loop
+/// of `RouDi` ran
+const DISCOVERY_LOOP_NOTIFICATION_INDEX: usize = Listener::CAPACITY + 1;
+
+type SampleReceiverCallback = Box<dyn FnMut(&(dyn Any + Send)) + Send>;
+
+enum Attachment {
+    Subscriber {
+        sample_receiver: Box<dyn Any + Send>,
+        callback: SampleReceiverCallback,
+    },
+    Publisher {
+        ffi_pub: ffi::PublisherArc,
+        has_subscribers: bool,
+        callback: Box<dyn FnMut(bool) + Send>,
+    },
+}
+
+struct ListenerShared {
+    condition_variable: Box<ffi::ConditionVariable>,
+    _runtime_resource: RuntimeResource,
+    attachments: Mutex<Vec<Option<Attachment>>>,
+    keep_running: AtomicBool,
+}
+
+impl ListenerShared {
+    fn attachments(&self) -> MutexGuard<'_, Vec<Option<Attachment>>> {
+        // a panic in a callback does not leave the attachments in an inconsistent state
+        self.attachments
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Executes callbacks in a background thread when an attached subscriber receives samples or the
+/// subscription state of an attached publisher changes
+///
+/// Only the multi-threaded [`mt::Subscriber`] can be attached since the callbacks are executed in
+/// a different thread.
+///
+/// The callbacks are executed one after another, therefore a long-running callback delays all other
+/// callbacks. Attaching and detaching blocks while a callback is executed. Attaching or detaching from
+/// within a callback results in a deadlock.
+///
+/// # Example
+/// ```
+/// # use iceoryx_rs::Runtime;
+/// use iceoryx_rs::Listener;
+/// use iceoryx_rs::SubscriberBuilder;
+/// # use ffi::RouDiEnvironment;
+/// #
+/// # use anyhow::{anyhow, Result};
+/// # fn main() -> Result<()> {
+/// # let _roudi = RouDiEnvironment::new();
+/// #
+/// # Runtime::init("listener");
+///
+/// let (subscriber, sample_receive_token) =
+///     SubscriberBuilder::<u32>::new("all", "glory", "hypnotoad").create_mt()?;
+///
+/// let listener = Listener::new();
+/// let attachment = listener.attach_subscriber(&subscriber, sample_receive_token, |sample_receiver| {
+///     while let Some(sample) = sample_receiver.take() {
+///         println!("Receiving: {}", *sample);
+///     }
+/// })?;
+///
+/// let sample_receiver = attachment.detach();
+/// subscriber.unsubscribe(sample_receiver);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Listener {
+    shared: Arc<ListenerShared>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl Listener {
+    /// The maximum number of attachments of a single `Listener`
+    pub const CAPACITY: usize = 128;
+
+    /// Creates a new `Listener` and starts the background thread
+    ///
+    /// # Panics
+    ///
+    /// [`Runtime::init`](crate::Runtime::init) must have been called otherwise this method will panic.
+    pub fn new() -> Self {
+        let shared = Arc::new(ListenerShared {
+            condition_variable: ffi::ConditionVariable::new(),
+            _runtime_resource: RuntimeResource::new(),
+            attachments: Mutex::new((0..Self::CAPACITY).map(|_| None).collect()),
+            keep_running: AtomicBool::new(true),
+        });
+
+        let thread_shared = shared.clone();
+        // the background thread creates ports to watch the discovery loop
+        let app_name = ffi::Runtime::app_name();
+        let thread = thread::Builder::new()
+            .name("iox-listener".to_string())
+            .spawn(move || {
+                ffi::Runtime::attach_current_thread(&app_name);
+                Self::run(&thread_shared)
+            })
+            .expect("Spawning the listener thread");
+
+        Self {
+            shared,
+            thread: Some(thread),
+        }
+    }
+
+    /// Attaches a multi-threaded subscriber and calls `callback` when new samples are received
+    ///
+    /// The [`SampleReceiver`] of the subscriber is owned by the `Listener` while the subscriber is attached
+    /// and handed to the callback. It can be retrieved by [`SubscriberListenerAttachment::detach`], e.g.
+    /// to unsubscribe the subscriber. The subscriber cannot be dropped or unsubscribed while it is attached.
+    pub fn attach_subscriber<'s, T, H, F>(
+        &self,
+        subscriber: &'s mt::Subscriber<T, H>,
+        token: SampleReceiverToken,
+        mut callback: F,
+    ) -> Result<SubscriberListenerAttachment<'_, 's, T, H>, IceoryxError>
+    where
+        T: ?Sized + Send + 'static,
+        H: Send + 'static,
+        F: FnMut(&SampleReceiver<T, ffi::SubscriberArc, H>) + Send + 'static,
+    {
+        let mut attachments = self.shared.attachments();
+        let index = Self::acquire_slot(&attachments)?;
+
+        let sample_receiver = subscriber.get_sample_receiver(token);
+        sample_receiver.attach_condition_variable(&self.shared.condition_variable, index as u64)?;
+        let has_data = sample_receiver.has_data();
+
+        attachments[index] = Some(Attachment::Subscriber {
+            sample_receiver: Box::new(sample_receiver),
+            callback: Box::new(move |sample_receiver| {
+                if let Some(sample_receiver) =
+                    sample_receiver.downcast_ref::<SampleReceiver<T, ffi::SubscriberArc, H>>()
+                {
+                    callback(sample_receiver);
+                }
+            }),
+        });
+
+        // samples which were received before the attachment do not trigger a notification
+        if has_data {
+            self.shared.condition_variable.notify(index as u64);
+        }
+
+        Ok(SubscriberListenerAttachment {
+            listener: self,
+            index,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Attaches a publisher and calls `callback` when the subscription state changes
+    ///
+    /// The callback gets the new state of [`Publisher::has_subscribers`] passed. The publisher cannot
+    /// be dropped or stop offering while it is attached.
+    ///
+    /// iceoryx does not notify about changes of the subscription state. Subscribers are connected and
+    /// disconnected by the discovery loop of `RouDi`, therefore the state is checked after each run of the
+    /// discovery loop while publishers are attached.
+    pub fn attach_publisher<'p, T, H, F>(
+        &self,
+        publisher: &'p Publisher<T, H>,
+        callback: F,
+    ) -> Result<PublisherListenerAttachment<'_, 'p>, IceoryxError>
+    where
+        T: ShmSend + ?Sized,
+        H: ShmSend,
+        F: FnMut(bool) + Send + 'static,
+    {
+        let mut attachments = self.shared.attachments();
+        let index = Self::acquire_slot(&attachments)?;
+
+        let ffi_pub = publisher.ffi_pub().clone();
+        attachments[index] = Some(Attachment::Publisher {
+            has_subscribers: ffi_pub.has_subscribers(),
+            ffi_pub,
+            callback: Box::new(callback),
+        });
+
+        // the background thread needs to start watching the discovery loop
+        self.shared
+            .condition_variable
+            .notify(WAKE_UP_NOTIFICATION_INDEX as u64);
+
+        Ok(PublisherListenerAttachment {
+            listener: self,
+            index,
+            phantom: PhantomData,
+        })
+    }
+
+    fn acquire_slot(attachments: &[Option<Attachment>]) -> Result<usize, IceoryxError> {
+        attachments
+            .iter()
+            .position(Option::is_none)
+            .ok_or(IceoryxError::TooManyAttachments)
+    }
+
+    fn detach(&self, index: usize) -> Option<Attachment> {
+        self.shared.attachments()[index].take()
+    }
+
+    fn run(shared: &ListenerShared) {
+        // notifies the background thread after each run of the discovery loop while publishers are attached; it is
+        // created with the first attached publisher and kept for the lifetime of the listener
+        let mut discovery_loop_watcher: Option<DiscoveryLoopWatcher<'_>> = None;
+
+        while shared.keep_running.load(Ordering::Relaxed) {
+            let has_publishers = shared
+                .attachments()
+                .iter()
+                .any(|attachment| matches!(attachment, Some(Attachment::Publisher { .. })));
+
+            if has_publishers {
+                // this fails only if `RouDi` has no ports left and is retried on the next wake up
+                match discovery_loop_watcher.as_mut() {
+                    Some(watcher) => {
+                        let _ = watcher.watch_next_discovery_loop_if_signaled();
+                    }
+                    None => discovery_loop_watcher = Self::watch_discovery_loop(shared).ok(),
+                }
+            }
+
+            let notifications = shared.condition_variable.wait_for_notifications();
+
+            let mut attachments = shared.attachments();
+
+            for index in notifications {
+                if let Some(Some(Attachment::Subscriber {
+                    sample_receiver,
+                    callback,
+                })) = attachments.get_mut(index as usize)
+                {
+                    callback(sample_receiver.as_ref());
+                }
+            }
+
+            for attachment in attachments.iter_mut() {
+                if let Some(Attachment::Publisher {
+                    ffi_pub,
+                    has_subscribers,
+                    callback,
+                }) = attachment
+                {
+                    let current_has_subscribers = ffi_pub.has_subscribers();
+                    if current_has_subscribers != *has_subscribers {
+                        *has_subscribers = current_has_subscribers;
+                        callback(current_has_subscribers);
+                    }
+                }
+            }
+        }
+    }
+
+    fn watch_discovery_loop(
+        shared: &ListenerShared,
+    ) -> Result<DiscoveryLoopWatcher<'_>, IceoryxError> {
+        let mut watcher = DiscoveryLoopWatcher::new()?;
+        watcher.attach_condition_variable(
+            &shared.condition_variable,
+            DISCOVERY_LOOP_NOTIFICATION_INDEX as u64,
+        )?;
+        Ok(watcher)
+    }
+}
+
+impl Default for Listener {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        self.shared.keep_running.store(false, Ordering::Relaxed);
+        self.shared
+            .condition_variable
+            .notify(WAKE_UP_NOTIFICATION_INDEX as u64);
+        if let Some(thread) = self.thread.take() {
+            // a panic in a callback was already reported by the panic hook of the background thread
+            let _ = thread.join();
+        }
+    }
+}
+
+/// The attachment of a subscriber to a [`Listener`]
+///
+/// The subscriber is detached from the `Listener` when this is dropped and the [`SampleReceiver`] is dropped.
+/// Use [`detach`](Self::detach) to retrieve the `SampleReceiver`.
+pub struct SubscriberListenerAttachment<'l, 's, T: ?Sized + 'static, H: 'static = NoUserHeader> {
+    listener: &'l Listener,
+    index: usize,
+    phantom: PhantomData<&'s mt::Subscriber<T, H>>,
+}
+
+impl<'l, 's, T: ?Sized + 'static, H: 'static> SubscriberListenerAttachment<'l, 's, T, H> {
+    /// Detaches the subscriber from the [`Listener`] and returns the [`SampleReceiver`]
+    ///
+    /// This blocks until a currently running callback for this subscriber returned.
+    pub fn detach(self) -> SampleReceiver<T, ffi::SubscriberArc, H> {
+        let sample_receiver = self.detach_sample_receiver();
+        std::mem::forget(self); // forget `self` to not detach again in drop
+        sample_receiver.expect("The sample receiver of an attachment")
+    }
+
+    fn detach_sample_receiver(&self) -> Option<SampleReceiver<T, ffi::SubscriberArc, H>> {
+        match self.listener.detach(self.index) {
+            Some(Attachment::Subscriber {
+                sample_receiver, ..
+            }) => {
+                let sample_receiver = sample_receiver
+                    .downcast::<SampleReceiver<T, ffi::SubscriberArc, H>>()
+                    .ok()?;
+                sample_receiver.detach_condition_variable();
+                Some(*sample_receiver)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'l, 's, T: ?Sized + 'static, H: 'static> Drop for SubscriberListenerAttachment<'l, 's, T, H> {
+    fn drop(&mut self) {
+        self.detach_sample_receiver();
+    }
+}
+
+/// The attachment of a publisher to a [`Listener`]
+///
+/// The publisher is detached from the `Listener` when this is dropped.
+pub struct PublisherListenerAttachment<'l, 'p> {
+    listener: &'l Listener,
+    index: usize,
+    phantom: PhantomData<&'p ()>,
+}
+
+impl<'l, 'p> Drop for PublisherListenerAttachment<'l, 'p> {
+    fn drop(&mut self) {
+        self.listener.detach(self.index);
+    }
+}
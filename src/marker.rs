@@ -4,8 +4,12 @@
 
 //! Marker traits for iceoryx-rs
 
+use std::marker::PhantomData;
 use std::mem::MaybeUninit;
 
+/// Derive macro for the [`ShmSend`](trait@ShmSend) marker trait
+pub use iceoryx_rs_derive::ShmSend;
+
 /// This is a marker trait for types that can be transferred via shared memory.
 ///
 /// The `ShmSend` marker trait is similar to the `Send` marker trait which is used for types that
@@ -21,101 +25,273 @@ use std::mem::MaybeUninit;
 ///   use pointers/references
 /// - the type must not implement `Drop`; `drop` will not be called when the memory is released
 ///   since the memory might be located in a shm segment without write access to the subscriber
+///
 /// In general, types that could implement the Copy trait fulfill these requirements.
 ///
 /// For interoperability with C and C++ the types should also be `#[repr(C)]`.
-pub unsafe trait ShmSend {}
-
-unsafe impl ShmSend for bool {}
-
-unsafe impl ShmSend for i8 {}
-unsafe impl ShmSend for u8 {}
-unsafe impl ShmSend for i16 {}
-unsafe impl ShmSend for u16 {}
-unsafe impl ShmSend for i32 {}
-unsafe impl ShmSend for u32 {}
-unsafe impl ShmSend for i64 {}
-unsafe impl ShmSend for u64 {}
-unsafe impl ShmSend for i128 {}
-unsafe impl ShmSend for u128 {}
-unsafe impl ShmSend for f32 {}
-unsafe impl ShmSend for f64 {}
-unsafe impl ShmSend for isize {}
-unsafe impl ShmSend for usize {}
+///
+/// # Derive
+///
+/// Instead of implementing the trait manually, it should be derived. The derive macro checks that the type is
+/// `#[repr(C)]` or `#[repr(transparent)]`, all fields are `ShmSend` and that the type does not implement `Drop`.
+/// Generic type parameters get a `ShmSend` bound.
+///
+/// ```
+/// use iceoryx_rs::marker::ShmSend;
+///
+/// #[repr(C)]
+/// #[derive(ShmSend)]
+/// struct Position<T> {
+///     x: T,
+///     y: T,
+///     z: T,
+/// }
+/// ```
+///
+/// Types with heap allocations are rejected
+/// ```compile_fail
+/// # use iceoryx_rs::marker::ShmSend;
+/// #[repr(C)]
+/// #[derive(ShmSend)]
+/// struct Message {
+///     data: Box<u32>,
+/// }
+/// ```
+///
+/// as well as types without a defined memory layout
+/// ```compile_fail
+/// # use iceoryx_rs::marker::ShmSend;
+/// #[derive(ShmSend)]
+/// struct Message {
+///     data: u32,
+/// }
+/// ```
+///
+/// and types which implement `Drop`.
+/// ```compile_fail
+/// # use iceoryx_rs::marker::ShmSend;
+/// #[repr(C)]
+/// #[derive(ShmSend)]
+/// struct Message {
+///     data: u32,
+/// }
+///
+/// impl Drop for Message {
+///     fn drop(&mut self) {}
+/// }
+/// ```
+///
+/// The derive macro uses the name of the type as [`TYPE_NAME`](ShmSend::TYPE_NAME). For generic types, the names
+/// of the generic arguments are appended, i.e. each instantiation has a different name. The name of the type can be
+/// changed with the `shm_send` attribute, e.g. to match the name used by a C++ application.
+///
+/// ```
+/// use iceoryx_rs::marker::ShmSend;
+///
+/// #[repr(C)]
+/// #[derive(ShmSend)]
+/// #[shm_send(type_name = "radar::Object")]
+/// struct Object {
+///     id: u64,
+/// }
+///
+/// assert_eq!(Object::TYPE_NAME, "radar::Object");
+///
+/// #[repr(C)]
+/// #[derive(ShmSend)]
+/// #[shm_send(type_name = "radar::Track")]
+/// struct Track<T, const N: usize> {
+///     objects: [T; N],
+/// }
+///
+/// assert_eq!(Track::<Object, 4>::TYPE_NAME, "radar::Track<radar::Object, 4>");
+/// assert_eq!(<[Object; 4]>::TYPE_NAME, "[radar::Object; 4]");
+/// ```
+pub unsafe trait ShmSend {
+    /// The name of the type which is used for the [`TypeIdentity`](crate::TypeIdentity)
+    ///
+    /// An empty name means that the name is unknown and only the size and alignment are checked. The names of
+    /// generic types like arrays, `Option` or tuples are composed of the names of their generic arguments and are
+    /// unknown if one of these names is unknown.
+    const TYPE_NAME: &'static str = "";
+}
 
-unsafe impl ShmSend for char {}
+macro_rules! impl_shm_send_for_primitives {
+    ($($t:ty),*) => {
+        $(
+            unsafe impl ShmSend for $t {
+                const TYPE_NAME: &'static str = stringify!($t);
+            }
+        )*
+    };
+}
 
-unsafe impl<T: ShmSend> ShmSend for [T] {}
+impl_shm_send_for_primitives!(bool);
 
-unsafe impl<T: ShmSend, const N: usize> ShmSend for [T; N] {}
+impl_shm_send_for_primitives!(
+    i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, f32, f64, isize, usize
+);
 
-unsafe impl<T: ShmSend> ShmSend for Option<T> {}
+impl_shm_send_for_primitives!(char);
 
-unsafe impl<T: ShmSend, E: ShmSend> ShmSend for Result<T, E> {}
+unsafe impl<T: ShmSend> ShmSend for [T] {
+    const TYPE_NAME: &'static str = TypeNameBuilder::build(
+        &TypeNameBuilder::new("[")
+            .push_type_name(T::TYPE_NAME)
+            .push_str("]"),
+    );
+}
 
-unsafe impl<T: ShmSend> ShmSend for MaybeUninit<T> {}
+unsafe impl<T: ShmSend, const N: usize> ShmSend for [T; N] {
+    const TYPE_NAME: &'static str = TypeNameBuilder::build(
+        &TypeNameBuilder::new("[")
+            .push_type_name(T::TYPE_NAME)
+            .push_str("; ")
+            .push_integer(N as i128)
+            .push_str("]"),
+    );
+}
 
-// TODO create macro to impl ShmSend for tuples
-unsafe impl<T1, T2> ShmSend for (T1, T2)
-where
-    T1: ShmSend,
-    T2: ShmSend,
-{
+unsafe impl<T: ShmSend> ShmSend for Option<T> {
+    const TYPE_NAME: &'static str = TypeNameBuilder::build(
+        &TypeNameBuilder::new("Option<")
+            .push_type_name(T::TYPE_NAME)
+            .push_str(">"),
+    );
 }
-unsafe impl<T1, T2, T3> ShmSend for (T1, T2, T3)
-where
-    T1: ShmSend,
-    T2: ShmSend,
-    T3: ShmSend,
-{
+
+unsafe impl<T: ShmSend, E: ShmSend> ShmSend for Result<T, E> {
+    const TYPE_NAME: &'static str = TypeNameBuilder::build(
+        &TypeNameBuilder::new("Result<")
+            .push_type_name(T::TYPE_NAME)
+            .push_str(", ")
+            .push_type_name(E::TYPE_NAME)
+            .push_str(">"),
+    );
 }
-unsafe impl<T1, T2, T3, T4> ShmSend for (T1, T2, T3, T4)
-where
-    T1: ShmSend,
-    T2: ShmSend,
-    T3: ShmSend,
-    T4: ShmSend,
-{
+
+unsafe impl<T: ShmSend> ShmSend for MaybeUninit<T> {
+    const TYPE_NAME: &'static str = TypeNameBuilder::build(
+        &TypeNameBuilder::new("MaybeUninit<")
+            .push_type_name(T::TYPE_NAME)
+            .push_str(">"),
+    );
 }
-unsafe impl<T1, T2, T3, T4, T5> ShmSend for (T1, T2, T3, T4, T5)
-where
-    T1: ShmSend,
-    T2: ShmSend,
-    T3: ShmSend,
-    T4: ShmSend,
-    T5: ShmSend,
-{
+
+// `T` does not need to be `ShmSend` since `PhantomData` has no data
+unsafe impl<T: ?Sized> ShmSend for PhantomData<T> {
+    const TYPE_NAME: &'static str = "PhantomData";
 }
-unsafe impl<T1, T2, T3, T4, T5, T6> ShmSend for (T1, T2, T3, T4, T5, T6)
-where
-    T1: ShmSend,
-    T2: ShmSend,
-    T3: ShmSend,
-    T4: ShmSend,
-    T5: ShmSend,
-    T6: ShmSend,
-{
+
+macro_rules! impl_shm_send_for_tuples {
+    ($(($first:ident $(, $t:ident)*)),*) => {
+        $(
+            unsafe impl<$first: ShmSend $(, $t: ShmSend)*> ShmSend for ($first, $($t),*) {
+                const TYPE_NAME: &'static str = TypeNameBuilder::build(
+                    &TypeNameBuilder::new("(")
+                        .push_type_name($first::TYPE_NAME)
+                        $(.push_str(", ").push_type_name($t::TYPE_NAME))*
+                        .push_str(")"),
+                );
+            }
+        )*
+    };
 }
-unsafe impl<T1, T2, T3, T4, T5, T6, T7> ShmSend for (T1, T2, T3, T4, T5, T6, T7)
-where
-    T1: ShmSend,
-    T2: ShmSend,
-    T3: ShmSend,
-    T4: ShmSend,
-    T5: ShmSend,
-    T6: ShmSend,
-    T7: ShmSend,
-{
+
+impl_shm_send_for_tuples!(
+    (T1, T2),
+    (T1, T2, T3),
+    (T1, T2, T3, T4),
+    (T1, T2, T3, T4, T5),
+    (T1, T2, T3, T4, T5, T6),
+    (T1, T2, T3, T4, T5, T6, T7),
+    (T1, T2, T3, T4, T5, T6, T7, T8)
+);
+
+/// The maximum length of a type name which is composed with the [`TypeNameBuilder`]
+const MAX_TYPE_NAME_LENGTH: usize = 512;
+
+/// Composes the [`TYPE_NAME`](ShmSend::TYPE_NAME) of generic types from the names of the generic arguments
+///
+/// This is used by the derive macro and not meant to be used directly. The composed name is unknown, i.e. empty,
+/// if the name of one of the generic arguments is unknown.
+#[doc(hidden)]
+pub struct TypeNameBuilder {
+    buffer: [u8; MAX_TYPE_NAME_LENGTH],
+    len: usize,
+    is_unknown: bool,
 }
-unsafe impl<T1, T2, T3, T4, T5, T6, T7, T8> ShmSend for (T1, T2, T3, T4, T5, T6, T7, T8)
-where
-    T1: ShmSend,
-    T2: ShmSend,
-    T3: ShmSend,
-    T4: ShmSend,
-    T5: ShmSend,
-    T6: ShmSend,
-    T7: ShmSend,
-    T8: ShmSend,
-{
+
+impl TypeNameBuilder {
+    pub const fn new(name: &str) -> Self {
+        Self {
+            buffer: [0; MAX_TYPE_NAME_LENGTH],
+            len: 0,
+            is_unknown: false,
+        }
+        .push_str(name)
+    }
+
+    pub const fn push_str(mut self, value: &str) -> Self {
+        let bytes = value.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            self = self.push_byte(bytes[i]);
+            i += 1;
+        }
+        self
+    }
+
+    pub const fn push_type_name(mut self, type_name: &str) -> Self {
+        if type_name.is_empty() {
+            self.is_unknown = true;
+        }
+        self.push_str(type_name)
+    }
+
+    pub const fn push_integer(mut self, value: i128) -> Self {
+        if value < 0 {
+            self = self.push_str("-");
+        }
+
+        let mut digits = [0u8; 40];
+        let mut count = 0;
+        let mut remainder = value.unsigned_abs();
+        loop {
+            digits[count] = b'0' + (remainder % 10) as u8;
+            count += 1;
+            remainder /= 10;
+            if remainder == 0 {
+                break;
+            }
+        }
+
+        while count > 0 {
+            count -= 1;
+            self = self.push_byte(digits[count]);
+        }
+        self
+    }
+
+    const fn push_byte(mut self, byte: u8) -> Self {
+        if self.len == MAX_TYPE_NAME_LENGTH {
+            panic!("The type name is too long! Use `#[shm_send(type_name = \"...\")]` for a shorter name.");
+        }
+        self.buffer[self.len] = byte;
+        self.len += 1;
+        self
+    }
+
+    /// Returns the composed name; the builder must be a constant to get a `'static` name
+    pub const fn build(&'static self) -> &'static str {
+        if self.is_unknown {
+            return "";
+        }
+
+        let (name, _) = self.buffer.split_at(self.len);
+        match std::str::from_utf8(name) {
+            Ok(name) => name,
+            Err(_) => panic!("The type name is not valid UTF-8!"),
+        }
+    }
 }
@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::marker::ShmSend;
+use crate::runtime::RuntimeResource;
+use crate::ClientBuilder;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::ServerBuilder;
+use crate::SubscriberBuilder;
+
+use std::sync::Arc;
+
+/// The maximum length of a node name; this is the capacity of the iceoryx `NodeName_t`
+const MAX_NODE_NAME_LENGTH: usize = 100;
+
+pub(crate) struct NodeShared {
+    _ffi_node: Box<ffi::Node>,
+    _runtime_resource: RuntimeResource,
+}
+
+/// Keeps a node registered at `RouDi` while a port created through it is alive
+pub(crate) type NodeResource = Option<Arc<NodeShared>>;
+
+/// Validates the name of a node
+pub(crate) fn validate_name(name: &str) -> Result<(), IceoryxError> {
+    if name.is_empty() || name.len() > MAX_NODE_NAME_LENGTH || name.chars().any(char::is_control) {
+        Err(IceoryxError::InvalidName {
+            name: name.to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Validates the node name of a port; the name is empty if the port does not belong to a node
+pub(crate) fn validate_port_node_name(name: &str) -> Result<(), IceoryxError> {
+    if name.is_empty() {
+        Ok(())
+    } else {
+        validate_name(name)
+    }
+}
+
+/// A component of the application which groups its ports
+///
+/// The node is registered at `RouDi` and its name is listed in the `nodes` of the process in the
+/// [`ProcessIntrospectionTopic`](crate::introspection::ProcessIntrospectionTopic). The publishers, subscribers,
+/// clients and servers created with the builders of the node carry the node name, which is shown in the port
+/// introspection. This makes the components of an application with multiple components visible.
+///
+/// The node stays registered until the `Node` and all the ports created through it are dropped. Due to a
+/// limitation of iceoryx, the name is removed from the process introspection only when the application
+/// deregisters from `RouDi`.
+///
+/// # Example
+/// ```
+/// # use iceoryx_rs::Runtime;
+/// use iceoryx_rs::Node;
+/// # use ffi::RouDiEnvironment;
+/// #
+/// # use anyhow::{anyhow, Result};
+/// # fn main() -> Result<()> {
+/// # let _roudi = RouDiEnvironment::new();
+/// #
+/// # Runtime::init("node");
+///
+/// let node = Node::new("camera")?;
+/// let publisher = node.publisher_builder::<u32>("all", "glory", "hypnotoad").create()?;
+/// let (subscriber, sample_receive_token) =
+///     node.subscriber_builder::<u32>("all", "glory", "hypnotoad").create()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Node {
+    shared: Arc<NodeShared>,
+    name: String,
+}
+
+impl Node {
+    /// Creates a new `Node` with the name `name` and registers it at `RouDi`
+    ///
+    /// The following errors are returned
+    /// - [`IceoryxError::InvalidName`] if `name` is empty, longer than 100 characters or contains control
+    ///   characters
+    /// - [`IceoryxError::NodeCreationFailed`] if `RouDi` could not create the node, e.g. since the maximum
+    ///   number of nodes is reached
+    ///
+    /// # Panics
+    ///
+    /// [`Runtime::init`](crate::Runtime::init) must have been called otherwise this method will panic.
+    pub fn new(name: &str) -> Result<Self, IceoryxError> {
+        validate_name(name)?;
+
+        let ffi_node = ffi::Node::new(name).ok_or(IceoryxError::NodeCreationFailed)?;
+
+        Ok(Self {
+            shared: Arc::new(NodeShared {
+                _ffi_node: ffi_node,
+                _runtime_resource: RuntimeResource::new(),
+            }),
+            name: name.to_string(),
+        })
+    }
+
+    /// The name of the node
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Creates a [`PublisherBuilder`] for a publisher which belongs to this node
+    pub fn publisher_builder<'a, T: ShmSend + ?Sized>(
+        &self,
+        service: &'a str,
+        instance: &'a str,
+        event: &'a str,
+    ) -> PublisherBuilder<'a, T> {
+        PublisherBuilder::new(service, instance, event).node(&self.name, self.resource())
+    }
+
+    /// Creates a [`SubscriberBuilder`] for a subscriber which belongs to this node
+    pub fn subscriber_builder<'a, T: ?Sized>(
+        &self,
+        service: &'a str,
+        instance: &'a str,
+        event: &'a str,
+    ) -> SubscriberBuilder<'a, T> {
+        SubscriberBuilder::new(service, instance, event).node(&self.name, self.resource())
+    }
+
+    /// Creates a [`ClientBuilder`] for a client which belongs to this node
+    pub fn client_builder<'a, Req: ShmSend, Res: ShmSend>(
+        &self,
+        service: &'a str,
+        instance: &'a str,
+        event: &'a str,
+    ) -> ClientBuilder<'a, Req, Res> {
+        ClientBuilder::new(service, instance, event).node(&self.name, self.resource())
+    }
+
+    /// Creates a [`ServerBuilder`] for a server which belongs to this node
+    pub fn server_builder<'a, Req: ShmSend, Res: ShmSend>(
+        &self,
+        service: &'a str,
+        instance: &'a str,
+        event: &'a str,
+    ) -> ServerBuilder<'a, Req, Res> {
+        ServerBuilder::new(service, instance, event).node(&self.name, self.resource())
+    }
+
+    fn resource(&self) -> NodeResource {
+        Some(self.shared.clone())
+    }
+}
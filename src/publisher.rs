@@ -2,13 +2,32 @@
 // SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
 // SPDX-FileContributor: Mathias Kraus
 
-use super::{RawSampleMut, SampleMut};
+use super::{RawSampleMut, SampleMut, SampleWriter};
+use crate::discovery;
 use crate::marker::ShmSend;
+use crate::node::{self, NodeResource};
+use crate::runtime::RuntimeResource;
+use crate::service_name;
+use crate::type_identity::TypeIdentityHeader;
+use crate::user_header::{self, NoUserHeader};
 use crate::ConsumerTooSlowPolicy;
 use crate::IceoryxError;
+use crate::ServiceName;
+use crate::TypeIdentity;
 
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use crate::codec::Codec;
+#[cfg(feature = "flatbuffers")]
+use crate::flatbuffer::ChunkAllocator;
+#[cfg(feature = "flatbuffers")]
+use flatbuffers::FlatBufferBuilder;
+#[cfg(feature = "serde")]
+use serde::Serialize;
 
 /// Create a publisher with custom options
 ///
@@ -30,26 +49,92 @@ use std::mem::MaybeUninit;
 /// # Ok(())
 /// # }
 /// ```
-pub struct PublisherBuilder<'a, T: ShmSend + ?Sized> {
+pub struct PublisherBuilder<'a, T: ShmSend + ?Sized, H: ShmSend = NoUserHeader> {
     service: &'a str,
     instance: &'a str,
     event: &'a str,
     options: ffi::PublisherOptions,
-    phantom: PhantomData<T>,
+    type_identity: Option<TypeIdentity>,
+    node: NodeResource,
+    phantom: PhantomData<(H, T)>,
 }
 
 impl<'a, T: ShmSend + ?Sized> PublisherBuilder<'a, T> {
     /// Creates a new `PublisherBuilder`
     ///
-    /// The parameter `service`, `instance` and `event` are used to specify the name of the service.
-    /// In the future this three strings will probably be fused to together in a single string separated
-    /// by slashes `/`.
+    /// The parameter `service`, `instance` and `event` are used to specify the name of the service. They are
+    /// validated like a [`ServiceName`] when the publisher is created.
     pub fn new(service: &'a str, instance: &'a str, event: &'a str) -> Self {
         Self {
             service,
             instance,
             event,
             options: ffi::PublisherOptions::default(),
+            type_identity: None,
+            node: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates a new `PublisherBuilder` for the service with the `service_name`
+    pub fn from_service_name(service_name: &'a ServiceName) -> Self {
+        Self::new(
+            service_name.service(),
+            service_name.instance(),
+            service_name.event(),
+        )
+    }
+}
+
+impl<'a, T: ShmSend + ?Sized, H: ShmSend> PublisherBuilder<'a, T, H> {
+    /// Adds a user defined header of type `H2` to each sample
+    ///
+    /// The user header is placed in front of the payload and can be accessed with
+    /// [`SampleMut::user_header_mut`] and [`Sample::user_header`](crate::Sample::user_header). This can be used
+    /// to transfer meta data like timestamps or sequence numbers without changing the payload type.
+    /// The subscriber must use the same header type with
+    /// [`SubscriberBuilder::with_user_header`](crate::SubscriberBuilder::with_user_header).
+    ///
+    /// The alignment of the user header must not exceed 8 bytes, which is checked on `create`.
+    ///
+    /// # Example
+    /// ```
+    /// # use iceoryx_rs::Runtime;
+    /// use iceoryx_rs::PublisherBuilder;
+    /// # use ffi::RouDiEnvironment;
+    /// #
+    /// # use anyhow::{anyhow, Result};
+    /// # fn main() -> Result<()> {
+    /// # let _roudi = RouDiEnvironment::new();
+    /// #
+    /// # Runtime::init("user_header");
+    ///
+    /// #[repr(C)]
+    /// #[derive(Default)]
+    /// struct Timestamp {
+    ///     nanoseconds: u64,
+    /// }
+    /// unsafe impl iceoryx_rs::marker::ShmSend for Timestamp {}
+    ///
+    /// let publisher = PublisherBuilder::<u32>::new("all", "glory", "hypnotoad")
+    ///     .with_user_header::<Timestamp>()
+    ///     .create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// sample.user_header_mut().nanoseconds = 42;
+    /// *sample = 73;
+    /// publisher.publish(sample);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_user_header<H2: ShmSend>(self) -> PublisherBuilder<'a, T, H2> {
+        PublisherBuilder {
+            service: self.service,
+            instance: self.instance,
+            event: self.event,
+            options: self.options,
+            type_identity: self.type_identity,
+            node: self.node,
             phantom: PhantomData,
         }
     }
@@ -64,14 +149,25 @@ impl<'a, T: ShmSend + ?Sized> PublisherBuilder<'a, T> {
         self
     }
 
-    /// The name of the node where the subscriber should belong to
+    /// The name of the node where the publisher should belong to
     ///
-    /// Setting the node name has currently no functionality but this might change in future.
+    /// The node name is shown in the port introspection. Use
+    /// [`Node::publisher_builder`](crate::Node::publisher_builder) to create a publisher which belongs to a node
+    /// that is registered at `RouDi`.
+    ///
+    /// The name is validated like the name of a [`Node`](crate::Node) when the publisher is created. It is empty by
+    /// default, i.e. the publisher does not belong to a node.
     pub fn node_name(mut self, name: String) -> Self {
         self.options.node_name = name;
         self
     }
 
+    pub(crate) fn node(mut self, name: &str, node: NodeResource) -> Self {
+        self.options.node_name = name.to_string();
+        self.node = node;
+        self
+    }
+
     /// Sets the policy on how to proceed when the subscriber is too slow in processing the published samples
     ///
     /// By default the oldest samples are removed from the subscriber queue and the latest ones added.
@@ -92,13 +188,19 @@ impl<'a, T: ShmSend + ?Sized> PublisherBuilder<'a, T> {
     /// # Panics
     ///
     /// [`Runtime::init`](crate::Runtime::init) must have been called otherwise this method will panic.
-    pub fn create(mut self) -> Result<Publisher<T>, IceoryxError> {
+    pub fn create(mut self) -> Result<Publisher<T, H>, IceoryxError> {
+        user_header::check_user_header::<H>()?;
         self.options.offer_on_create = true;
+        service_name::validate(self.service, self.instance, self.event)?;
+        node::validate_port_node_name(&self.options.node_name)?;
         let ffi_pub = ffi::Publisher::new(self.service, self.instance, self.event, &self.options)
             .ok_or(IceoryxError::PublisherCreationFailed)?;
 
         Ok(Publisher {
-            ffi_pub,
+            ffi_pub: Arc::new(ffi_pub),
+            type_identity: self.type_identity,
+            _node: self.node,
+            _runtime_resource: RuntimeResource::new(),
             phantom: PhantomData,
         })
     }
@@ -110,30 +212,65 @@ impl<'a, T: ShmSend + ?Sized> PublisherBuilder<'a, T> {
     /// # Panics
     ///
     /// [`Runtime::init`](crate::Runtime::init) must have been called otherwise this method will panic.
-    pub fn create_without_offer(mut self) -> Result<InactivePublisher<T>, IceoryxError> {
+    pub fn create_without_offer(mut self) -> Result<InactivePublisher<T, H>, IceoryxError> {
+        user_header::check_user_header::<H>()?;
         self.options.offer_on_create = false;
+        service_name::validate(self.service, self.instance, self.event)?;
+        node::validate_port_node_name(&self.options.node_name)?;
         let ffi_pub = ffi::Publisher::new(self.service, self.instance, self.event, &self.options)
             .ok_or(IceoryxError::PublisherCreationFailed)?;
 
         Ok(InactivePublisher {
             ffi_pub,
+            type_identity: self.type_identity,
+            _node: self.node,
+            _runtime_resource: RuntimeResource::new(),
             phantom: PhantomData,
         })
     }
 }
 
+impl<'a, T: ShmSend, H: ShmSend> PublisherBuilder<'a, T, H> {
+    /// Transfers the [`TypeIdentity`] of `T` with each sample
+    ///
+    /// This enables subscriber created with
+    /// [`SubscriberBuilder::with_type_identity`](crate::SubscriberBuilder::with_type_identity) to detect
+    /// samples of a different type. The type identity is stored in the user header of the chunk in front
+    /// of the user defined header.
+    pub fn with_type_identity(mut self) -> Self {
+        self.type_identity = Some(TypeIdentity::of::<T>());
+        self
+    }
+}
+
+impl<'a, T: ShmSend, H: ShmSend> PublisherBuilder<'a, [T], H> {
+    /// Transfers the [`TypeIdentity`] of the element type `T` with each sample
+    ///
+    /// Have a look at the `with_type_identity` method for non-slice types for details.
+    pub fn with_type_identity(mut self) -> Self {
+        self.type_identity = Some(TypeIdentity::of::<T>());
+        self
+    }
+}
+
 /// An inactive publisher which does not offer and is not visible to any subscriber
 ///
 /// This can be used for cases where the service is suspended and the publisher/subscriber need to be disconnected.
-pub struct InactivePublisher<T: ShmSend + ?Sized> {
+pub struct InactivePublisher<T: ShmSend + ?Sized, H: ShmSend = NoUserHeader> {
     ffi_pub: Box<ffi::Publisher>,
-    phantom: PhantomData<T>,
+    type_identity: Option<TypeIdentity>,
+    _node: NodeResource,
+    _runtime_resource: RuntimeResource,
+    phantom: PhantomData<(H, T)>,
 }
 
-impl<T: ShmSend + ?Sized> InactivePublisher<T> {
-    fn new_from_publisher(publisher: Publisher<T>) -> Self {
+impl<T: ShmSend + ?Sized, H: ShmSend> InactivePublisher<T, H> {
+    fn new_from_publisher(publisher: Publisher<T, H>) -> Self {
         Self {
-            ffi_pub: publisher.ffi_pub,
+            ffi_pub: Arc::try_unwrap(publisher.ffi_pub).expect("Unique owner of publisher"),
+            type_identity: publisher.type_identity,
+            _node: publisher._node,
+            _runtime_resource: publisher._runtime_resource,
             phantom: PhantomData,
         }
     }
@@ -142,22 +279,44 @@ impl<T: ShmSend + ?Sized> InactivePublisher<T> {
     ///
     /// Contrary to [`PublisherBuilder::create`] the publisher does not offer immediately after this
     /// method returns and it might take up to 50 milliseconds until `RouDi` runs its discovery loop.
-    pub fn offer(self) -> Publisher<T> {
+    /// Use [`Publisher::wait_until_offered`] to wait for the offer to be processed.
+    pub fn offer(self) -> Publisher<T, H> {
         self.ffi_pub.offer();
         Publisher::new_from_inactive_publisher(self)
     }
+
+    /// Blocks until `RouDi` processed the stop offer and the service is not visible to subscribers anymore
+    ///
+    /// The offer state is taken from the service registry of `RouDi`, which notifies about each change. The
+    /// registry counts the offers per service, therefore this method waits until there are no other publishers
+    /// with the same service.
+    ///
+    /// Returns [`IceoryxError::DiscoveryTimeout`] if the stop offer was not processed within `timeout`.
+    pub fn wait_until_stopped_offer(&self, timeout: Duration) -> Result<(), IceoryxError> {
+        discovery::wait_for_service_registry(timeout, |registry| {
+            // SAFETY: the registry is a sample of the service registry topic
+            unsafe { self.ffi_pub.number_of_offers_in_service_registry(registry) == 0 }
+        })
+    }
 }
 
 /// A publisher which is offering its service
-pub struct Publisher<T: ShmSend + ?Sized> {
-    ffi_pub: Box<ffi::Publisher>,
-    phantom: PhantomData<T>,
+pub struct Publisher<T: ShmSend + ?Sized, H: ShmSend = NoUserHeader> {
+    // shared with a `Listener` which observes the subscription state
+    ffi_pub: ffi::PublisherArc,
+    type_identity: Option<TypeIdentity>,
+    _node: NodeResource,
+    _runtime_resource: RuntimeResource,
+    phantom: PhantomData<(H, T)>,
 }
 
-impl<T: ShmSend + ?Sized> Publisher<T> {
-    fn new_from_inactive_publisher(publisher: InactivePublisher<T>) -> Self {
+impl<T: ShmSend + ?Sized, H: ShmSend> Publisher<T, H> {
+    fn new_from_inactive_publisher(publisher: InactivePublisher<T, H>) -> Self {
         Self {
-            ffi_pub: publisher.ffi_pub,
+            ffi_pub: Arc::new(publisher.ffi_pub),
+            type_identity: publisher.type_identity,
+            _node: publisher._node,
+            _runtime_resource: publisher._runtime_resource,
             phantom: PhantomData,
         }
     }
@@ -174,19 +333,59 @@ impl<T: ShmSend + ?Sized> Publisher<T> {
     /// Stops offering the service by consuming the `Publisher` and creating an [`InactivePublisher`]
     ///
     /// All connected subscriber will be disconnected. It might take up to 50 milliseconds until `RouDi` runs its
-    /// discovery loop and this takes effect.
-    pub fn stop_offer(self) -> InactivePublisher<T> {
+    /// discovery loop and this takes effect. Use [`InactivePublisher::wait_until_stopped_offer`] to wait for this.
+    pub fn stop_offer(self) -> InactivePublisher<T, H> {
         self.ffi_pub.stop_offer();
         InactivePublisher::new_from_publisher(self)
     }
 
+    /// Blocks until `RouDi` processed the offer and the service is visible to subscribers
+    ///
+    /// The offer state is taken from the service registry of `RouDi`, which notifies about each change. The
+    /// registry counts the offers per service, therefore this method returns immediately if another publisher
+    /// already offers the same service.
+    ///
+    /// Returns [`IceoryxError::DiscoveryTimeout`] if the offer was not processed within `timeout`.
+    pub fn wait_until_offered(&self, timeout: Duration) -> Result<(), IceoryxError> {
+        discovery::wait_for_service_registry(timeout, |registry| {
+            // SAFETY: the registry is a sample of the service registry topic
+            unsafe { self.ffi_pub.number_of_offers_in_service_registry(registry) > 0 }
+        })
+    }
+
     /// Checks whether there are subscriber for the service of the publisher
+    ///
+    /// Use [`Listener::attach_publisher`](crate::Listener::attach_publisher) to get notified when subscribers
+    /// connect or disconnect.
     pub fn has_subscribers(&self) -> bool {
         self.ffi_pub.has_subscribers()
     }
 
+    /// The number of subscribers which are currently connected to the publisher
+    pub fn number_of_subscribers(&self) -> u64 {
+        self.ffi_pub.number_of_subscribers()
+    }
+
+    /// Blocks until at least one subscriber is connected or the `timeout` elapsed
+    ///
+    /// Subscribers are connected by the discovery loop of `RouDi` and iceoryx does not notify about this,
+    /// therefore the subscription state is checked after each run of the discovery loop.
+    ///
+    /// Returns [`IceoryxError::DiscoveryTimeout`] if no subscriber connected within `timeout`.
+    pub fn wait_for_subscribers(&self, timeout: Duration) -> Result<(), IceoryxError> {
+        discovery::wait_for_discovery_loop(timeout, || self.has_subscribers())
+    }
+
+    pub(crate) fn ffi_pub(&self) -> &ffi::PublisherArc {
+        &self.ffi_pub
+    }
+
+    pub(crate) fn type_identity(&self) -> Option<TypeIdentity> {
+        self.type_identity
+    }
+
     /// Publishes a sample
-    pub fn publish(&self, sample: SampleMut<T>) {
+    pub fn publish(&self, sample: SampleMut<T, H>) {
         self.publish_raw(sample.into_raw())
     }
 
@@ -201,13 +400,19 @@ impl<T: ShmSend + ?Sized> Publisher<T> {
     }
 }
 
-impl<T: ShmSend + Default> Publisher<T> {
+impl<T: ShmSend + Default, H: ShmSend + Default> Publisher<T, H> {
     /// Loan a sample
     ///
     /// The loaned sample is initialized with the default value of the type. If this is not desired
     /// or the type does not implement the `Default` trait, [`loan_uninit`](Self::loan_uninit)
-    /// can be used.
-    pub fn loan(&self) -> Result<SampleMut<T>, IceoryxError> {
+    /// can be used. The user header is always initialized with its default value.
+    ///
+    /// If the sample cannot be loaned, the error reports the cause together with the requested size and
+    /// alignment, e.g. [`IceoryxError::MempoolsExhausted`] when all chunks of the matching memory pool are in use,
+    /// [`IceoryxError::NoMempoolForRequestedSize`] when there is no memory pool available for the publisher or
+    /// [`IceoryxError::TooManySamplesLoanedInParallel`] when too many samples are loaned without being published
+    /// or released.
+    pub fn loan(&self) -> Result<SampleMut<'_, T, H>, IceoryxError> {
         let mut sample = self.loan_uninit()?;
 
         unsafe {
@@ -217,24 +422,33 @@ impl<T: ShmSend + Default> Publisher<T> {
     }
 }
 
-impl<T: ShmSend> Publisher<T> {
+impl<T: ShmSend, H: ShmSend + Default> Publisher<T, H> {
     /// Loan an uninitialized sample
     ///
-    /// Same as [`loan`](Self::loan) but with uninitialized data.
-    pub fn loan_uninit(&self) -> Result<SampleMut<MaybeUninit<T>>, IceoryxError> {
-        let data = self
-            .ffi_pub
-            .try_allocate::<T>()
-            .ok_or(IceoryxError::LoanSampleFailed)?;
+    /// Same as [`loan`](Self::loan) but with uninitialized data. The user header is initialized with its
+    /// default value.
+    pub fn loan_uninit(&self) -> Result<SampleMut<'_, MaybeUninit<T>, H>, IceoryxError> {
+        let data = if self.type_identity.is_some() {
+            self.ffi_pub.try_allocate::<T, TypeIdentityHeader<H>>()
+        } else {
+            self.ffi_pub.try_allocate::<T, H>()
+        }
+        .map_err(|e| {
+            IceoryxError::from_allocation_error(
+                e,
+                std::mem::size_of::<T>(),
+                std::mem::align_of::<T>(),
+            )
+        })?;
 
         // the transmute is not nice but safe since MaybeUninit has the same layout as the inner type
         let publisher =
-            unsafe { std::mem::transmute::<&Publisher<T>, &Publisher<MaybeUninit<T>>>(self) };
-        Ok(SampleMut::new(data, publisher))
+            unsafe { std::mem::transmute::<&Publisher<T, H>, &Publisher<MaybeUninit<T>, H>>(self) };
+        Ok(SampleMut::new_with_default_user_header(data, publisher))
     }
 }
 
-impl<T: ShmSend + Default> Publisher<[T]> {
+impl<T: ShmSend + Default, H: ShmSend + Default> Publisher<[T], H> {
     /// Loan a slice with the same alignment as `T`
     ///
     /// The loaned slice is initialized with the default value of the type. If this is not desired
@@ -246,7 +460,9 @@ impl<T: ShmSend + Default> Publisher<[T]> {
     ///  alignment of the type in the buffer. This is required to utilize crates like
     /// [zerocopy](https://crates.io/crates/zerocopy) for safe zero-copy parsing and serialization.
     /// Please use [`loan_slice_with_alignment`](Self::loan_slice_with_alignment) for this purpose.
-    pub fn loan_slice(&self, len: usize) -> Result<SampleMut<[T]>, IceoryxError> {
+    ///
+    /// The errors are the same as for [`loan`](Publisher::loan) with the size of the whole slice as requested size.
+    pub fn loan_slice(&self, len: usize) -> Result<SampleMut<'_, [T], H>, IceoryxError> {
         self.loan_slice_with_alignment(len, std::mem::align_of::<T>())
     }
 
@@ -260,7 +476,7 @@ impl<T: ShmSend + Default> Publisher<[T]> {
         &self,
         len: usize,
         align: usize,
-    ) -> Result<SampleMut<[T]>, IceoryxError> {
+    ) -> Result<SampleMut<'_, [T], H>, IceoryxError> {
         let mut sample = self.loan_uninit_slice_with_alignment(len, align)?;
 
         unsafe {
@@ -270,7 +486,78 @@ impl<T: ShmSend + Default> Publisher<[T]> {
     }
 }
 
-impl<T: ShmSend> Publisher<[T]> {
+#[cfg(feature = "serde")]
+impl<H: ShmSend + Default> Publisher<[u8], H> {
+    /// Serializes `value` with `codec` into a sample and publishes it
+    ///
+    /// If the codec can compute the size of the serialized value, a sample with exactly this size is loaned
+    /// and the value is serialized directly into the shared memory. Otherwise the value is serialized into a
+    /// temporary buffer which is copied into the sample. The subscriber can deserialize the value with
+    /// [`Sample::deserialize`](crate::Sample::deserialize) and the same codec.
+    ///
+    /// Returns [`IceoryxError::SerializationFailed`] if the value could not be serialized and the same errors as
+    /// [`loan_slice`](Self::loan_slice) if the sample could not be loaned.
+    pub fn publish_serialized<T: Serialize + ?Sized>(
+        &self,
+        value: &T,
+        codec: impl Codec,
+    ) -> Result<(), IceoryxError> {
+        let sample = match codec.serialized_size(value) {
+            Some(size) => {
+                let mut sample = self.loan_slice(size)?;
+                codec.serialize_into(value, &mut sample)?;
+                sample
+            }
+            None => {
+                let buffer = codec.serialize(value)?;
+                let mut sample = self.loan_uninit_slice(buffer.len())?;
+                for (element, byte) in sample.iter_mut().zip(buffer) {
+                    element.write(byte);
+                }
+                // SAFETY: all elements were written since the slice has the size of the buffer
+                unsafe { sample.assume_init() }
+            }
+        };
+        self.publish(sample);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<H: ShmSend + Default> Publisher<[u8], H> {
+    /// Creates a `FlatBufferBuilder` which builds the message directly in a loaned chunk
+    ///
+    /// The chunk has room for a message of `capacity` bytes. If the message exceeds the capacity, a larger
+    /// chunk is loaned and the message is moved, see [`ChunkAllocator`]. The finished message is published with
+    /// [`publish_flatbuffer`](Self::publish_flatbuffer).
+    ///
+    /// The errors are the same as for [`loan_slice`](Self::loan_slice).
+    pub fn flatbuffer_builder<'fbb>(
+        &self,
+        capacity: usize,
+    ) -> Result<FlatBufferBuilder<'fbb, ChunkAllocator<'_, H>>, IceoryxError> {
+        Ok(FlatBufferBuilder::new_in(ChunkAllocator::new(
+            self, capacity,
+        )?))
+    }
+
+    /// Publishes the message of a finished `FlatBufferBuilder` without copying it
+    ///
+    /// The subscriber can read the message with [`Sample::flatbuffer_root`](crate::Sample::flatbuffer_root).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the builder is not finished or was created by a different publisher.
+    pub fn publish_flatbuffer(&self, builder: FlatBufferBuilder<'_, ChunkAllocator<'_, H>>) {
+        // asserts that the builder is finished
+        builder.finished_data();
+        let (allocator, head) = builder.collapse_in();
+        self.publish(allocator.into_sample(self, head));
+    }
+}
+
+impl<T: ShmSend, H: ShmSend + Default> Publisher<[T], H> {
     /// Loan an uninitialized slice with the same alignment as `T`
     ///
     /// Same as [`loan_slice`](Self::loan_slice) but with uninitialized data.
@@ -279,7 +566,7 @@ impl<T: ShmSend> Publisher<[T]> {
     pub fn loan_uninit_slice(
         &self,
         len: usize,
-    ) -> Result<SampleMut<[MaybeUninit<T>]>, IceoryxError> {
+    ) -> Result<SampleMut<'_, [MaybeUninit<T>], H>, IceoryxError> {
         self.loan_uninit_slice_with_alignment(len, std::mem::align_of::<T>())
     }
 
@@ -290,7 +577,7 @@ impl<T: ShmSend> Publisher<[T]> {
         &self,
         len: usize,
         align: usize,
-    ) -> Result<SampleMut<[MaybeUninit<T>]>, IceoryxError> {
+    ) -> Result<SampleMut<'_, [MaybeUninit<T>], H>, IceoryxError> {
         if align < std::mem::align_of::<T>() {
             return Err(IceoryxError::InvalidAlignment {
                 requested: align,
@@ -298,14 +585,31 @@ impl<T: ShmSend> Publisher<[T]> {
             });
         }
 
-        let data = self
-            .ffi_pub
-            .try_allocate_slice(len as u32, align as u32)
-            .ok_or(IceoryxError::LoanSampleFailed)?;
+        let data = if self.type_identity.is_some() {
+            self.ffi_pub
+                .try_allocate_slice::<T, TypeIdentityHeader<H>>(len as u32, align as u32)
+        } else {
+            self.ffi_pub
+                .try_allocate_slice::<T, H>(len as u32, align as u32)
+        }
+        .map_err(|e| {
+            IceoryxError::from_allocation_error(e, len * std::mem::size_of::<T>(), align)
+        })?;
 
         // the transmute is not nice but safe since MaybeUninit has the same layout as the inner type
-        let publisher =
-            unsafe { std::mem::transmute::<&Publisher<[T]>, &Publisher<[MaybeUninit<T>]>>(self) };
-        Ok(SampleMut::new(data, publisher))
+        let publisher = unsafe {
+            std::mem::transmute::<&Publisher<[T], H>, &Publisher<[MaybeUninit<T>], H>>(self)
+        };
+        Ok(SampleMut::new_with_default_user_header(data, publisher))
+    }
+}
+
+impl<H: ShmSend + Default> Publisher<[u8], H> {
+    /// Loan an uninitialized `[u8]` sample with `capacity` bytes and wrap it in a [`SampleWriter`]
+    ///
+    /// The writer implements `std::io::Write` and, with the `bytes` feature, `bytes::BufMut`. The errors are the
+    /// same as for [`loan_slice`](Self::loan_slice).
+    pub fn loan_writer(&self, capacity: usize) -> Result<SampleWriter<'_, H>, IceoryxError> {
+        Ok(SampleWriter::new(self.loan_uninit_slice(capacity)?))
     }
 }
@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+//! Wakes up the tasks which wait asynchronously on a `SampleReceiver`
+//!
+//! A reactor owns a condition variable and a background thread which waits on it. The sample receiver
+//! are attached to the condition variable of a reactor with their own notification index and the
+//! background thread wakes the registered waker when the corresponding index is notified. When all the
+//! notification indices of the existing reactors are in use, a new reactor is created.
+
+use crate::runtime::RuntimeResource;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::task::Waker;
+use std::thread;
+
+/// The notification index to wake up the background thread, e.g. to shut down
+const WAKE_UP_NOTIFICATION_INDEX: usize = Reactor::CAPACITY;
+
+/// The reactors with free slots are shared by all the sample receiver of the process
+static REACTORS: Mutex<Vec<Weak<Reactor>>> = Mutex::new(Vec::new());
+
+enum Slot {
+    Free,
+    Registered(Option<Waker>),
+}
+
+struct ReactorShared {
+    condition_variable: Box<ffi::ConditionVariable>,
+    _runtime_resource: RuntimeResource,
+    slots: Mutex<Vec<Slot>>,
+    keep_running: AtomicBool,
+}
+
+impl ReactorShared {
+    fn slots(&self) -> MutexGuard<'_, Vec<Slot>> {
+        // waking a task does not leave the slots in an inconsistent state
+        self.slots
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+struct Reactor {
+    shared: Arc<ReactorShared>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl Reactor {
+    /// The maximum number of sample receiver of a single reactor; one notification index is reserved for the wake up
+    const CAPACITY: usize = ffi::ConditionVariable::MAX_NOTIFICATIONS - 1;
+
+    fn new() -> Self {
+        let shared = Arc::new(ReactorShared {
+            condition_variable: ffi::ConditionVariable::new(),
+            _runtime_resource: RuntimeResource::new(),
+            slots: Mutex::new((0..Self::CAPACITY).map(|_| Slot::Free).collect()),
+            keep_running: AtomicBool::new(true),
+        });
+
+        let thread_shared = shared.clone();
+        let thread = thread::Builder::new()
+            .name("iox-reactor".to_string())
+            .spawn(move || Self::run(&thread_shared))
+            .expect("Spawning the reactor thread");
+
+        Self {
+            shared,
+            thread: Some(thread),
+        }
+    }
+
+    fn try_acquire_slot(&self) -> Option<usize> {
+        let mut slots = self.shared.slots();
+        let index = slots.iter().position(|slot| matches!(slot, Slot::Free))?;
+        slots[index] = Slot::Registered(None);
+        Some(index)
+    }
+
+    fn run(shared: &ReactorShared) {
+        while shared.keep_running.load(Ordering::Relaxed) {
+            let notifications = shared.condition_variable.wait_for_notifications();
+
+            let wakers = {
+                let mut slots = shared.slots();
+                notifications
+                    .into_iter()
+                    .filter_map(|index| match slots.get_mut(index as usize) {
+                        Some(Slot::Registered(waker)) => waker.take(),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            // the tasks are woken without holding the lock since they might be polled immediately
+            wakers.into_iter().for_each(Waker::wake);
+        }
+    }
+}
+
+impl Drop for Reactor {
+    fn drop(&mut self) {
+        self.shared.keep_running.store(false, Ordering::Relaxed);
+        self.shared
+            .condition_variable
+            .notify(WAKE_UP_NOTIFICATION_INDEX as u64);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// A slot of a reactor which is used by a single sample receiver
+///
+/// The slot is released when this is dropped and the reactor is shut down when the last slot is released.
+pub(crate) struct Registration {
+    reactor: Arc<Reactor>,
+    index: usize,
+}
+
+impl Registration {
+    /// Acquires a slot from an existing reactor or creates a new one if all reactors are exhausted
+    pub(crate) fn new() -> Self {
+        let mut reactors = REACTORS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        reactors.retain(|reactor| reactor.strong_count() > 0);
+
+        for reactor in reactors.iter().filter_map(Weak::upgrade) {
+            if let Some(index) = reactor.try_acquire_slot() {
+                return Self { reactor, index };
+            }
+        }
+
+        let reactor = Arc::new(Reactor::new());
+        let index = reactor
+            .try_acquire_slot()
+            .expect("A free slot in a new reactor");
+        reactors.push(Arc::downgrade(&reactor));
+
+        Self { reactor, index }
+    }
+
+    pub(crate) fn condition_variable(&self) -> &ffi::ConditionVariable {
+        &self.reactor.shared.condition_variable
+    }
+
+    pub(crate) fn notification_index(&self) -> u64 {
+        self.index as u64
+    }
+
+    /// Registers the waker which is woken on the next notification of the sample receiver
+    pub(crate) fn register_waker(&self, waker: &Waker) {
+        if let Slot::Registered(registered_waker) = &mut self.reactor.shared.slots()[self.index] {
+            match registered_waker {
+                Some(registered_waker) if registered_waker.will_wake(waker) => (),
+                _ => *registered_waker = Some(waker.clone()),
+            }
+        }
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        self.reactor.shared.slots()[self.index] = Slot::Free;
+    }
+}
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::marker::ShmSend;
+use crate::{RawSample, RawSampleMut};
+
+use std::ffi::c_void;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+
+/// A mutable request owned by a single client
+pub struct RequestMut<'a, T: ShmSend> {
+    data: RawSampleMut<T>,
+    ffi_client: &'a ffi::Client,
+}
+
+impl<'a, T: ShmSend> Deref for RequestMut<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: `as_payload_ptr` returns a non-null ptr
+        unsafe { &*self.data.as_payload_ptr() }
+    }
+}
+
+impl<'a, T: ShmSend> DerefMut for RequestMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: `as_payload_mut_ptr` returns a non-null ptr
+        unsafe { &mut *self.data.as_payload_mut_ptr() }
+    }
+}
+
+impl<'a, T: ShmSend> Drop for RequestMut<'a, T> {
+    fn drop(&mut self) {
+        self.ffi_client.release_request(self.data);
+    }
+}
+
+impl<'a, T: ShmSend> RequestMut<'a, T> {
+    pub(super) fn new(data: RawSampleMut<T>, ffi_client: &'a ffi::Client) -> Self {
+        Self { data, ffi_client }
+    }
+
+    pub(super) fn into_raw_parts(self) -> (RawSampleMut<T>, &'a ffi::Client) {
+        let request = self.data;
+        let ffi_client = self.ffi_client;
+        std::mem::forget(self); // forget `self` to not call drop
+        (request, ffi_client)
+    }
+
+    fn request_header(&self) -> &ffi::RequestHeader {
+        // SAFETY: `as_payload_ptr` returns a non-null ptr to the payload of a request
+        unsafe {
+            ffi::RequestHeader::from_user_payload_unchecked(
+                self.data.as_payload_ptr().cast::<c_void>(),
+            )
+        }
+    }
+
+    /// The sequence ID of the request
+    ///
+    /// The client assigns a consecutive sequence ID when the request is loaned. The server copies
+    /// the sequence ID into the corresponding response which can be used to match the response
+    /// with the request.
+    pub fn sequence_id(&self) -> i64 {
+        self.request_header().get_sequence_id()
+    }
+
+    /// Overrides the sequence ID which was assigned by the client when the request was loaned
+    pub fn set_sequence_id(&mut self, sequence_id: i64) {
+        self.request_header().set_sequence_id(sequence_id);
+    }
+}
+
+impl<'a, T: ShmSend> RequestMut<'a, MaybeUninit<T>> {
+    /// Extracts the value of `MaybeUninit<T>` container and labels the request as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `MaybeUninit<T>` really is initialized. Calling this when
+    /// the content is not fully initialized causes immediate undefined behavior.
+    pub unsafe fn assume_init(self) -> RequestMut<'a, T> {
+        let (data, ffi_client) = self.into_raw_parts();
+
+        // the transmute is not nice but safe since MaybeUninit has the same layout as the inner type
+        let data = std::mem::transmute::<RawSampleMut<MaybeUninit<T>>, RawSampleMut<T>>(data);
+
+        RequestMut { data, ffi_client }
+    }
+}
+
+/// An immutable request received by a server
+pub struct Request<'a, T: ShmSend> {
+    data: RawSample<T>,
+    ffi_server: &'a ffi::Server,
+}
+
+impl<'a, T: ShmSend> Deref for Request<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: `as_payload_ptr` returns a non-null ptr
+        unsafe { &*self.data.as_payload_ptr() }
+    }
+}
+
+impl<'a, T: ShmSend> Drop for Request<'a, T> {
+    fn drop(&mut self) {
+        self.ffi_server.release_request(self.data);
+    }
+}
+
+impl<'a, T: ShmSend> Request<'a, T> {
+    pub(super) fn new(data: RawSample<T>, ffi_server: &'a ffi::Server) -> Self {
+        Self { data, ffi_server }
+    }
+
+    pub(super) fn as_raw(&self) -> RawSample<T> {
+        self.data
+    }
+
+    /// The sequence ID of the request
+    ///
+    /// The sequence ID is automatically copied into the response which is loaned for this request.
+    pub fn sequence_id(&self) -> i64 {
+        // SAFETY: `as_payload_ptr` returns a non-null ptr to the payload of a request
+        unsafe {
+            ffi::RequestHeader::from_user_payload_unchecked(
+                self.data.as_payload_ptr().cast::<c_void>(),
+            )
+            .get_sequence_id()
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::marker::ShmSend;
+use crate::{RawSample, RawSampleMut};
+
+use std::ffi::c_void;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+
+/// A mutable response owned by a single server
+pub struct ResponseMut<'a, T: ShmSend> {
+    data: RawSampleMut<T>,
+    ffi_server: &'a ffi::Server,
+}
+
+impl<'a, T: ShmSend> Deref for ResponseMut<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: `as_payload_ptr` returns a non-null ptr
+        unsafe { &*self.data.as_payload_ptr() }
+    }
+}
+
+impl<'a, T: ShmSend> DerefMut for ResponseMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: `as_payload_mut_ptr` returns a non-null ptr
+        unsafe { &mut *self.data.as_payload_mut_ptr() }
+    }
+}
+
+impl<'a, T: ShmSend> Drop for ResponseMut<'a, T> {
+    fn drop(&mut self) {
+        self.ffi_server.release_response(self.data);
+    }
+}
+
+impl<'a, T: ShmSend> ResponseMut<'a, T> {
+    pub(super) fn new(data: RawSampleMut<T>, ffi_server: &'a ffi::Server) -> Self {
+        Self { data, ffi_server }
+    }
+
+    pub(super) fn into_raw_parts(self) -> (RawSampleMut<T>, &'a ffi::Server) {
+        let response = self.data;
+        let ffi_server = self.ffi_server;
+        std::mem::forget(self); // forget `self` to not call drop
+        (response, ffi_server)
+    }
+
+    /// The sequence ID of the request this response belongs to
+    pub fn sequence_id(&self) -> i64 {
+        // SAFETY: `as_payload_ptr` returns a non-null ptr to the payload of a response
+        unsafe {
+            ffi::ResponseHeader::from_user_payload_unchecked(
+                self.data.as_payload_ptr().cast::<c_void>(),
+            )
+            .get_sequence_id()
+        }
+    }
+}
+
+impl<'a, T: ShmSend> ResponseMut<'a, MaybeUninit<T>> {
+    /// Extracts the value of `MaybeUninit<T>` container and labels the response as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `MaybeUninit<T>` really is initialized. Calling this when
+    /// the content is not fully initialized causes immediate undefined behavior.
+    pub unsafe fn assume_init(self) -> ResponseMut<'a, T> {
+        let (data, ffi_server) = self.into_raw_parts();
+
+        // the transmute is not nice but safe since MaybeUninit has the same layout as the inner type
+        let data = std::mem::transmute::<RawSampleMut<MaybeUninit<T>>, RawSampleMut<T>>(data);
+
+        ResponseMut { data, ffi_server }
+    }
+}
+
+/// An immutable response received by a client
+pub struct Response<'a, T: ShmSend> {
+    data: RawSample<T>,
+    ffi_client: &'a ffi::Client,
+}
+
+impl<'a, T: ShmSend> Deref for Response<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: `as_payload_ptr` returns a non-null ptr
+        unsafe { &*self.data.as_payload_ptr() }
+    }
+}
+
+impl<'a, T: ShmSend> Drop for Response<'a, T> {
+    fn drop(&mut self) {
+        self.ffi_client.release_response(self.data);
+    }
+}
+
+impl<'a, T: ShmSend> Response<'a, T> {
+    pub(super) fn new(data: RawSample<T>, ffi_client: &'a ffi::Client) -> Self {
+        Self { data, ffi_client }
+    }
+
+    /// The sequence ID of the request this response belongs to
+    ///
+    /// This can be used to match the response with the [`RequestMut::sequence_id`](crate::RequestMut::sequence_id).
+    pub fn sequence_id(&self) -> i64 {
+        // SAFETY: `as_payload_ptr` returns a non-null ptr to the payload of a response
+        unsafe {
+            ffi::ResponseHeader::from_user_payload_unchecked(
+                self.data.as_payload_ptr().cast::<c_void>(),
+            )
+            .get_sequence_id()
+        }
+    }
+}
@@ -0,0 +1,360 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use super::MonitoringMode;
+use crate::IceoryxError;
+
+#[cfg(feature = "toml")]
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of shared memory segments; same as in iceoryx
+const MAX_SEGMENTS: usize = 100;
+/// The maximum number of mempools per segment; same as in iceoryx
+const MAX_MEMPOOLS_PER_SEGMENT: usize = 32;
+/// The maximum length of a POSIX group name; same as in iceoryx
+const MAX_GROUP_NAME_LENGTH: usize = 100;
+/// The only supported version of the config file
+#[cfg(feature = "toml")]
+const CONFIG_FILE_VERSION: u32 = 1;
+
+/// The mempools of the default config; same as for the `iox-roudi` daemon
+const DEFAULT_MEMPOOLS: [MempoolConfig; 7] = [
+    MempoolConfig::new(128, 10000),
+    MempoolConfig::new(1024, 5000),
+    MempoolConfig::new(16 * 1024, 1000),
+    MempoolConfig::new(128 * 1024, 200),
+    MempoolConfig::new(512 * 1024, 50),
+    MempoolConfig::new(1024 * 1024, 30),
+    MempoolConfig::new(4 * 1024 * 1024, 10),
+];
+
+/// The configuration of the shared memory segments and the application monitoring of `RouDi`
+///
+/// The default config has one segment for the group of the current process with the mempools of the
+/// `iox-roudi` daemon and monitors the applications.
+///
+/// With the `toml` feature, the segments and mempools can also be read from and written to the TOML config file
+/// format of the `iox-roudi` daemon with `from_toml` and `to_toml`.
+///
+/// # Example
+/// ```
+/// use iceoryx_rs::roudi::{MonitoringMode, RouDiConfig};
+///
+/// # use anyhow::{anyhow, Result};
+/// # fn main() -> Result<()> {
+/// let config = RouDiConfig::new()
+///     .add_mempool(128, 1000)
+///     .add_mempool(64 * 1024, 100);
+/// config.validate()?;
+///
+/// let _unmonitored_config = config.monitoring_mode(MonitoringMode::Off);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouDiConfig {
+    segments: Vec<SegmentConfig>,
+    monitoring_mode: MonitoringMode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SegmentConfig {
+    // `None` stands for the group of the current process
+    reader_group: Option<String>,
+    writer_group: Option<String>,
+    mempools: Vec<MempoolConfig>,
+}
+
+impl SegmentConfig {
+    fn new(reader_group: Option<String>, writer_group: Option<String>) -> Self {
+        Self {
+            reader_group,
+            writer_group,
+            mempools: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct MempoolConfig {
+    chunk_size: u32,
+    chunk_count: u32,
+}
+
+impl MempoolConfig {
+    const fn new(chunk_size: u32, chunk_count: u32) -> Self {
+        Self {
+            chunk_size,
+            chunk_count,
+        }
+    }
+}
+
+impl Default for RouDiConfig {
+    fn default() -> Self {
+        Self {
+            segments: vec![SegmentConfig {
+                reader_group: None,
+                writer_group: None,
+                mempools: DEFAULT_MEMPOOLS.to_vec(),
+            }],
+            monitoring_mode: MonitoringMode::On,
+        }
+    }
+}
+
+impl RouDiConfig {
+    /// Creates a config without any segments which monitors the applications
+    pub fn new() -> Self {
+        Self {
+            segments: Vec::new(),
+            monitoring_mode: MonitoringMode::On,
+        }
+    }
+
+    /// Adds a shared memory segment which can be read by the members of `reader_group` and written by the
+    /// members of `writer_group`
+    ///
+    /// The subsequently added mempools belong to this segment.
+    pub fn add_segment(mut self, reader_group: &str, writer_group: &str) -> Self {
+        self.segments.push(SegmentConfig::new(
+            Some(reader_group.to_string()),
+            Some(writer_group.to_string()),
+        ));
+        self
+    }
+
+    /// Adds a mempool with `chunk_count` chunks with a payload of `chunk_size` bytes to the last segment
+    ///
+    /// If there is no segment yet, a segment for the group of the current process is added.
+    pub fn add_mempool(mut self, chunk_size: u32, chunk_count: u32) -> Self {
+        if self.segments.is_empty() {
+            self.segments.push(SegmentConfig::new(None, None));
+        }
+        if let Some(segment) = self.segments.last_mut() {
+            segment
+                .mempools
+                .push(MempoolConfig::new(chunk_size, chunk_count));
+        }
+        self
+    }
+
+    /// Sets whether `RouDi` monitors the applications; defaults to [`MonitoringMode::On`]
+    pub fn monitoring_mode(mut self, monitoring_mode: MonitoringMode) -> Self {
+        self.monitoring_mode = monitoring_mode;
+        self
+    }
+
+    /// Checks whether `RouDi` can be launched with this config
+    ///
+    /// Returns [`IceoryxError::InvalidRouDiConfig`] if
+    /// - there are no segments or more than 100
+    /// - a segment has no mempools or more than 32
+    /// - a mempool has a chunk size or chunk count of zero
+    /// - a group does not exist
+    pub fn validate(&self) -> Result<(), IceoryxError> {
+        if self.segments.is_empty() {
+            return Err(invalid_config("no segments"));
+        }
+        if self.segments.len() > MAX_SEGMENTS {
+            return Err(invalid_config(format!(
+                "more than {} segments",
+                MAX_SEGMENTS
+            )));
+        }
+
+        for segment in &self.segments {
+            for group in segment.reader_group.iter().chain(&segment.writer_group) {
+                if group.is_empty() || group.len() > MAX_GROUP_NAME_LENGTH {
+                    return Err(invalid_config(format!("invalid group name '{}'", group)));
+                }
+                if !ffi::RouDiConfig::does_group_exist(group) {
+                    return Err(invalid_config(format!("group '{}' does not exist", group)));
+                }
+            }
+
+            if segment.mempools.is_empty() {
+                return Err(invalid_config("segment without mempools"));
+            }
+            if segment.mempools.len() > MAX_MEMPOOLS_PER_SEGMENT {
+                return Err(invalid_config(format!(
+                    "more than {} mempools in a segment",
+                    MAX_MEMPOOLS_PER_SEGMENT
+                )));
+            }
+            for mempool in &segment.mempools {
+                if mempool.chunk_size == 0 || mempool.chunk_count == 0 {
+                    return Err(invalid_config(
+                        "mempool with a chunk size or chunk count of zero",
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses a config in the TOML config file format of the `iox-roudi` daemon
+    ///
+    /// Unknown tables and keys are rejected. The monitoring mode is not part of the config file and is set to
+    /// [`MonitoringMode::On`].
+    ///
+    /// Like the `iox-roudi` daemon, only the structure of the config is checked. Use
+    /// [`validate`](Self::validate) to check whether `RouDi` can be launched with the config.
+    ///
+    /// # Example
+    /// ```
+    /// use iceoryx_rs::roudi::RouDiConfig;
+    ///
+    /// # use anyhow::{anyhow, Result};
+    /// # fn main() -> Result<()> {
+    /// let config = RouDiConfig::from_toml(
+    ///     r#"
+    ///     [general]
+    ///     version = 1
+    ///
+    ///     [[segment]]
+    ///
+    ///     [[segment.mempool]]
+    ///     size = 128
+    ///     count = 1000
+    ///     "#,
+    /// )?;
+    ///
+    /// assert_eq!(config, RouDiConfig::new().add_mempool(128, 1000));
+    /// assert_eq!(RouDiConfig::from_toml(&config.to_toml())?, config);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self, IceoryxError> {
+        let config_file: ConfigFile =
+            toml::from_str(toml).map_err(|e| invalid_config(e.message()))?;
+
+        if config_file.general.version != CONFIG_FILE_VERSION {
+            return Err(invalid_config("invalid config file version"));
+        }
+        if config_file.segment.is_empty() {
+            return Err(invalid_config("no segments"));
+        }
+
+        let mut config = Self::new();
+        for segment in config_file.segment {
+            if segment.mempool.is_empty() {
+                return Err(invalid_config("segment without mempools"));
+            }
+            config.segments.push(SegmentConfig {
+                reader_group: segment.reader,
+                writer_group: segment.writer,
+                mempools: segment
+                    .mempool
+                    .iter()
+                    .map(|mempool| MempoolConfig::new(mempool.size, mempool.count))
+                    .collect(),
+            });
+        }
+
+        Ok(config)
+    }
+
+    /// Writes the config in the TOML config file format of the `iox-roudi` daemon
+    ///
+    /// The monitoring mode is not part of the config file.
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> String {
+        let config_file = ConfigFile {
+            general: GeneralTable {
+                version: CONFIG_FILE_VERSION,
+            },
+            segment: self
+                .segments
+                .iter()
+                .map(|segment| SegmentTable {
+                    reader: segment.reader_group.clone(),
+                    writer: segment.writer_group.clone(),
+                    mempool: segment
+                        .mempools
+                        .iter()
+                        .map(|mempool| MempoolTable {
+                            size: mempool.chunk_size,
+                            count: mempool.chunk_count,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        };
+
+        // the config file consists only of tables, strings and integers which can always be serialized
+        toml::to_string(&config_file).expect("serializing the config file failed")
+    }
+
+    pub(super) fn get_monitoring_mode(&self) -> MonitoringMode {
+        self.monitoring_mode
+    }
+
+    /// Validates the config and converts it for the use with `RouDi` or the `RouDiEnvironment`
+    pub(crate) fn to_ffi(&self) -> Result<ffi::RouDiConfig, IceoryxError> {
+        self.validate()?;
+
+        let mut config = ffi::RouDiConfig::default();
+        for segment in &self.segments {
+            if !config.add_segment(
+                segment.reader_group.as_deref(),
+                segment.writer_group.as_deref(),
+            ) {
+                return Err(invalid_config("could not add segment"));
+            }
+            for mempool in &segment.mempools {
+                if !config.add_mempool(mempool.chunk_size, mempool.chunk_count) {
+                    return Err(invalid_config("could not add mempool"));
+                }
+            }
+        }
+        Ok(config)
+    }
+}
+
+fn invalid_config(reason: impl Into<String>) -> IceoryxError {
+    IceoryxError::InvalidRouDiConfig {
+        reason: reason.into(),
+    }
+}
+
+/// The TOML config file of the `iox-roudi` daemon
+#[cfg(feature = "toml")]
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    general: GeneralTable,
+    #[serde(default)]
+    segment: Vec<SegmentTable>,
+}
+
+#[cfg(feature = "toml")]
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GeneralTable {
+    version: u32,
+}
+
+#[cfg(feature = "toml")]
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SegmentTable {
+    // a missing group stands for the group of the current process
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reader: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    writer: Option<String>,
+    #[serde(default)]
+    mempool: Vec<MempoolTable>,
+}
+
+#[cfg(feature = "toml")]
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MempoolTable {
+    size: u32,
+    count: u32,
+}
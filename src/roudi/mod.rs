@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+//! `RouDi` running in the application process instead of the separate `iox-roudi` daemon
+
+mod config;
+pub use config::RouDiConfig;
+
+// re-exports from iceoryx-sys
+pub use ffi::MonitoringMode;
+
+use crate::IceoryxError;
+
+/// The central daemon of iceoryx, launched in the current process
+///
+/// `RouDi` manages the shared memory and the registered applications and processes their requests in
+/// background threads. It is shut down with [`shutdown`](Self::shutdown) or when it is dropped, which
+/// terminates all the applications still registered at it.
+///
+/// The runtime of the current process must be deregistered before `RouDi` is shut down, i.e. the order is
+/// 1. initialize the runtime with [`Runtime::try_init`](crate::Runtime::try_init) after launching `RouDi`
+/// 2. drop all ports, then shut down the [`RuntimeHandle`](crate::RuntimeHandle)
+/// 3. shut down `RouDi`
+///
+/// Otherwise the shutdown fails and a dropped `RouDi` logs a warning and keeps running until the process
+/// terminates.
+///
+/// # Example
+/// ```
+/// use iceoryx_rs::roudi::{RouDi, RouDiConfig};
+/// use iceoryx_rs::PublisherBuilder;
+/// use iceoryx_rs::Runtime;
+///
+/// # use anyhow::{anyhow, Result};
+/// # fn main() -> Result<()> {
+/// let mut roudi = RouDi::launch(RouDiConfig::default())?;
+///
+/// let runtime = Runtime::try_init("embedded_roudi")?;
+/// let publisher = PublisherBuilder::<u32>::new("all", "glory", "hypnotoad").create()?;
+///
+/// drop(publisher);
+/// runtime.shutdown()?;
+/// roudi.shutdown()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RouDi {
+    roudi: Option<Box<ffi::RouDi>>,
+}
+
+impl RouDi {
+    /// Launches `RouDi` with the memory layout and monitoring mode of `config`
+    ///
+    /// The following errors are returned
+    /// - [`IceoryxError::InvalidRouDiConfig`] if the config is invalid; see [`RouDiConfig::validate`]
+    /// - [`IceoryxError::RouDiAlreadyRunning`] if there is already a `RouDi` running, either in this or in
+    ///   another process
+    /// - [`IceoryxError::RouDiLockUnavailable`] if the file lock of `RouDi` cannot be created
+    pub fn launch(config: RouDiConfig) -> Result<Self, IceoryxError> {
+        let ffi_config = config.to_ffi()?;
+        let roudi = ffi::RouDi::launch(&ffi_config, config.get_monitoring_mode())?;
+
+        Ok(Self { roudi: Some(roudi) })
+    }
+
+    /// Shuts down `RouDi` and terminates all the applications still registered at it
+    ///
+    /// Returns [`IceoryxError::RouDiInUse`] if the runtime of the current process is still registered since the
+    /// shutdown would terminate this process as well. `RouDi` keeps running in this case and the shutdown can be
+    /// retried once the runtime is shut down. Calling this again after a successful shutdown does nothing.
+    pub fn shutdown(&mut self) -> Result<(), IceoryxError> {
+        if self.roudi.is_some() && ffi::Runtime::is_initialized() {
+            return Err(IceoryxError::RouDiInUse);
+        }
+
+        if let Some(roudi) = self.roudi.take() {
+            roudi.shutdown();
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for RouDi {
+    fn drop(&mut self) {
+        if self.shutdown().is_err() {
+            if let Some(roudi) = self.roudi.take() {
+                roudi.leak();
+            }
+        }
+    }
+}
@@ -0,0 +1,324 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::IceoryxError;
+use crate::LogLevel;
+
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+/// The default timeout for waiting on `RouDi`; same as for the iceoryx C++ runtime
+const DEFAULT_ROUDI_WAITING_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The default log level of iceoryx; only warnings and errors are logged
+const DEFAULT_LOG_LEVEL: LogLevel = LogLevel::Warn;
+
+/// The runtime that is needed for each application to communicate with the `RouDi` daemon
+pub struct Runtime {}
+
+impl Runtime {
+    /// With this associated function the application registers at `RouDi`
+    ///
+    /// The call to this function is required in order to create publisher and subscriber and must be done early
+    /// in the application startup. There cannot be two application with the same `app_name` be registered
+    /// simultaneously at `RouDi`.
+    ///
+    /// If `RouDi` is not available or the `app_name` is invalid, iceoryx terminates the application.
+    /// Use [`try_init`](Self::try_init) to handle these cases gracefully.
+    ///
+    /// Only warnings and errors are logged by iceoryx. Use the [`RuntimeBuilder`] for a different log level.
+    pub fn init(app_name: &str) {
+        RuntimeBuilder::new(app_name).init();
+    }
+
+    /// Same as [`init`](Self::init) but returns an error instead of terminating the application
+    ///
+    /// `RouDi` is awaited for 60 seconds. Use [`try_init_with_timeout`](Self::try_init_with_timeout) for a
+    /// custom timeout.
+    ///
+    /// The application is deregistered from `RouDi` when the returned [`RuntimeHandle`] is dropped.
+    /// Afterwards, the runtime can be initialized again, e.g. with a different name. There can only be one
+    /// runtime at a time and `try_init` fails if [`init`](Self::init) was already called.
+    ///
+    /// # Example
+    /// ```
+    /// use iceoryx_rs::IceoryxError;
+    /// use iceoryx_rs::PublisherBuilder;
+    /// use iceoryx_rs::Runtime;
+    /// # use ffi::RouDiEnvironment;
+    /// #
+    /// # use anyhow::{anyhow, Result};
+    /// # fn main() -> Result<()> {
+    /// # let _roudi = RouDiEnvironment::new();
+    ///
+    /// let runtime = Runtime::try_init("hypnotoad")?;
+    /// assert_eq!(runtime.app_name(), "hypnotoad");
+    ///
+    /// assert!(matches!(
+    ///     Runtime::try_init("brain_slug"),
+    ///     Err(IceoryxError::RuntimeAlreadyInitialized)
+    /// ));
+    ///
+    /// let publisher = PublisherBuilder::<u32>::new("all", "glory", "hypnotoad").create()?;
+    /// drop(publisher);
+    /// drop(runtime);
+    ///
+    /// let runtime = Runtime::try_init("brain_slug")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_init(app_name: &str) -> Result<RuntimeHandle, IceoryxError> {
+        RuntimeBuilder::new(app_name).try_init()
+    }
+
+    /// Same as [`try_init`](Self::try_init) but waits at most `roudi_waiting_timeout` for `RouDi`
+    ///
+    /// See [`RuntimeBuilder::try_init`] for the returned errors.
+    pub fn try_init_with_timeout(
+        app_name: &str,
+        roudi_waiting_timeout: Duration,
+    ) -> Result<RuntimeHandle, IceoryxError> {
+        RuntimeBuilder::new(app_name)
+            .roudi_waiting_timeout(roudi_waiting_timeout)
+            .try_init()
+    }
+}
+
+/// Initialize the runtime with custom options
+///
+/// # Example
+/// ```
+/// use iceoryx_rs::LogLevel;
+/// use iceoryx_rs::RuntimeBuilder;
+/// # use ffi::RouDiEnvironment;
+/// #
+/// # use anyhow::{anyhow, Result};
+/// # fn main() -> Result<()> {
+/// # let _roudi = RouDiEnvironment::new();
+/// use std::time::Duration;
+///
+/// let runtime = RuntimeBuilder::new("hypnotoad")
+///     .log_level(LogLevel::Info)
+///     .roudi_waiting_timeout(Duration::from_secs(5))
+///     .try_init()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RuntimeBuilder<'a> {
+    app_name: &'a str,
+    log_level: LogLevel,
+    roudi_waiting_timeout: Duration,
+    #[cfg(feature = "log")]
+    forward_log_to_log_crate: bool,
+}
+
+impl<'a> RuntimeBuilder<'a> {
+    /// Creates a new `RuntimeBuilder` for an application with the name `app_name`
+    pub fn new(app_name: &'a str) -> Self {
+        Self {
+            app_name,
+            log_level: DEFAULT_LOG_LEVEL,
+            roudi_waiting_timeout: DEFAULT_ROUDI_WAITING_TIMEOUT,
+            #[cfg(feature = "log")]
+            forward_log_to_log_crate: false,
+        }
+    }
+
+    /// The log level of iceoryx; defaults to [`LogLevel::Warn`]
+    ///
+    /// The log level applies to the whole process, i.e. the last initialized runtime determines the log level.
+    pub fn log_level(mut self, log_level: LogLevel) -> Self {
+        self.log_level = log_level;
+        self
+    }
+
+    /// The maximum time [`try_init`](Self::try_init) waits for `RouDi`; defaults to 60 seconds
+    pub fn roudi_waiting_timeout(mut self, roudi_waiting_timeout: Duration) -> Self {
+        self.roudi_waiting_timeout = roudi_waiting_timeout;
+        self
+    }
+
+    /// Forwards the log output of iceoryx to the [`log`](https://docs.rs/log) crate instead of writing it to stderr
+    ///
+    /// The messages are logged with the `iceoryx` target and iceoryx's `Fatal` and `Error` levels are mapped to
+    /// `log::Level::Error` and `Verbose` to `log::Level::Trace`. The [`log_level`](Self::log_level) is still
+    /// applied before the messages are forwarded, i.e. set it to [`LogLevel::Verbose`] to leave the filtering
+    /// to the logger. With the `tracing-log` crate, the messages can also be forwarded to `tracing`.
+    ///
+    /// The forwarding must be installed before iceoryx logs from other threads, i.e. it cannot be installed while a
+    /// runtime is initialized. [`try_init`](Self::try_init) returns [`IceoryxError::RuntimeAlreadyInitialized`] in
+    /// this case and [`init`](Self::init) does not install the forwarding. Once installed, the forwarding stays
+    /// active until the process terminates.
+    #[cfg(feature = "log")]
+    pub fn forward_log_to_log_crate(mut self) -> Self {
+        self.forward_log_to_log_crate = true;
+        self
+    }
+
+    /// Registers the application at `RouDi`; see [`Runtime::init`]
+    pub fn init(self) {
+        // without a runtime the forwarding can only fail if it is already installed
+        let _ = self.install_log_sink();
+        ffi::Runtime::init(self.app_name, self.log_level);
+    }
+
+    /// Registers the application at `RouDi`; see [`Runtime::try_init`]
+    ///
+    /// The following errors are returned
+    /// - [`IceoryxError::InvalidName`] if `app_name` is empty, longer than 100 characters or contains other
+    ///   characters than ASCII letters, digits, `-`, `.`, `:` and `_`
+    /// - [`IceoryxError::RouDiNotAvailable`] if `RouDi` did not become available within the
+    ///   [`roudi_waiting_timeout`](Self::roudi_waiting_timeout)
+    /// - [`IceoryxError::NameAlreadyRegistered`] if an application with the same name is currently running
+    /// - [`IceoryxError::RuntimeAlreadyInitialized`] if there is already a runtime in this process
+    pub fn try_init(self) -> Result<RuntimeHandle, IceoryxError> {
+        self.install_log_sink()?;
+        ffi::Runtime::try_init(self.app_name, self.roudi_waiting_timeout, self.log_level).map_err(
+            |e| match e {
+                ffi::RuntimeInitError::InvalidName => IceoryxError::InvalidName {
+                    name: self.app_name.to_string(),
+                },
+                ffi::RuntimeInitError::RouDiNotAvailable => IceoryxError::RouDiNotAvailable {
+                    timeout: self.roudi_waiting_timeout,
+                },
+                ffi::RuntimeInitError::NameAlreadyRegistered => {
+                    IceoryxError::NameAlreadyRegistered {
+                        name: self.app_name.to_string(),
+                    }
+                }
+                ffi::RuntimeInitError::RuntimeAlreadyInitialized => {
+                    IceoryxError::RuntimeAlreadyInitialized
+                }
+            },
+        )?;
+
+        Ok(RuntimeHandle {
+            app_name: self.app_name.to_string(),
+        })
+    }
+
+    fn install_log_sink(&self) -> Result<(), IceoryxError> {
+        #[cfg(feature = "log")]
+        if self.forward_log_to_log_crate {
+            match ffi::set_log_sink(forward_to_log_crate) {
+                // the sink can only be installed once and is already active
+                Ok(()) | Err(ffi::LogSinkError::AlreadySet) => (),
+                Err(ffi::LogSinkError::RuntimeAlreadyInitialized) => {
+                    return Err(IceoryxError::RuntimeAlreadyInitialized)
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "log")]
+fn forward_to_log_crate(level: LogLevel, message: &str) {
+    let level = match level {
+        LogLevel::Off => return,
+        LogLevel::Fatal | LogLevel::Error => log::Level::Error,
+        LogLevel::Warn => log::Level::Warn,
+        LogLevel::Info => log::Level::Info,
+        LogLevel::Debug => log::Level::Debug,
+        LogLevel::Verbose => log::Level::Trace,
+    };
+    log::log!(target: "iceoryx", level, "{}", message);
+}
+
+/// A handle to the runtime which was successfully registered at `RouDi`
+///
+/// The runtime is deregistered from `RouDi` when the handle is dropped or [`shutdown`](Self::shutdown) is called.
+///
+/// All publisher, subscriber, samples, client, server, `Listener` and `WaitSet` should be dropped before the
+/// handle. If some of these objects are still alive, the runtime is deregistered when the last of them is dropped.
+#[derive(Debug)]
+pub struct RuntimeHandle {
+    app_name: String,
+}
+
+impl RuntimeHandle {
+    /// The name the application is registered with at `RouDi`
+    pub fn app_name(&self) -> &str {
+        &self.app_name
+    }
+
+    /// Deregisters the application from `RouDi`
+    ///
+    /// Returns [`IceoryxError::RuntimeInUse`] if objects created with the runtime are still alive. The runtime
+    /// is then deregistered when the last of these objects is dropped.
+    pub fn shutdown(mut self) -> Result<(), IceoryxError> {
+        let result = shutdown_or_defer();
+        let app_name = std::mem::take(&mut self.app_name);
+        std::mem::forget(self); // forget `self` to not shut down twice
+
+        result.map_err(|count| IceoryxError::RuntimeInUse {
+            name: app_name,
+            count,
+        })
+    }
+}
+
+impl Drop for RuntimeHandle {
+    fn drop(&mut self) {
+        // the shutdown is deferred until the last object which uses the runtime is dropped
+        let _ = shutdown_or_defer();
+    }
+}
+
+/// Shuts the runtime down or defers the shutdown and returns the number of alive objects which use the runtime
+fn shutdown_or_defer() -> Result<(), usize> {
+    let mut resources = runtime_resources();
+    if resources.count == 0 {
+        // SAFETY: there are no objects left which use the runtime
+        unsafe { ffi::Runtime::shutdown() };
+        return Ok(());
+    }
+
+    resources.is_shutdown_pending = true;
+    Err(resources.count)
+}
+
+struct RuntimeResources {
+    // the number of alive objects which use resources of the runtime, e.g. ports or condition variables
+    count: usize,
+    // the runtime handle was dropped while there were alive objects
+    is_shutdown_pending: bool,
+}
+
+static RUNTIME_RESOURCES: Mutex<RuntimeResources> = Mutex::new(RuntimeResources {
+    count: 0,
+    is_shutdown_pending: false,
+});
+
+fn runtime_resources() -> MutexGuard<'static, RuntimeResources> {
+    // the counter cannot be left in an inconsistent state
+    RUNTIME_RESOURCES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Marks an object which uses resources of the runtime; the runtime cannot be shut down while it is alive
+#[derive(Debug)]
+pub(crate) struct RuntimeResource {
+    _private: (),
+}
+
+impl RuntimeResource {
+    pub(crate) fn new() -> Self {
+        runtime_resources().count += 1;
+        Self { _private: () }
+    }
+}
+
+impl Drop for RuntimeResource {
+    fn drop(&mut self) {
+        let mut resources = runtime_resources();
+        resources.count -= 1;
+        if resources.count == 0 && resources.is_shutdown_pending {
+            resources.is_shutdown_pending = false;
+            // SAFETY: this was the last object which used the runtime
+            unsafe { ffi::Runtime::shutdown() };
+        }
+    }
+}
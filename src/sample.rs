@@ -3,22 +3,53 @@
 // SPDX-FileContributor: Mathias Kraus
 
 use crate::marker::ShmSend;
+use crate::runtime::RuntimeResource;
+use crate::type_identity;
+use crate::user_header::{self, NoUserHeader};
+use crate::IceoryxError;
+use crate::TypeIdentity;
 use crate::{RawSample, SubscribeState};
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
 use std::time::{Duration, SystemTime};
 
+#[cfg(feature = "serde")]
+use crate::codec::Codec;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+
+#[cfg(feature = "flatbuffers")]
+use crate::flatbuffer;
+#[cfg(feature = "flatbuffers")]
+use flatbuffers::{Follow, Verifiable};
+
+#[cfg(feature = "async")]
+use crate::reactor::Registration;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::sync::OnceLock;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
+
 use std::mem::ManuallyDrop;
 use std::ops::Deref;
 
 //TODO impl debug for Sample with T: Debug
 /// An immutable sample shared between multiple subscriber
-pub struct Sample<T: ?Sized, S: ffi::SubscriberStrongRef> {
+pub struct Sample<T: ?Sized, S: ffi::SubscriberStrongRef, H = NoUserHeader> {
     data: RawSample<T>,
     ffi_sub: ManuallyDrop<S>,
+    // the sample references the port of the subscriber which must not be released by a runtime shutdown
+    runtime_resource: ManuallyDrop<RuntimeResource>,
+    has_type_identity: bool,
+    phantom: PhantomData<H>,
 }
 
-impl<T: ?Sized, S: ffi::SubscriberStrongRef> Deref for Sample<T, S> {
+impl<T: ?Sized, S: ffi::SubscriberStrongRef, H> Deref for Sample<T, S, H> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -27,21 +58,43 @@ impl<T: ?Sized, S: ffi::SubscriberStrongRef> Deref for Sample<T, S> {
     }
 }
 
-impl<T: ?Sized, S: ffi::SubscriberStrongRef> Drop for Sample<T, S> {
+impl<T: ?Sized, S: ffi::SubscriberStrongRef, H> Drop for Sample<T, S, H> {
     fn drop(&mut self) {
         self.ffi_sub.as_ref().release(self.data);
         unsafe {
             ManuallyDrop::<S>::drop(&mut self.ffi_sub);
+            ManuallyDrop::<RuntimeResource>::drop(&mut self.runtime_resource);
         }
     }
 }
 
-impl<T: ?Sized, S: ffi::SubscriberStrongRef> Sample<T, S> {
+impl<T: ?Sized, S: ffi::SubscriberStrongRef, H> Sample<T, S, H> {
+    fn new(data: RawSample<T>, ffi_sub: S, has_type_identity: bool) -> Self {
+        Self {
+            data,
+            ffi_sub: ManuallyDrop::new(ffi_sub),
+            runtime_resource: ManuallyDrop::new(RuntimeResource::new()),
+            has_type_identity,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Get a reference to the user header
+    ///
+    /// The size of the user header was checked when the sample was taken from the receiver queue.
+    pub fn user_header(&self) -> &H {
+        // SAFETY: the ptr is valid and aligned since the user header was checked when the sample was taken
+        unsafe {
+            &*user_header::user_header_ptr::<H>(self.data.chunk_header(), self.has_type_identity)
+        }
+    }
+
     fn into_raw_parts(mut self) -> (RawSample<T>, S) {
         let sample = self.data;
         let ffi_sub = ManuallyDrop::into_inner(self.ffi_sub.clone());
         unsafe {
             ManuallyDrop::<S>::drop(&mut self.ffi_sub);
+            ManuallyDrop::<RuntimeResource>::drop(&mut self.runtime_resource);
         }
         std::mem::forget(self); // forget `self` to not call drop
         (sample, ffi_sub)
@@ -59,7 +112,7 @@ impl<T: ?Sized, S: ffi::SubscriberStrongRef> Sample<T, S> {
     }
 }
 
-impl<S: ffi::SubscriberStrongRef> Sample<[u8], S> {
+impl<S: ffi::SubscriberStrongRef, H> Sample<[u8], S, H> {
     /// Get a reference to a T
     ///
     /// If the size and alignment of T do not match with the alignment of the underlying buffer, None is returned.
@@ -81,27 +134,124 @@ impl<S: ffi::SubscriberStrongRef> Sample<[u8], S> {
             None
         }
     }
+
+    /// Deserializes a `T` from the payload with `codec`
+    ///
+    /// The sample must have been published with
+    /// [`Publisher::publish_serialized`](crate::Publisher::publish_serialized) and the same codec. Types which
+    /// borrow from the payload, like `&str`, are bound to the lifetime of the sample.
+    ///
+    /// Returns [`IceoryxError::DeserializationFailed`] if the payload could not be deserialized.
+    #[cfg(feature = "serde")]
+    pub fn deserialize<'de, T: Deserialize<'de>>(
+        &'de self,
+        codec: impl Codec,
+    ) -> Result<T, IceoryxError> {
+        codec.deserialize(self)
+    }
+
+    /// Gets the plain FlatBuffer of a sample published with
+    /// [`Publisher::publish_flatbuffer`](crate::Publisher::publish_flatbuffer)
+    ///
+    /// Returns [`IceoryxError::InvalidFlatBuffer`] if the payload does not have the layout of the
+    /// [`flatbuffer`](crate::flatbuffer) module.
+    #[cfg(feature = "flatbuffers")]
+    pub fn flatbuffer_data(&self) -> Result<&[u8], IceoryxError> {
+        flatbuffer::finished_data(self)
+    }
+
+    /// Verifies the FlatBuffer of a sample published with
+    /// [`Publisher::publish_flatbuffer`](crate::Publisher::publish_flatbuffer) and returns its root `T`
+    ///
+    /// The message is read directly from the shared memory without a copy.
+    ///
+    /// Returns [`IceoryxError::InvalidFlatBuffer`] if the payload is not a valid FlatBuffer with a root `T`.
+    #[cfg(feature = "flatbuffers")]
+    pub fn flatbuffer_root<'a, T: 'a + Follow<'a> + Verifiable>(
+        &'a self,
+    ) -> Result<T::Inner, IceoryxError> {
+        flatbuffers::root::<T>(self.flatbuffer_data()?).map_err(|e| {
+            IceoryxError::InvalidFlatBuffer {
+                reason: e.to_string(),
+            }
+        })
+    }
 }
 
+/// The notification index used for the condition variable owned by the `SampleReceiver`
+const NOTIFICATION_INDEX: u64 = 0;
+
 /// Access to the sample receiver queue of the subscriber
-pub struct SampleReceiver<T: ?Sized, S: ffi::SubscriberStrongRef> {
+pub struct SampleReceiver<T: ?Sized, S: ffi::SubscriberStrongRef, H = NoUserHeader> {
     ffi_sub: S,
     condition_variable: Box<ffi::ConditionVariable>,
-    phantom: PhantomData<T>,
+    _runtime_resource: RuntimeResource,
+    attached: AtomicBool,
+    type_identity: Option<TypeIdentity>,
+    // the lock is held while taking a sample
+    sequence_numbers: Mutex<SequenceNumbers>,
+    lost_samples: AtomicU64,
+    discarded_samples: AtomicU64,
+    // the condition variable of a reactor is used once the `SampleReceiver` is polled asynchronously
+    #[cfg(feature = "async")]
+    registration: OnceLock<Registration>,
+    phantom: PhantomData<(H, T)>,
 }
 
-impl<T: ?Sized, S: ffi::SubscriberStrongRef> SampleReceiver<T, S> {
-    pub(super) fn new(ffi_sub: S) -> Self {
+impl<T: ?Sized, S: ffi::SubscriberStrongRef, H> SampleReceiver<T, S, H> {
+    pub(super) fn new(ffi_sub: S, type_identity: Option<TypeIdentity>) -> Self {
         let condition_variable = ffi::ConditionVariable::new();
-        ffi_sub.as_ref().set_condition_variable(&condition_variable);
+        ffi_sub
+            .as_ref()
+            .set_condition_variable(&condition_variable, NOTIFICATION_INDEX);
 
         SampleReceiver {
             ffi_sub,
             condition_variable,
+            _runtime_resource: RuntimeResource::new(),
+            attached: AtomicBool::new(false),
+            type_identity,
+            sequence_numbers: Mutex::new(SequenceNumbers::default()),
+            lost_samples: AtomicU64::new(0),
+            discarded_samples: AtomicU64::new(0),
+            #[cfg(feature = "async")]
+            registration: OnceLock::new(),
             phantom: PhantomData,
         }
     }
 
+    /// Replaces the own condition variable with the one from a `WaitSet`
+    ///
+    /// Fails if the `SampleReceiver` is already attached to another condition variable.
+    pub(crate) fn attach_condition_variable(
+        &self,
+        condition_variable: &ffi::ConditionVariable,
+        notification_index: u64,
+    ) -> Result<(), IceoryxError> {
+        if self.attached.swap(true, Ordering::Relaxed) {
+            return Err(IceoryxError::AlreadyAttached);
+        }
+
+        let ffi_sub = self.ffi_sub.as_ref();
+        ffi_sub.unset_condition_variable();
+        ffi_sub.set_condition_variable(condition_variable, notification_index);
+
+        Ok(())
+    }
+
+    /// Restores the own condition variable after it was replaced by [`attach_condition_variable`](Self::attach_condition_variable)
+    pub(crate) fn detach_condition_variable(&self) {
+        let ffi_sub = self.ffi_sub.as_ref();
+        ffi_sub.unset_condition_variable();
+        ffi_sub.set_condition_variable(&self.condition_variable, NOTIFICATION_INDEX);
+
+        self.attached.store(false, Ordering::Relaxed);
+    }
+
+    pub(crate) fn ffi_sub(&self) -> &S {
+        &self.ffi_sub
+    }
+
     /// The current subscription state of the corresponding subscriber
     ///
     /// After `SubscriberBuilder::create` this will immediately be [`SubscribeState::Subscribed`] but after
@@ -115,6 +265,10 @@ impl<T: ?Sized, S: ffi::SubscriberStrongRef> SampleReceiver<T, S> {
     ///
     /// This method unblock when either new samples are available, the timeout duration elapsed or the
     /// `SampleReceiver` was stopped.
+    ///
+    /// While the `SampleReceiver` is attached to a [`WaitSet`](crate::WaitSet), new samples do not wake up this
+    /// method and it will only return after the timeout elapsed. Use the `WaitSet` instead. The same applies
+    /// once the `SampleReceiver` was polled asynchronously.
     pub fn wait_for_samples(&self, timeout: Duration) -> SampleReceiverWaitState {
         if !self.ffi_sub.as_ref().is_condition_variable_set() {
             return SampleReceiverWaitState::Stopped;
@@ -146,6 +300,36 @@ impl<T: ?Sized, S: ffi::SubscriberStrongRef> SampleReceiver<T, S> {
         self.ffi_sub.as_ref().has_chunks()
     }
 
+    /// Checks whether samples were discarded due to a full receiver queue since the last call
+    ///
+    /// This happens with [`QueueFullPolicy::DiscardOldestData`](crate::QueueFullPolicy::DiscardOldestData) when
+    /// the samples are not taken fast enough. The flag is reset by this call and is shared with
+    /// [`WaitSet::lost_samples`](crate::WaitSet::lost_samples).
+    pub fn has_lost_samples(&self) -> bool {
+        self.ffi_sub.as_ref().has_lost_chunks()
+    }
+
+    /// The total number of lost samples since the creation of the `SampleReceiver`
+    ///
+    /// The lost samples are detected by gaps in the sequence numbers of the taken samples of each publisher.
+    /// This includes samples discarded due to a full receiver queue as well as samples which were rejected
+    /// due to a mismatching user header or payload. Lost samples are counted once the next sample of the same
+    /// publisher is taken. A sequence number which is not greater than the last one, e.g. from the history of a
+    /// reconnected publisher, starts the counting anew.
+    pub fn lost_samples(&self) -> u64 {
+        self.lost_samples.load(Ordering::Relaxed)
+    }
+
+    /// The total number of samples which were discarded by `take` or `try_take` since the creation of the
+    /// `SampleReceiver`
+    ///
+    /// Samples are discarded if they do not match `T`, `H` or the [`TypeIdentity`] or if too many samples are
+    /// held in parallel. iceoryx does not transfer the payload and user header type when connecting publisher
+    /// and subscriber, therefore a mismatch can only be detected when a sample is taken.
+    pub fn discarded_samples(&self) -> u64 {
+        self.discarded_samples.load(Ordering::Relaxed)
+    }
+
     /// Clears the receiver queue and release all the samples from the queue
     pub fn clear(&self) {
         self.ffi_sub.as_ref().clear();
@@ -155,45 +339,302 @@ impl<T: ?Sized, S: ffi::SubscriberStrongRef> SampleReceiver<T, S> {
     pub fn release_raw(&self, sample: RawSample<T>) {
         self.ffi_sub.as_ref().release(sample);
     }
+
+    /// Takes a chunk with `take` and counts the gap to the last sequence number of the same publisher
+    fn take_chunk<F>(&self, take: F) -> Result<Option<RawSample<T>>, IceoryxError>
+    where
+        F: FnOnce(&ffi::Subscriber) -> Result<Option<RawSample<T>>, ffi::ChunkTakeError>,
+    {
+        let mut sequence_numbers = self
+            .sequence_numbers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let data = match take(self.ffi_sub.as_ref()).inspect_err(|_| self.discard())? {
+            Some(data) => data,
+            None => return Ok(None),
+        };
+
+        let chunk_header = data.chunk_header();
+        let lost_samples = sequence_numbers.update(
+            chunk_header.get_origin_id(),
+            chunk_header.get_sequence_number(),
+        );
+        if lost_samples > 0 {
+            self.lost_samples.fetch_add(lost_samples, Ordering::Relaxed);
+        }
+
+        Ok(Some(data))
+    }
+
+    /// Checks the type identity of the sample and releases it on a mismatch
+    fn check_type_identity(
+        &self,
+        sample: RawSample<T>,
+        expected: TypeIdentity,
+    ) -> Result<(), IceoryxError> {
+        type_identity::check_type_identity::<H>(sample.chunk_header(), expected).inspect_err(|_| {
+            self.release_raw(sample);
+            self.discard();
+        })
+    }
+
+    fn discard(&self) {
+        self.discarded_samples.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Polls the receiver queue with `take` and registers the waker of `cx` if it is empty
+    ///
+    /// Returns `Poll::Ready(None)` if the `SampleReceiver` was stopped.
+    #[cfg(feature = "async")]
+    fn poll_take<F>(&self, cx: &mut Context<'_>, take: F) -> Poll<Option<Sample<T, S, H>>>
+    where
+        F: Fn(&Self) -> Option<Sample<T, S, H>>,
+    {
+        if let Some(sample) = take(self) {
+            return Poll::Ready(Some(sample));
+        }
+        // attaching to the reactor would revert the stop
+        if !self.ffi_sub.as_ref().is_condition_variable_set() {
+            return Poll::Ready(None);
+        }
+
+        let registration = self.registration.get_or_init(|| {
+            let registration = Registration::new();
+            self.attach_condition_variable(
+                registration.condition_variable(),
+                registration.notification_index(),
+            )
+            .expect(
+                "The exclusive borrow prevents the SampleReceiver from being attached elsewhere",
+            );
+            registration
+        });
+        registration.register_waker(cx.waker());
+
+        // a sample might have been received before the waker was registered
+        if let Some(sample) = take(self) {
+            return Poll::Ready(Some(sample));
+        }
+
+        if self.ffi_sub.as_ref().is_condition_variable_set() {
+            Poll::Pending
+        } else {
+            Poll::Ready(None)
+        }
+    }
 }
 
-impl<T, S: ffi::SubscriberStrongRef> SampleReceiver<T, S> {
+impl<T, S: ffi::SubscriberStrongRef, H> SampleReceiver<T, S, H> {
     /// Takes a sample from the receiver queue
     ///
-    /// If the receiver queue is empty, `None` will be returned.
-    pub fn take(&self) -> Option<Sample<T, S>> {
-        self.ffi_sub
-            .as_ref()
-            .try_take::<T>()
-            .map(|data| Sample::<T, S> {
-                data,
-                ffi_sub: ManuallyDrop::new(self.ffi_sub.clone()),
-            })
+    /// If the receiver queue is empty or too many samples are held in parallel, `None` will be returned. Samples
+    /// which do not match `T`, `H` or the [`TypeIdentity`] are discarded and the next sample of the queue is taken
+    /// instead. The discarded samples are counted by [`discarded_samples`](Self::discarded_samples). Use
+    /// [`try_take`](Self::try_take) to get the reason why a sample could not be taken.
+    pub fn take(&self) -> Option<Sample<T, S, H>> {
+        take_skipping_mismatches(|| self.try_take())
+    }
+
+    /// Takes a sample from the receiver queue and reports why a sample could not be taken
+    ///
+    /// Returns `Ok(None)` if the receiver queue is empty. Samples which do not match are released, counted by
+    /// [`discarded_samples`](Self::discarded_samples) and one of the following errors is returned
+    /// - [`IceoryxError::TooManySamplesHoldInParallel`] if too many samples are held at the same time;
+    ///   the sample is discarded by iceoryx
+    /// - [`IceoryxError::UserHeaderSizeMismatch`] if the size of the user header does not match `H`
+    /// - [`IceoryxError::PayloadSizeMismatch`] if the payload size does not match the size of `T`
+    /// - [`IceoryxError::AlignmentMismatch`] if the payload alignment is smaller than the one of `T`
+    /// - [`IceoryxError::TypeMismatch`] if the subscriber was created with
+    ///   [`SubscriberBuilder::with_type_identity`](crate::SubscriberBuilder::with_type_identity) and the
+    ///   [`TypeIdentity`] does not match
+    pub fn try_take(&self) -> Result<Option<Sample<T, S, H>>, IceoryxError> {
+        let Some(expected) = self.type_identity else {
+            return Ok(self
+                .take_chunk(|ffi_sub| ffi_sub.try_take::<T, H>())?
+                .map(|data| Sample::new(data, self.ffi_sub.clone(), false)));
+        };
+
+        // a zero-sized user header accepts all chunks and the type identity check reports the mismatches
+        let Some(data) = self.take_chunk(|ffi_sub| ffi_sub.try_take::<T, NoUserHeader>())? else {
+            return Ok(None);
+        };
+        self.check_type_identity(data, expected)?;
+        Ok(Some(Sample::new(data, self.ffi_sub.clone(), true)))
+    }
+
+    /// Asynchronously waits for the next sample
+    ///
+    /// Returns `None` if the `SampleReceiver` was stopped. Stopping the `SampleReceiver` does not wake up a
+    /// pending task, the stop is only detected with the next received sample or when the task is polled again.
+    ///
+    /// No thread is blocked while waiting. The `SampleReceiver` is attached to a reactor on the first poll,
+    /// which wakes up the task when a sample is received, and cannot be attached to a [`WaitSet`](crate::WaitSet)
+    /// anymore.
+    ///
+    /// # Example
+    /// ```
+    /// # use iceoryx_rs::Runtime;
+    /// use iceoryx_rs::PublisherBuilder;
+    /// use iceoryx_rs::SubscriberBuilder;
+    /// # use ffi::RouDiEnvironment;
+    /// #
+    /// # use anyhow::{anyhow, Result};
+    /// # fn main() -> Result<()> {
+    /// # let _roudi = RouDiEnvironment::new();
+    /// #
+    /// # Runtime::init("async_sample_receiver");
+    ///
+    /// let (subscriber, sample_receive_token) =
+    ///     SubscriberBuilder::<u32>::new("all", "glory", "hypnotoad").create_mt()?;
+    /// let mut sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+    ///
+    /// let publisher = PublisherBuilder::<u32>::new("all", "glory", "hypnotoad").create()?;
+    /// publisher.publish(publisher.loan()?);
+    ///
+    /// futures::executor::block_on(async {
+    ///     if let Some(sample) = sample_receiver.next().await {
+    ///         println!("Receiving: {}", *sample);
+    ///     }
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn next(&mut self) -> Option<Sample<T, S, H>> {
+        std::future::poll_fn(|cx| self.poll_take(cx, Self::take)).await
     }
 }
 
-impl<T, S: ffi::SubscriberStrongRef> SampleReceiver<[T], S> {
+impl<T, S: ffi::SubscriberStrongRef, H> SampleReceiver<[T], S, H> {
     /// Takes a sample from the receiver queue
     ///
-    /// If the receiver queue is empty, `None` will be returned.
-    pub fn take(&self) -> Option<Sample<[T], S>> {
-        self.ffi_sub
-            .as_ref()
-            .try_take_slice::<T>()
-            .map(|data| Sample::<[T], S> {
-                data,
-                ffi_sub: ManuallyDrop::new(self.ffi_sub.clone()),
-            })
+    /// Have a look at the `take` method for non-slice types for details.
+    pub fn take(&self) -> Option<Sample<[T], S, H>> {
+        take_skipping_mismatches(|| self.try_take())
+    }
+
+    /// Takes a sample from the receiver queue and reports why a sample could not be taken
+    ///
+    /// Have a look at the `try_take` method for non-slice types for details. Contrary to non-slice types,
+    /// the payload size must be a multiple of the size of `T`.
+    pub fn try_take(&self) -> Result<Option<Sample<[T], S, H>>, IceoryxError> {
+        let Some(expected) = self.type_identity else {
+            return Ok(self
+                .take_chunk(|ffi_sub| ffi_sub.try_take_slice::<T, H>())?
+                .map(|data| Sample::new(data, self.ffi_sub.clone(), false)));
+        };
+
+        // a zero-sized user header accepts all chunks and the type identity check reports the mismatches
+        let Some(data) = self.take_chunk(|ffi_sub| ffi_sub.try_take_slice::<T, NoUserHeader>())?
+        else {
+            return Ok(None);
+        };
+        self.check_type_identity(data, expected)?;
+        Ok(Some(Sample::new(data, self.ffi_sub.clone(), true)))
+    }
+
+    /// Asynchronously waits for the next sample
+    ///
+    /// Have a look at the `next` method for non-slice types for details.
+    #[cfg(feature = "async")]
+    pub async fn next(&mut self) -> Option<Sample<[T], S, H>> {
+        std::future::poll_fn(|cx| self.poll_take(cx, Self::take)).await
+    }
+}
+
+/// A stream of samples which ends when the `SampleReceiver` is stopped
+///
+/// This is mainly intended for the [`mt::SampleReceiver`](crate::mt::SampleReceiver) in combination with
+/// a multi-threaded async runtime. Have a look at [`SampleReceiver::next`] for details.
+#[cfg(feature = "async")]
+impl<T, S: ffi::SubscriberStrongRef, H> futures_core::Stream for SampleReceiver<T, S, H> {
+    type Item = Sample<T, S, H>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.as_ref().get_ref().poll_take(cx, Self::take)
     }
 }
 
-impl<T: ?Sized, S: ffi::SubscriberStrongRef> Drop for SampleReceiver<T, S> {
+/// A stream of samples which ends when the `SampleReceiver` is stopped
+#[cfg(feature = "async")]
+impl<T, S: ffi::SubscriberStrongRef, H> futures_core::Stream for SampleReceiver<[T], S, H> {
+    type Item = Sample<[T], S, H>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.as_ref().get_ref().poll_take(cx, Self::take)
+    }
+}
+
+impl<T: ?Sized, S: ffi::SubscriberStrongRef, H> Drop for SampleReceiver<T, S, H> {
     fn drop(&mut self) {
         self.ffi_sub.as_ref().unset_condition_variable();
         self.ffi_sub.as_ref().unsubscribe();
     }
 }
 
+/// The last sequence number of each publisher to detect gaps in the taken samples
+///
+/// iceoryx does not notify a subscriber when a publisher disconnects. Since at most [`ffi::Publisher::MAX_PUBLISHERS`]
+/// publishers exist at the same time, the publisher whose last sample was taken the longest time ago is removed when
+/// there are more entries.
+#[derive(Default)]
+struct SequenceNumbers {
+    // the last sequence number and the number of taken samples at that time for each publisher
+    last: HashMap<u64, (u64, u64)>,
+    taken_samples: u64,
+}
+
+impl SequenceNumbers {
+    /// Stores the `sequence_number` of the publisher with `origin_id` and returns the number of samples lost since
+    /// its last sample
+    fn update(&mut self, origin_id: u64, sequence_number: u64) -> u64 {
+        self.taken_samples += 1;
+        let lost_samples = match self
+            .last
+            .insert(origin_id, (sequence_number, self.taken_samples))
+        {
+            Some((last_sequence_number, _)) if sequence_number > last_sequence_number => {
+                sequence_number - last_sequence_number - 1
+            }
+            // the publisher is new or delivered its history again after it was restarted or reconnected
+            _ => 0,
+        };
+
+        if self.last.len() > ffi::Publisher::MAX_PUBLISHERS {
+            let least_recent = self
+                .last
+                .iter()
+                .min_by_key(|(_, (_, taken_samples))| *taken_samples)
+                .map(|(origin_id, _)| *origin_id);
+            if let Some(origin_id) = least_recent {
+                self.last.remove(&origin_id);
+            }
+        }
+
+        lost_samples
+    }
+}
+
+/// Calls `try_take` until a sample is taken, the queue is empty or too many samples are held in parallel
+///
+/// Only samples which do not match the expected type are skipped since each of them is released and removed from the
+/// queue. Holding too many samples does not change until a sample is dropped and would drain the whole queue.
+pub(crate) fn take_skipping_mismatches<R>(
+    try_take: impl Fn() -> Result<Option<R>, IceoryxError>,
+) -> Option<R> {
+    loop {
+        match try_take() {
+            Ok(sample) => return sample,
+            Err(IceoryxError::UserHeaderSizeMismatch { .. })
+            | Err(IceoryxError::PayloadSizeMismatch { .. })
+            | Err(IceoryxError::AlignmentMismatch { .. })
+            | Err(IceoryxError::TypeMismatch { .. }) => continue,
+            Err(_) => return None,
+        }
+    }
+}
+
 /// The state of the [`SampleReceiver`]
 pub enum SampleReceiverWaitState {
     /// Samples are available and can be taken from the queue by [`SampleReceiver::take`]
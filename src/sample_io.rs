@@ -0,0 +1,230 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::marker::ShmSend;
+use crate::user_header::NoUserHeader;
+use crate::Sample;
+use crate::SampleMut;
+
+#[cfg(feature = "bytes")]
+use bytes::{buf::UninitSlice, Buf, BufMut};
+
+use std::io;
+use std::mem::MaybeUninit;
+
+/// A safe writer for a loaned `[u8]` sample
+///
+/// The writer tracks how many bytes are written into the uninitialized sample and implements `std::io::Write`
+/// and, with the `bytes` feature, `bytes::BufMut`. Writing beyond the capacity of the sample is not possible,
+/// i.e. `write` returns 0 and `write_all` fails with `std::io::ErrorKind::WriteZero` while the `BufMut` methods
+/// panic, like for any other `BufMut` without remaining capacity.
+///
+/// The payload size of a chunk is fixed when it is loaned. When the writer is converted with
+/// [`into_sample`](Self::into_sample), the bytes which were not written are set to 0. The capacity should
+/// therefore match the size of the message or the message must encode its own length.
+///
+/// # Example
+/// ```
+/// # use iceoryx_rs::Runtime;
+/// use iceoryx_rs::{PublisherBuilder, SampleReader, SubscriberBuilder};
+/// # use ffi::RouDiEnvironment;
+///
+/// use std::io::{Read, Write};
+///
+/// # use anyhow::{anyhow, Result};
+/// # fn main() -> Result<()> {
+/// # let _roudi = RouDiEnvironment::new();
+/// #
+/// # Runtime::init("sample_io");
+/// let publisher = PublisherBuilder::<[u8]>::new("all", "glory", "hypnotoad").create()?;
+/// let (subscriber, sample_receive_token) =
+///     SubscriberBuilder::<[u8]>::new("all", "glory", "hypnotoad").create()?;
+/// let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+///
+/// let mut writer = publisher.loan_writer(26)?;
+/// write!(writer, "All glory to the {}", "hypnotoad")?;
+/// publisher.publish(writer.into_sample());
+///
+/// let sample = sample_receiver.take().ok_or_else(|| anyhow!("No sample received"))?;
+/// let mut greeting = String::new();
+/// SampleReader::new(sample).read_to_string(&mut greeting)?;
+/// assert_eq!(greeting, "All glory to the hypnotoad");
+/// # Ok(())
+/// # }
+/// ```
+pub struct SampleWriter<'a, H: ShmSend = NoUserHeader> {
+    sample: SampleMut<'a, [MaybeUninit<u8>], H>,
+    len: usize,
+}
+
+impl<'a, H: ShmSend> SampleWriter<'a, H> {
+    /// Creates a writer which starts at the beginning of the uninitialized `sample`
+    pub fn new(sample: SampleMut<'a, [MaybeUninit<u8>], H>) -> Self {
+        Self { sample, len: 0 }
+    }
+
+    /// The size of the payload of the sample
+    pub fn capacity(&self) -> usize {
+        self.sample.len()
+    }
+
+    /// The number of bytes which were written
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether no bytes were written
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of bytes which can still be written
+    pub fn remaining(&self) -> usize {
+        self.capacity() - self.len
+    }
+
+    /// The bytes which were written
+    pub fn written(&self) -> &[u8] {
+        // SAFETY: the first `len` bytes are initialized and `MaybeUninit<u8>` has the same layout as `u8`
+        unsafe { std::slice::from_raw_parts(self.sample.as_ptr().cast::<u8>(), self.len) }
+    }
+
+    /// Get a mutable reference to the user header
+    pub fn user_header_mut(&mut self) -> &mut H {
+        self.sample.user_header_mut()
+    }
+
+    /// Converts into an initialized sample which can be published
+    ///
+    /// The bytes which were not written are set to 0.
+    pub fn into_sample(self) -> SampleMut<'a, [u8], H> {
+        let len = self.len;
+        let mut sample = self.sample;
+        sample[len..].fill(MaybeUninit::new(0));
+        // SAFETY: the first `len` bytes were written and the remaining ones were just set to 0
+        unsafe { sample.assume_init() }
+    }
+}
+
+impl<'a, H: ShmSend> io::Write for SampleWriter<'a, H> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let count = buf.len().min(self.remaining());
+        let len = self.len;
+        // SAFETY: the destination has at least `count` bytes and cannot overlap with `buf` which is borrowed
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                buf.as_ptr(),
+                self.sample[len..].as_mut_ptr().cast::<u8>(),
+                count,
+            );
+        }
+        self.len += count;
+        Ok(count)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// SAFETY: `advance_mut` only marks bytes as written which are within the capacity of the sample
+#[cfg(feature = "bytes")]
+unsafe impl<'a, H: ShmSend> BufMut for SampleWriter<'a, H> {
+    fn remaining_mut(&self) -> usize {
+        self.remaining()
+    }
+
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.remaining(),
+            "Advanced by {} bytes but only {} bytes are remaining",
+            cnt,
+            self.remaining()
+        );
+        self.len += cnt;
+    }
+
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        let len = self.len;
+        UninitSlice::uninit(&mut self.sample[len..])
+    }
+}
+
+/// A reader for a received `[u8]` sample
+///
+/// The reader tracks the position in the payload and implements `std::io::Read` and `std::io::BufRead` and,
+/// with the `bytes` feature, `bytes::Buf`. See [`SampleWriter`] for an example.
+pub struct SampleReader<S: ffi::SubscriberStrongRef, H = NoUserHeader> {
+    sample: Sample<[u8], S, H>,
+    position: usize,
+}
+
+impl<S: ffi::SubscriberStrongRef, H> SampleReader<S, H> {
+    /// Creates a reader which starts at the beginning of the payload of `sample`
+    pub fn new(sample: Sample<[u8], S, H>) -> Self {
+        Self {
+            sample,
+            position: 0,
+        }
+    }
+
+    /// The number of bytes which were read
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The bytes which were not yet read
+    pub fn unread(&self) -> &[u8] {
+        &self.sample[self.position..]
+    }
+
+    /// Get a reference to the user header
+    pub fn user_header(&self) -> &H {
+        self.sample.user_header()
+    }
+
+    /// Converts back into the sample
+    pub fn into_sample(self) -> Sample<[u8], S, H> {
+        self.sample
+    }
+}
+
+impl<S: ffi::SubscriberStrongRef, H> io::Read for SampleReader<S, H> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = io::Read::read(&mut self.unread(), buf)?;
+        self.position += count;
+        Ok(count)
+    }
+}
+
+impl<S: ffi::SubscriberStrongRef, H> io::BufRead for SampleReader<S, H> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.unread())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.position = (self.position + amt).min(self.sample.len());
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<S: ffi::SubscriberStrongRef, H> Buf for SampleReader<S, H> {
+    fn remaining(&self) -> usize {
+        self.sample.len() - self.position
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.unread()
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.remaining(),
+            "Advanced by {} bytes but only {} bytes are remaining",
+            cnt,
+            self.remaining()
+        );
+        self.position += cnt;
+    }
+}
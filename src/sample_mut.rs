@@ -4,17 +4,19 @@
 
 use super::{Publisher, RawSampleMut};
 use crate::marker::ShmSend;
+use crate::type_identity;
+use crate::user_header::{self, NoUserHeader};
 
 use std::mem::MaybeUninit;
 use std::ops::{Deref, DerefMut};
 
 /// A mutable sample owned by a single publisher
-pub struct SampleMut<'a, T: ShmSend + ?Sized> {
+pub struct SampleMut<'a, T: ShmSend + ?Sized, H: ShmSend = NoUserHeader> {
     data: RawSampleMut<T>,
-    publisher: &'a Publisher<T>,
+    publisher: &'a Publisher<T, H>,
 }
 
-impl<'a, T: ShmSend + ?Sized> Deref for SampleMut<'a, T> {
+impl<'a, T: ShmSend + ?Sized, H: ShmSend> Deref for SampleMut<'a, T, H> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -23,25 +25,57 @@ impl<'a, T: ShmSend + ?Sized> Deref for SampleMut<'a, T> {
     }
 }
 
-impl<'a, T: ShmSend + ?Sized> DerefMut for SampleMut<'a, T> {
+impl<'a, T: ShmSend + ?Sized, H: ShmSend> DerefMut for SampleMut<'a, T, H> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         // SAFETY: `as_payload_mut_ptr` returns a non-null ptr
         unsafe { &mut *self.data.as_payload_mut_ptr() }
     }
 }
 
-impl<'a, T: ShmSend + ?Sized> Drop for SampleMut<'a, T> {
+impl<'a, T: ShmSend + ?Sized, H: ShmSend> Drop for SampleMut<'a, T, H> {
     fn drop(&mut self) {
         self.publisher.release_raw(self.data);
     }
 }
 
-impl<'a, T: ShmSend + ?Sized> SampleMut<'a, T> {
-    pub(super) fn new(data: RawSampleMut<T>, publisher: &'a Publisher<T>) -> Self {
-        Self { data, publisher }
+impl<'a, T: ShmSend + ?Sized, H: ShmSend + Default> SampleMut<'a, T, H> {
+    pub(super) fn new_with_default_user_header(
+        data: RawSampleMut<T>,
+        publisher: &'a Publisher<T, H>,
+    ) -> Self {
+        let sample = Self { data, publisher };
+        // SAFETY: the ptr is valid and aligned since the user header was allocated together with the payload
+        unsafe {
+            if let Some(type_identity) = publisher.type_identity() {
+                type_identity::write_type_identity(sample.data.chunk_header(), type_identity);
+            }
+            sample.user_header_ptr().write(H::default());
+        }
+        sample
+    }
+}
+
+impl<'a, T: ShmSend + ?Sized, H: ShmSend> SampleMut<'a, T, H> {
+    /// Get a reference to the user header
+    pub fn user_header(&self) -> &H {
+        // SAFETY: the ptr is valid and aligned since the user header was allocated together with the payload
+        unsafe { &*self.user_header_ptr() }
     }
 
-    fn into_raw_parts(self) -> (RawSampleMut<T>, &'a Publisher<T>) {
+    /// Get a mutable reference to the user header
+    pub fn user_header_mut(&mut self) -> &mut H {
+        // SAFETY: the ptr is valid and aligned since the user header was allocated together with the payload
+        unsafe { &mut *self.user_header_ptr() }
+    }
+
+    fn user_header_ptr(&self) -> *mut H {
+        user_header::user_header_ptr::<H>(
+            self.data.chunk_header(),
+            self.publisher.type_identity().is_some(),
+        )
+    }
+
+    fn into_raw_parts(self) -> (RawSampleMut<T>, &'a Publisher<T, H>) {
         let sample = self.data;
         let publisher = self.publisher;
         std::mem::forget(self); // forget `self` to not call drop
@@ -61,51 +95,53 @@ impl<'a, T: ShmSend + ?Sized> SampleMut<'a, T> {
     }
 }
 
-impl<'a, T: ShmSend> SampleMut<'a, MaybeUninit<T>> {
+impl<'a, T: ShmSend, H: ShmSend> SampleMut<'a, MaybeUninit<T>, H> {
     /// Extracts the value of `MaybeUninit<T>` container and labels the sample as initialized
     ///
     /// # Safety
     ///
     /// The caller must ensure that `MaybeUninit<T>` really is initialized. Calling this when
     /// the content is not fully initialized causes immediate undefined behavior.
-    pub unsafe fn assume_init(self) -> SampleMut<'a, T> {
+    pub unsafe fn assume_init(self) -> SampleMut<'a, T, H> {
         let (data, publisher) = self.into_raw_parts();
 
         // the transmute is not nice but safe since MaybeUninit has the same layout as the inner type
         let data = std::mem::transmute::<RawSampleMut<MaybeUninit<T>>, RawSampleMut<T>>(data);
-        let publisher = std::mem::transmute::<&Publisher<MaybeUninit<T>>, &Publisher<T>>(publisher);
+        let publisher =
+            std::mem::transmute::<&Publisher<MaybeUninit<T>, H>, &Publisher<T, H>>(publisher);
 
         SampleMut { data, publisher }
     }
 }
 
-impl<'a, T: ShmSend> SampleMut<'a, [MaybeUninit<T>]> {
+impl<'a, T: ShmSend, H: ShmSend> SampleMut<'a, [MaybeUninit<T>], H> {
     /// Extracts the value of `MaybeUninit<T>` container and labels the sample as initialized
     ///
     /// # Safety
     ///
     /// The caller must ensure that `MaybeUninit<T>` really is initialized. Calling this when
     /// the content is not fully initialized causes immediate undefined behavior.
-    pub unsafe fn assume_init(self) -> SampleMut<'a, [T]> {
+    pub unsafe fn assume_init(self) -> SampleMut<'a, [T], H> {
         let (data, publisher) = self.into_raw_parts();
 
         // the transmute is not nice but safe since MaybeUninit has the same layout as the inner type
         let data = std::mem::transmute::<RawSampleMut<[MaybeUninit<T>]>, RawSampleMut<[T]>>(data);
         let publisher =
-            std::mem::transmute::<&Publisher<[MaybeUninit<T>]>, &Publisher<[T]>>(publisher);
+            std::mem::transmute::<&Publisher<[MaybeUninit<T>], H>, &Publisher<[T], H>>(publisher);
 
         SampleMut { data, publisher }
     }
 }
 
-impl<'a> SampleMut<'a, [MaybeUninit<u8>]> {
+impl<'a, H: ShmSend> SampleMut<'a, [MaybeUninit<u8>], H> {
     /// Get a mutable slice to the elements
     ///
     /// # Safety
     ///
     /// It is safe to write to the slice but reading is undefined behaviour.
     /// The main purpose of this method is to be used in combination with the `BufMut` trait of the
-    /// [bytes](https://crates.io/crates/bytes) crate.
+    /// [bytes](https://crates.io/crates/bytes) crate. Prefer the [`SampleWriter`](crate::SampleWriter), which
+    /// implements `std::io::Write` and, with the `bytes` feature, `BufMut` without `unsafe`.
     pub unsafe fn slice_assume_init_mut(&mut self) -> &mut [u8] {
         // TODO check if `MaybeUninit::slice_assume_init_mut` can be used once it is stabilized;
         // it might not be possible since current documentation labels the usage as undefined behavior
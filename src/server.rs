@@ -0,0 +1,272 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use super::{Request, ResponseMut};
+use crate::marker::ShmSend;
+use crate::node::{self, NodeResource};
+use crate::runtime::RuntimeResource;
+use crate::sample::take_skipping_mismatches;
+use crate::service_name;
+use crate::ConsumerTooSlowPolicy;
+use crate::IceoryxError;
+use crate::QueueFullPolicy;
+use crate::ServiceName;
+
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+
+/// Create a server with custom options
+///
+/// # Example
+/// ```
+/// # use iceoryx_rs::Runtime;
+/// use iceoryx_rs::ServerBuilder;
+/// # use ffi::RouDiEnvironment;
+/// #
+/// # use anyhow::{anyhow, Result};
+/// # fn main() -> Result<()> {
+/// # let _roudi = RouDiEnvironment::new();
+/// #
+/// # Runtime::init("basic_request_response");
+///
+/// let server = ServerBuilder::<u64, u64>::new("all", "glory", "hypnotoad")
+///         .request_queue_capacity(10)
+///         .create()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ServerBuilder<'a, Req: ShmSend, Res: ShmSend> {
+    service: &'a str,
+    instance: &'a str,
+    event: &'a str,
+    options: ffi::ServerOptions,
+    node: NodeResource,
+    phantom: PhantomData<(Req, Res)>,
+}
+
+impl<'a, Req: ShmSend, Res: ShmSend> ServerBuilder<'a, Req, Res> {
+    /// Creates a new `ServerBuilder`
+    ///
+    /// The parameter `service`, `instance` and `event` are used to specify the name of the service. They are
+    /// validated like a [`ServiceName`] when the server is created.
+    pub fn new(service: &'a str, instance: &'a str, event: &'a str) -> Self {
+        Self {
+            service,
+            instance,
+            event,
+            options: ffi::ServerOptions::default(),
+            node: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates a new `ServerBuilder` for the service with the `service_name`
+    pub fn from_service_name(service_name: &'a ServiceName) -> Self {
+        Self::new(
+            service_name.service(),
+            service_name.instance(),
+            service_name.event(),
+        )
+    }
+
+    /// The size of the queue where requests are stored before they are passed to the user
+    pub fn request_queue_capacity(mut self, size: u64) -> Self {
+        self.options.request_queue_capacity = size;
+        self
+    }
+
+    /// The name of the node where the server should belong to
+    ///
+    /// The node name is shown in the port introspection. Use [`Node::server_builder`](crate::Node::server_builder)
+    /// to create a server which belongs to a node that is registered at `RouDi`.
+    ///
+    /// The name is validated like the name of a [`Node`](crate::Node) when the server is created. It is empty by
+    /// default, i.e. the server does not belong to a node.
+    pub fn node_name(mut self, name: &str) -> Self {
+        self.options.node_name = name.to_string();
+        self
+    }
+
+    pub(crate) fn node(mut self, name: &str, node: NodeResource) -> Self {
+        self.options.node_name = name.to_string();
+        self.node = node;
+        self
+    }
+
+    /// Set the behavior of a full request queue
+    ///
+    /// By default the client discards the oldest requests to make room for new requests.
+    pub fn request_queue_full_policy(mut self, queue_full_policy: QueueFullPolicy) -> Self {
+        self.options.request_queue_full_policy = queue_full_policy;
+        self
+    }
+
+    /// Sets the policy on how to proceed when the client is too slow in processing the responses
+    ///
+    /// By default the oldest responses are removed from the client queue and the latest ones added.
+    pub fn client_too_slow_policy(mut self, client_too_slow_policy: ConsumerTooSlowPolicy) -> Self {
+        self.options.client_too_slow_policy = client_too_slow_policy;
+        self
+    }
+
+    /// Create a new [`Server`]
+    ///
+    /// The server is in the offer state when this method returns. If there are clients waiting
+    /// to be connected, they will be connected.
+    ///
+    /// # Panics
+    ///
+    /// [`Runtime::init`](crate::Runtime::init) must have been called otherwise this method will panic.
+    pub fn create(mut self) -> Result<Server<Req, Res>, IceoryxError> {
+        self.options.offer_on_create = true;
+        self.create_server()
+    }
+
+    /// Create a new [`Server`] which does not offer its service
+    ///
+    /// No clients will be connected until [`Server::offer`] is called.
+    ///
+    /// # Panics
+    ///
+    /// [`Runtime::init`](crate::Runtime::init) must have been called otherwise this method will panic.
+    pub fn create_without_offer(mut self) -> Result<Server<Req, Res>, IceoryxError> {
+        self.options.offer_on_create = false;
+        self.create_server()
+    }
+
+    fn create_server(self) -> Result<Server<Req, Res>, IceoryxError> {
+        service_name::validate(self.service, self.instance, self.event)?;
+        node::validate_port_node_name(&self.options.node_name)?;
+        let ffi_server = ffi::Server::new(self.service, self.instance, self.event, &self.options)
+            .ok_or(IceoryxError::ServerCreationFailed)?;
+
+        Ok(Server {
+            ffi_server,
+            _node: self.node,
+            _runtime_resource: RuntimeResource::new(),
+            phantom: PhantomData,
+        })
+    }
+}
+
+/// A server which receives requests of type `Req` from clients and answers with responses of type `Res`
+pub struct Server<Req: ShmSend, Res: ShmSend> {
+    ffi_server: Box<ffi::Server>,
+    _node: NodeResource,
+    _runtime_resource: RuntimeResource,
+    phantom: PhantomData<(Req, Res)>,
+}
+
+impl<Req: ShmSend, Res: ShmSend> Server<Req, Res> {
+    /// Offers the service of the server
+    ///
+    /// It might take up to 50 milliseconds until `RouDi` runs its discovery loop and the server
+    /// does actually offer the service for clients.
+    pub fn offer(&self) {
+        self.ffi_server.offer();
+    }
+
+    /// Stops offering the service
+    ///
+    /// All connected clients will be disconnected. It might take up to 50 milliseconds until `RouDi` runs its
+    /// discovery loop and this takes effect.
+    pub fn stop_offer(&self) {
+        self.ffi_server.stop_offer();
+    }
+
+    /// Check whether the service is offered
+    pub fn is_offered(&self) -> bool {
+        self.ffi_server.is_offered()
+    }
+
+    /// Checks whether there are clients connected to the server
+    pub fn has_clients(&self) -> bool {
+        self.ffi_server.has_clients()
+    }
+
+    /// Checks whether there are requests in the request queue
+    pub fn has_requests(&self) -> bool {
+        self.ffi_server.has_requests()
+    }
+
+    /// Takes a request from the request queue
+    ///
+    /// If the request queue is empty or too many requests are held in parallel, `None` will be returned. Requests
+    /// which do not match `Req` are discarded and the next request of the queue is taken instead. Use
+    /// [`try_take_request`](Self::try_take_request) to get the reason why a request could not be taken.
+    pub fn take_request(&self) -> Option<Request<'_, Req>> {
+        take_skipping_mismatches(|| self.try_take_request())
+    }
+
+    /// Takes a request from the request queue and reports why a request could not be taken
+    ///
+    /// Returns `Ok(None)` if the request queue is empty. Requests which do not match are released and one of the
+    /// following errors is returned
+    /// - [`IceoryxError::TooManySamplesHoldInParallel`] if too many requests are held at the same time
+    /// - [`IceoryxError::PayloadSizeMismatch`] if the payload size does not match the size of `Req`
+    /// - [`IceoryxError::AlignmentMismatch`] if the payload alignment is smaller than the alignment of `Req`
+    pub fn try_take_request(&self) -> Result<Option<Request<'_, Req>>, IceoryxError> {
+        Ok(self
+            .ffi_server
+            .try_take_request::<Req>()?
+            .map(|data| Request::new(data, &self.ffi_server)))
+    }
+
+    /// Clears the request queue and release all the requests from the queue
+    pub fn clear_requests(&self) {
+        self.ffi_server.clear_requests();
+    }
+
+    /// Sends a response to the client which sent the corresponding request
+    ///
+    /// The response is consumed, even if sending fails.
+    pub fn send(&self, response: ResponseMut<Res>) -> Result<(), IceoryxError> {
+        let (data, ffi_server) = response.into_raw_parts();
+        ffi_server
+            .send_response(data)
+            .map_err(IceoryxError::SendResponseFailed)
+    }
+
+    /// Loan an uninitialized response for a request
+    ///
+    /// Same as [`loan_response`](Self::loan_response) but with uninitialized data.
+    pub fn loan_uninit_response(
+        &self,
+        request: &Request<Req>,
+    ) -> Result<ResponseMut<'_, MaybeUninit<Res>>, IceoryxError> {
+        let data = self
+            .ffi_server
+            .try_allocate_response::<Res, Req>(request.as_raw())
+            .map_err(|e| {
+                IceoryxError::from_allocation_error(
+                    e,
+                    std::mem::size_of::<Res>(),
+                    std::mem::align_of::<Res>(),
+                )
+            })?;
+
+        Ok(ResponseMut::new(data, &self.ffi_server))
+    }
+}
+
+impl<Req: ShmSend, Res: ShmSend + Default> Server<Req, Res> {
+    /// Loan a response for a request
+    ///
+    /// The response gets the sequence ID of the request. The loaned response is initialized with the
+    /// default value of the type. If this is not desired or the type does not implement the `Default`
+    /// trait, [`loan_uninit_response`](Self::loan_uninit_response) can be used.
+    ///
+    /// The errors are the same as for [`Publisher::loan`](crate::Publisher::loan).
+    pub fn loan_response(
+        &self,
+        request: &Request<Req>,
+    ) -> Result<ResponseMut<'_, Res>, IceoryxError> {
+        let mut response = self.loan_uninit_response(request)?;
+
+        unsafe {
+            response.as_mut_ptr().write(Res::default());
+            Ok(response.assume_init())
+        }
+    }
+}
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::introspection::ServiceDescription;
+use crate::mt;
+use crate::service_name;
+use crate::IceoryxError;
+use crate::MessagingPattern;
+use crate::SampleReceiverWaitState;
+use crate::SubscriberBuilder;
+
+use std::time::Duration;
+
+/// Lookup of the services which are currently offered
+///
+/// # Example
+/// ```
+/// # use iceoryx_rs::Runtime;
+/// use iceoryx_rs::PublisherBuilder;
+/// use iceoryx_rs::ServiceDiscovery;
+/// # use ffi::RouDiEnvironment;
+/// #
+/// # use anyhow::{anyhow, Result};
+/// # fn main() -> Result<()> {
+/// # let _roudi = RouDiEnvironment::new();
+/// #
+/// # Runtime::init("service_discovery");
+///
+/// let publisher = PublisherBuilder::<u32>::new("all", "glory", "hypnotoad").create()?;
+///
+/// let service_discovery = ServiceDiscovery::new()?;
+/// let services = service_discovery.find_service(Some("all"), None, None)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ServiceDiscovery {
+    ffi_service_discovery: Box<ffi::ServiceDiscovery>,
+    // is notified by `RouDi` when the service registry changes
+    registry_change_receiver: mt::SampleReceiver<[u8]>,
+    _registry_change_subscriber: mt::Subscriber<[u8]>,
+}
+
+impl ServiceDiscovery {
+    /// Creates a new `ServiceDiscovery`
+    ///
+    /// # Panics
+    ///
+    /// [`Runtime::init`](crate::Runtime::init) must have been called otherwise this method will panic.
+    pub fn new() -> Result<Self, IceoryxError> {
+        let (registry_change_subscriber, sample_receive_token) =
+            SubscriberBuilder::<[u8]>::new("ServiceDiscovery", "RouDi_ID", "ServiceRegistry")
+                .queue_capacity(1)
+                .create_mt()?;
+        let registry_change_receiver =
+            registry_change_subscriber.get_sample_receiver(sample_receive_token);
+
+        Ok(Self {
+            ffi_service_discovery: ffi::ServiceDiscovery::new(),
+            registry_change_receiver,
+            _registry_change_subscriber: registry_change_subscriber,
+        })
+    }
+
+    /// Searches all publisher services which match the provided `service`, `instance` and `event`
+    ///
+    /// `None` is a wildcard and matches all services, instances or events. The other parts are validated like a
+    /// [`ServiceName`](crate::ServiceName) and [`IceoryxError::InvalidServiceName`] is returned if one is invalid.
+    pub fn find_service(
+        &self,
+        service: Option<&str>,
+        instance: Option<&str>,
+        event: Option<&str>,
+    ) -> Result<Vec<ServiceDescription>, IceoryxError> {
+        self.find_service_with_pattern(service, instance, event, MessagingPattern::PubSub)
+    }
+
+    /// Searches all services of the messaging `pattern` which match the provided `service`, `instance` and `event`
+    ///
+    /// `None` is a wildcard and matches all services, instances or events. With [`MessagingPattern::ReqRes`]
+    /// the services of servers are searched. The errors are the same as for [`find_service`](Self::find_service).
+    pub fn find_service_with_pattern(
+        &self,
+        service: Option<&str>,
+        instance: Option<&str>,
+        event: Option<&str>,
+        pattern: MessagingPattern,
+    ) -> Result<Vec<ServiceDescription>, IceoryxError> {
+        service_name::validate_pattern(service, instance, event)?;
+        Ok(self
+            .ffi_service_discovery
+            .find_service(service, instance, event, pattern))
+    }
+
+    /// Checks whether the set of offered services changed since the last call to this method
+    /// or to [`wait_for_change`](Self::wait_for_change)
+    pub fn has_changed(&self) -> bool {
+        let has_changed = self.registry_change_receiver.has_data();
+        self.registry_change_receiver.clear();
+        has_changed
+    }
+
+    /// Blocking wait for changes of the set of offered services
+    ///
+    /// Returns `true` if the services changed since the last call to this method or to
+    /// [`has_changed`](Self::has_changed) and `false` if the timeout elapsed.
+    pub fn wait_for_change(&self, timeout: Duration) -> bool {
+        match self.registry_change_receiver.wait_for_samples(timeout) {
+            SampleReceiverWaitState::SamplesAvailable => {
+                self.registry_change_receiver.clear();
+                true
+            }
+            _ => false,
+        }
+    }
+}
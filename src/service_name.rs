@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::introspection::ServiceDescription;
+use crate::IceoryxError;
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// The separator of the service, instance and event in the string representation of a [`ServiceName`]
+const SEPARATOR: char = '/';
+
+/// The representation of a wildcard part of a search pattern in error messages
+const WILDCARD: &str = "*";
+
+/// The validated name of a service, consisting of the `service`, `instance` and `event` part
+///
+/// Each part must not be empty, must not be longer than [`ServiceName::MAX_PART_LENGTH`] bytes and must not
+/// contain control characters like `NUL` or the separator `/`. This ensures that two different names never
+/// end up on the same topic, which could happen if iceoryx truncated long names.
+///
+/// The string representation is `service/instance/event`.
+///
+/// # Example
+/// ```
+/// use iceoryx_rs::ServiceName;
+///
+/// # use anyhow::Result;
+/// # fn main() -> Result<()> {
+/// let service_name = ServiceName::new("all", "glory", "hypnotoad")?;
+/// assert_eq!(service_name, "all/glory/hypnotoad".parse::<ServiceName>()?);
+/// assert_eq!(service_name.to_string(), "all/glory/hypnotoad");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct ServiceName {
+    service: String,
+    instance: String,
+    event: String,
+}
+
+impl ServiceName {
+    /// The maximum length of each part of the name in bytes; this is the capacity of the iceoryx `IdString_t`
+    pub const MAX_PART_LENGTH: usize = 100;
+
+    /// Creates a `ServiceName` from its `service`, `instance` and `event` part
+    ///
+    /// Returns [`IceoryxError::InvalidServiceName`] if one of the parts is invalid.
+    pub fn new(service: &str, instance: &str, event: &str) -> Result<Self, IceoryxError> {
+        validate(service, instance, event)?;
+        Ok(Self {
+            service: service.to_string(),
+            instance: instance.to_string(),
+            event: event.to_string(),
+        })
+    }
+
+    /// The service part of the name
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// The instance part of the name
+    pub fn instance(&self) -> &str {
+        &self.instance
+    }
+
+    /// The event part of the name
+    pub fn event(&self) -> &str {
+        &self.event
+    }
+}
+
+/// Validates the parts of a service name; this is also used by the builders which take the parts as `&str`
+pub(crate) fn validate(service: &str, instance: &str, event: &str) -> Result<(), IceoryxError> {
+    validate_pattern(Some(service), Some(instance), Some(event))
+}
+
+/// Validates the parts of a search pattern of the service discovery; `None` is a wildcard and always valid
+pub(crate) fn validate_pattern(
+    service: Option<&str>,
+    instance: Option<&str>,
+    event: Option<&str>,
+) -> Result<(), IceoryxError> {
+    let invalid_part = |part: Option<&str>, part_name: &str| {
+        let part = part?;
+        let reason = if part.is_empty() {
+            "is empty"
+        } else if part.len() > ServiceName::MAX_PART_LENGTH {
+            "exceeds the maximum length"
+        } else if part.contains(SEPARATOR) {
+            "contains the separator '/'"
+        } else if part.chars().any(char::is_control) {
+            "contains control characters"
+        } else {
+            return None;
+        };
+        Some(format!("{} {}", part_name, reason))
+    };
+
+    match invalid_part(service, "service")
+        .or_else(|| invalid_part(instance, "instance"))
+        .or_else(|| invalid_part(event, "event"))
+    {
+        None => Ok(()),
+        Some(reason) => Err(IceoryxError::InvalidServiceName {
+            name: format!(
+                "{}/{}/{}",
+                service.unwrap_or(WILDCARD),
+                instance.unwrap_or(WILDCARD),
+                event.unwrap_or(WILDCARD)
+            ),
+            reason,
+        }),
+    }
+}
+
+impl fmt::Display for ServiceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.service, self.instance, self.event)
+    }
+}
+
+impl FromStr for ServiceName {
+    type Err = IceoryxError;
+
+    /// Parses a name in the form `service/instance/event`
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let mut parts = name.split(SEPARATOR);
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(service), Some(instance), Some(event), None) => {
+                Self::new(service, instance, event)
+            }
+            _ => Err(IceoryxError::InvalidServiceName {
+                name: name.to_string(),
+                reason: "does not consist of service, instance and event separated by '/'"
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<&str> for ServiceName {
+    type Error = IceoryxError;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
+
+impl TryFrom<&ServiceDescription> for ServiceName {
+    type Error = IceoryxError;
+
+    fn try_from(service_description: &ServiceDescription) -> Result<Self, Self::Error> {
+        Self::new(
+            &service_description.service_id,
+            &service_description.instance_id,
+            &service_description.event_id,
+        )
+    }
+}
+
+impl From<&ServiceName> for ServiceDescription {
+    fn from(service_name: &ServiceName) -> Self {
+        ServiceDescription {
+            service_id: service_name.service.clone(),
+            instance_id: service_name.instance.clone(),
+            event_id: service_name.event.clone(),
+        }
+    }
+}
+
+impl PartialEq<ServiceDescription> for ServiceName {
+    fn eq(&self, other: &ServiceDescription) -> bool {
+        self.service == other.service_id
+            && self.instance == other.instance_id
+            && self.event == other.event_id
+    }
+}
+
+impl PartialEq<ServiceName> for ServiceDescription {
+    fn eq(&self, other: &ServiceName) -> bool {
+        other == self
+    }
+}
@@ -4,12 +4,21 @@
 
 use super::sample::SampleReceiver;
 use super::{mt, st};
+use crate::discovery;
+use crate::marker::ShmSend;
+use crate::node::{self, NodeResource};
+use crate::runtime::RuntimeResource;
+use crate::service_name;
+use crate::user_header::{self, NoUserHeader};
 use crate::IceoryxError;
 use crate::QueueFullPolicy;
 use crate::RawSample;
+use crate::ServiceName;
 use crate::SubscribeState;
+use crate::TypeIdentity;
 
 use std::marker::PhantomData;
+use std::time::Duration;
 
 /// Create a subscriber with custom options
 ///
@@ -33,26 +42,63 @@ use std::marker::PhantomData;
 /// # Ok(())
 /// # }
 /// ```
-pub struct SubscriberBuilder<'a, T: ?Sized> {
+pub struct SubscriberBuilder<'a, T: ?Sized, H = NoUserHeader> {
     service: &'a str,
     instance: &'a str,
     event: &'a str,
     options: ffi::SubscriberOptions,
-    phantom: PhantomData<T>,
+    type_identity: Option<TypeIdentity>,
+    node: NodeResource,
+    phantom: PhantomData<(H, T)>,
 }
 
 impl<'a, T: ?Sized> SubscriberBuilder<'a, T> {
     /// Creates a new `SubscriberBuilder`
     ///
-    /// The parameter `service`, `instance` and `event` are used to specify the name of the service.
-    /// In the future this three strings will probably be fused to together in a single string separated
-    /// by slashes `/`.
+    /// The parameter `service`, `instance` and `event` are used to specify the name of the service. They are
+    /// validated like a [`ServiceName`] when the subscriber is created.
     pub fn new(service: &'a str, instance: &'a str, event: &'a str) -> Self {
         Self {
             service,
             instance,
             event,
             options: ffi::SubscriberOptions::default(),
+            type_identity: None,
+            node: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates a new `SubscriberBuilder` for the service with the `service_name`
+    pub fn from_service_name(service_name: &'a ServiceName) -> Self {
+        Self::new(
+            service_name.service(),
+            service_name.instance(),
+            service_name.event(),
+        )
+    }
+}
+
+impl<'a, T: ?Sized, H> SubscriberBuilder<'a, T, H> {
+    /// Expects a user defined header of type `H2` in front of the payload of each sample
+    ///
+    /// The header type must match the one of the publisher, see
+    /// [`PublisherBuilder::with_user_header`](crate::PublisherBuilder::with_user_header), and can be accessed with
+    /// [`Sample::user_header`](crate::Sample::user_header). The alignment of the user header must not exceed
+    /// 8 bytes, which is checked on `create`.
+    ///
+    /// Since iceoryx does not transfer the header type when connecting publisher and subscriber, the size
+    /// of the user header is checked for each sample. Samples with a mismatching user header are discarded and
+    /// counted by [`SampleReceiver::discarded_samples`].
+    /// A subscriber without user header receives the samples of publisher with and without user header.
+    pub fn with_user_header<H2: ShmSend>(self) -> SubscriberBuilder<'a, T, H2> {
+        SubscriberBuilder {
+            service: self.service,
+            instance: self.instance,
+            event: self.event,
+            options: self.options,
+            type_identity: self.type_identity,
+            node: self.node,
             phantom: PhantomData,
         }
     }
@@ -71,12 +117,23 @@ impl<'a, T: ?Sized> SubscriberBuilder<'a, T> {
 
     /// The name of the node where the subscriber should belong to
     ///
-    /// Setting the node name has currently no functionality but this might change in future.
+    /// The node name is shown in the port introspection. Use
+    /// [`Node::subscriber_builder`](crate::Node::subscriber_builder) to create a subscriber which belongs to a node
+    /// that is registered at `RouDi`.
+    ///
+    /// The name is validated like the name of a [`Node`](crate::Node) when the subscriber is created. It is empty by
+    /// default, i.e. the subscriber does not belong to a node.
     pub fn node_name(mut self, name: &str) -> Self {
         self.options.node_name = name.to_string();
         self
     }
 
+    pub(crate) fn node(mut self, name: &str, node: NodeResource) -> Self {
+        self.options.node_name = name.to_string();
+        self.node = node;
+        self
+    }
+
     /// Set the behavior of full receiver queue
     ///
     /// By default the publisher discards the oldest data to make room for new data. Optionally,
@@ -105,13 +162,19 @@ impl<'a, T: ?Sized> SubscriberBuilder<'a, T> {
     /// # Panics
     ///
     /// [`Runtime::init`](crate::Runtime::init) must have been called otherwise this method will panic.
-    pub fn create(mut self) -> Result<(st::Subscriber<T>, SampleReceiverToken), IceoryxError> {
+    pub fn create(mut self) -> Result<(st::Subscriber<T, H>, SampleReceiverToken), IceoryxError> {
+        user_header::check_user_header::<H>()?;
         self.options.subscribe_on_create = true;
+        service_name::validate(self.service, self.instance, self.event)?;
+        node::validate_port_node_name(&self.options.node_name)?;
         let ffi_sub = ffi::Subscriber::new(self.service, self.instance, self.event, &self.options)
             .ok_or(IceoryxError::SubscriberCreationFailed)?;
 
         let subscriber = st::Subscriber {
             ffi_sub: ffi::SubscriberRc::new(ffi_sub),
+            type_identity: self.type_identity,
+            _node: self.node,
+            _runtime_resource: RuntimeResource::new(),
             phantom: PhantomData,
         };
 
@@ -126,13 +189,21 @@ impl<'a, T: ?Sized> SubscriberBuilder<'a, T> {
     /// # Panics
     ///
     /// [`Runtime::init`](crate::Runtime::init) must have been called otherwise this method will panic.
-    pub fn create_mt(mut self) -> Result<(mt::Subscriber<T>, SampleReceiverToken), IceoryxError> {
+    pub fn create_mt(
+        mut self,
+    ) -> Result<(mt::Subscriber<T, H>, SampleReceiverToken), IceoryxError> {
+        user_header::check_user_header::<H>()?;
         self.options.subscribe_on_create = true;
+        service_name::validate(self.service, self.instance, self.event)?;
+        node::validate_port_node_name(&self.options.node_name)?;
         let ffi_sub = ffi::Subscriber::new(self.service, self.instance, self.event, &self.options)
             .ok_or(IceoryxError::SubscriberCreationFailed)?;
 
         let subscriber = mt::Subscriber {
             ffi_sub: ffi::SubscriberArc::new(ffi_sub),
+            type_identity: self.type_identity,
+            _node: self.node,
+            _runtime_resource: RuntimeResource::new(),
             phantom: PhantomData,
         };
 
@@ -147,32 +218,71 @@ impl<'a, T: ?Sized> SubscriberBuilder<'a, T> {
     /// # Panics
     ///
     /// [`Runtime::init`](crate::Runtime::init) must have been called otherwise this method will panic.
-    pub fn create_without_subscribe(mut self) -> Result<InactiveSubscriber<T>, IceoryxError> {
+    pub fn create_without_subscribe(mut self) -> Result<InactiveSubscriber<T, H>, IceoryxError> {
+        user_header::check_user_header::<H>()?;
         self.options.subscribe_on_create = false;
+        service_name::validate(self.service, self.instance, self.event)?;
+        node::validate_port_node_name(&self.options.node_name)?;
         let ffi_sub = ffi::Subscriber::new(self.service, self.instance, self.event, &self.options)
             .ok_or(IceoryxError::SubscriberCreationFailed)?;
 
         Ok(InactiveSubscriber {
             ffi_sub,
+            type_identity: self.type_identity,
+            _node: self.node,
+            _runtime_resource: RuntimeResource::new(),
             phantom: PhantomData,
         })
     }
 }
 
+impl<'a, T: ShmSend, H> SubscriberBuilder<'a, T, H> {
+    /// Checks the [`TypeIdentity`] of `T` against the one transferred with each sample
+    ///
+    /// The publisher must have been created with
+    /// [`PublisherBuilder::with_type_identity`](crate::PublisherBuilder::with_type_identity). Samples with a
+    /// different type identity or without type identity are rejected by
+    /// [`SampleReceiver::try_take`](crate::SampleReceiver::try_take) with [`IceoryxError::TypeMismatch`].
+    pub fn with_type_identity(mut self) -> Self {
+        self.type_identity = Some(TypeIdentity::of::<T>());
+        self
+    }
+}
+
+impl<'a, T: ShmSend, H> SubscriberBuilder<'a, [T], H> {
+    /// Checks the [`TypeIdentity`] of the element type `T` against the one transferred with each sample
+    ///
+    /// Have a look at the `with_type_identity` method for non-slice types for details.
+    pub fn with_type_identity(mut self) -> Self {
+        self.type_identity = Some(TypeIdentity::of::<T>());
+        self
+    }
+}
+
 pub struct SampleReceiverToken {}
 
 /// An inactive subscriber which is not subscribed and is not visible to any publisher
 ///
 /// This can be used for cases where the service is suspended and the publisher/subscriber need to be disconnected.
-pub struct InactiveSubscriber<T: ?Sized> {
+pub struct InactiveSubscriber<T: ?Sized, H = NoUserHeader> {
     ffi_sub: Box<ffi::Subscriber>,
-    phantom: PhantomData<T>,
+    type_identity: Option<TypeIdentity>,
+    _node: NodeResource,
+    _runtime_resource: RuntimeResource,
+    phantom: PhantomData<(H, T)>,
 }
 
-impl<T: ?Sized> InactiveSubscriber<T> {
-    fn from_ffi(ffi_sub: Box<ffi::Subscriber>) -> Self {
+impl<T: ?Sized, H> InactiveSubscriber<T, H> {
+    fn from_ffi(
+        ffi_sub: Box<ffi::Subscriber>,
+        type_identity: Option<TypeIdentity>,
+        node: NodeResource,
+    ) -> Self {
         Self {
             ffi_sub,
+            type_identity,
+            _node: node,
+            _runtime_resource: RuntimeResource::new(),
             phantom: PhantomData,
         }
     }
@@ -180,11 +290,11 @@ impl<T: ?Sized> InactiveSubscriber<T> {
     /// Subscribes to a publisher by consuming the `InactiveSubscriber` and creating a [`st::Subscriber`]
     ///
     /// It might take up to 50 milliseconds until `RouDi` runs its discovery loop and the subscriber
-    /// will be subscribed to the publisher.
-    pub fn subscribe(self) -> (st::Subscriber<T>, SampleReceiverToken) {
+    /// will be subscribed to the publisher. Use [`Subscriber::wait_until_subscribed`] to wait for this.
+    pub fn subscribe(self) -> (st::Subscriber<T, H>, SampleReceiverToken) {
         self.ffi_sub.subscribe();
         (
-            st::Subscriber::new_from_ffi(self.ffi_sub),
+            st::Subscriber::new_from_ffi(self.ffi_sub, self.type_identity, self._node),
             SampleReceiverToken {},
         )
     }
@@ -192,10 +302,11 @@ impl<T: ?Sized> InactiveSubscriber<T> {
     /// Subscribes to a publisher by consuming the `InactiveSubscriber` and creating a [`mt::Subscriber`]
     ///
     /// It might take up to 50 milliseconds until `RouDi` runs its discovery loop and the subscriber
-    pub fn subscribe_mt(self) -> (mt::Subscriber<T>, SampleReceiverToken) {
+    /// will be subscribed to the publisher. Use [`Subscriber::wait_until_subscribed`] to wait for this.
+    pub fn subscribe_mt(self) -> (mt::Subscriber<T, H>, SampleReceiverToken) {
         self.ffi_sub.subscribe();
         (
-            mt::Subscriber::new_from_ffi(self.ffi_sub),
+            mt::Subscriber::new_from_ffi(self.ffi_sub, self.type_identity, self._node),
             SampleReceiverToken {},
         )
     }
@@ -209,6 +320,19 @@ impl<T: ?Sized> InactiveSubscriber<T> {
         self.ffi_sub.subscription_state()
     }
 
+    /// Blocks until `RouDi` processed the unsubscribe request and the subscriber is disconnected from the publisher
+    ///
+    /// iceoryx does not notify about changes of the subscription state, therefore the state is checked after
+    /// each run of the discovery loop of `RouDi`, which processes the request.
+    ///
+    /// Returns [`IceoryxError::DiscoveryTimeout`] if the subscription state is not
+    /// [`SubscribeState::NotSubscribed`] within `timeout`.
+    pub fn wait_until_unsubscribed(&self, timeout: Duration) -> Result<(), IceoryxError> {
+        discovery::wait_for_discovery_loop(timeout, || {
+            self.subscription_state() == SubscribeState::NotSubscribed
+        })
+    }
+
     /// Releases a raw sample which will not be used anymore
     pub fn release_raw(&self, sample: RawSample<T>) {
         self.ffi_sub.as_ref().release(sample);
@@ -216,15 +340,25 @@ impl<T: ?Sized> InactiveSubscriber<T> {
 }
 
 /// A subscriber which is subscribed or requested to be subscribed to a publisher
-pub struct Subscriber<T: ?Sized, S: ffi::SubscriberStrongRef> {
+pub struct Subscriber<T: ?Sized, S: ffi::SubscriberStrongRef, H = NoUserHeader> {
     ffi_sub: S,
-    phantom: PhantomData<T>,
+    type_identity: Option<TypeIdentity>,
+    _node: NodeResource,
+    _runtime_resource: RuntimeResource,
+    phantom: PhantomData<(H, T)>,
 }
 
-impl<T: ?Sized, S: ffi::SubscriberStrongRef> Subscriber<T, S> {
-    fn new_from_ffi(ffi_sub: Box<ffi::Subscriber>) -> Self {
+impl<T: ?Sized, S: ffi::SubscriberStrongRef, H> Subscriber<T, S, H> {
+    fn new_from_ffi(
+        ffi_sub: Box<ffi::Subscriber>,
+        type_identity: Option<TypeIdentity>,
+        node: NodeResource,
+    ) -> Self {
         Subscriber {
             ffi_sub: S::new(ffi_sub),
+            type_identity,
+            _node: node,
+            _runtime_resource: RuntimeResource::new(),
             phantom: PhantomData,
         }
     }
@@ -238,9 +372,22 @@ impl<T: ?Sized, S: ffi::SubscriberStrongRef> Subscriber<T, S> {
         self.ffi_sub.as_ref().subscription_state()
     }
 
+    /// Blocks until `RouDi` processed the subscribe request and the subscriber is connected to the matching publishers
+    ///
+    /// iceoryx does not notify about changes of the subscription state, therefore the state is checked after
+    /// each run of the discovery loop of `RouDi`, which processes the request.
+    ///
+    /// Returns [`IceoryxError::DiscoveryTimeout`] if the subscription state is not [`SubscribeState::Subscribed`]
+    /// within `timeout`.
+    pub fn wait_until_subscribed(&self, timeout: Duration) -> Result<(), IceoryxError> {
+        discovery::wait_for_discovery_loop(timeout, || {
+            self.subscription_state() == SubscribeState::Subscribed
+        })
+    }
+
     /// Obtain the [`SampleReceiver`] to take samples published by a publisher
-    pub fn get_sample_receiver(&self, _: SampleReceiverToken) -> SampleReceiver<T, S> {
-        SampleReceiver::<T, S>::new(self.ffi_sub.clone())
+    pub fn get_sample_receiver(&self, _: SampleReceiverToken) -> SampleReceiver<T, S, H> {
+        SampleReceiver::<T, S, H>::new(self.ffi_sub.clone(), self.type_identity)
     }
 
     /// Obtain the [`SampleReceiver`] to take samples published by a publisher
@@ -251,13 +398,13 @@ impl<T: ?Sized, S: ffi::SubscriberStrongRef> Subscriber<T, S> {
     /// Unsubscribes from the publisher by consuming the `Subscriber` and creating an [`InactiveSubscriber`]
     ///
     /// It might take up to 50 milliseconds until `RouDi` runs its discovery loop and the subscriber
-    /// will be unsubscribed from the publisher.
-    pub fn unsubscribe(self, sample_receiver: SampleReceiver<T, S>) -> InactiveSubscriber<T> {
+    /// will be unsubscribed from the publisher. Use [`InactiveSubscriber::wait_until_unsubscribed`] to wait for this.
+    pub fn unsubscribe(self, sample_receiver: SampleReceiver<T, S, H>) -> InactiveSubscriber<T, H> {
         self.ffi_sub.as_ref().unsubscribe();
 
         drop(sample_receiver);
 
-        InactiveSubscriber::from_ffi(self.ffi_sub.take())
+        InactiveSubscriber::from_ffi(self.ffi_sub.take(), self.type_identity, self._node)
     }
 
     /// Releases a raw sample which will not be used anymore
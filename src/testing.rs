@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+//! Test harness with `RouDi` running in the test process
+//!
+//! This module is only available with the `testing` feature.
+
+use crate::roudi::RouDiConfig;
+use crate::IceoryxError;
+
+use std::time::Duration;
+
+/// `RouDi` for hermetic tests, running in the process of the test
+///
+/// In contrast to the [`RouDi`](crate::roudi::RouDi), the runtimes of the current process are cleaned up
+/// when the `RouDiEnvironment` is dropped. This enables multiple tests with their own `RouDiEnvironment`
+/// and runtime to run one after another in the same process. Since there can be only one `RouDi`, tests
+/// with a `RouDiEnvironment` must not run in parallel, e.g. by running them with `--test-threads=1`.
+///
+/// The applications are not monitored and the discovery loop of `RouDi` can be triggered with
+/// [`trigger_discovery_loop`](RouDiEnvironment::trigger_discovery_loop) to deterministically process
+/// subscription requests instead of waiting for the next cycle of the loop.
+///
+/// # Example
+/// ```
+/// use iceoryx_rs::testing::RouDiEnvironment;
+/// use iceoryx_rs::{PublisherBuilder, Runtime, SubscribeState, SubscriberBuilder};
+///
+/// # use anyhow::Result;
+/// # fn main() -> Result<()> {
+/// let roudi = RouDiEnvironment::new();
+///
+/// Runtime::init("testing");
+///
+/// let publisher = PublisherBuilder::<u32>::new("all", "glory", "hypnotoad").create()?;
+/// let inactive_subscriber =
+///     SubscriberBuilder::<u32>::new("all", "glory", "hypnotoad").create_without_subscribe()?;
+/// let (subscriber, _sample_receive_token) = inactive_subscriber.subscribe();
+///
+/// roudi.trigger_discovery_loop();
+/// assert_eq!(subscriber.subscription_state(), SubscribeState::Subscribed);
+/// # drop(publisher);
+/// # Ok(())
+/// # }
+/// ```
+pub struct RouDiEnvironment {
+    roudi: Box<ffi::RouDiEnvironment>,
+}
+
+impl RouDiEnvironment {
+    /// Creates a `RouDiEnvironment` with the default config
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is already a `RouDi` running, either in this or in another process.
+    pub fn new() -> Self {
+        Self {
+            roudi: ffi::RouDiEnvironment::new(),
+        }
+    }
+
+    /// Creates a `RouDiEnvironment` with the memory layout of `config`
+    ///
+    /// The monitoring mode of the config is ignored since the applications are never monitored.
+    ///
+    /// The following errors are returned
+    /// - [`IceoryxError::InvalidRouDiConfig`] if the config is invalid; see [`RouDiConfig::validate`]
+    /// - [`IceoryxError::RouDiAlreadyRunning`] if there is already a `RouDi` running, either in this or in
+    ///   another process
+    /// - [`IceoryxError::RouDiLockUnavailable`] if the file lock of `RouDi` cannot be created
+    pub fn with_config(config: &RouDiConfig) -> Result<Self, IceoryxError> {
+        let config = config.to_ffi()?;
+        let roudi = ffi::RouDiEnvironment::try_with_config(&config)?;
+        Ok(Self { roudi })
+    }
+
+    /// Sets the time [`inter_op_wait`](RouDiEnvironment::inter_op_wait) blocks; the default is 200 milliseconds
+    pub fn set_inter_op_waiting_time(&mut self, waiting_time: Duration) {
+        self.roudi.set_inter_op_waiting_time(waiting_time);
+    }
+
+    /// Blocks until `RouDi` has processed the pending requests
+    ///
+    /// This waits for the time set with [`set_inter_op_waiting_time`](RouDiEnvironment::set_inter_op_waiting_time),
+    /// which is sufficient for the background threads of `RouDi` to process e.g. an offer of a publisher
+    /// and to connect the matching subscribers.
+    pub fn inter_op_wait(&self) {
+        self.roudi.inter_op_wait();
+    }
+
+    /// Runs the discovery loop of `RouDi` and returns after it is finished
+    ///
+    /// Requests like [`InactiveSubscriber::subscribe`](crate::InactiveSubscriber::subscribe) or
+    /// [`Subscriber::unsubscribe`](crate::Subscriber::unsubscribe) are processed by the discovery loop. They take
+    /// effect when this method returns, without waiting for the next cycle of the loop.
+    pub fn trigger_discovery_loop(&self) {
+        self.roudi.trigger_discovery_loop();
+    }
+}
+
+impl Default for RouDiEnvironment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
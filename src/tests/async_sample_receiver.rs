@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::testing::RouDiEnvironment;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+use futures::executor::block_on;
+use futures::StreamExt;
+
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn next_is_woken_up_by_published_sample() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("async_sample_receiver");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "Async", "Counter")
+            .queue_capacity(5)
+            .create_mt()?;
+    let mut sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "Async", "Counter").create()?;
+
+    const SEND_COUNTER: u32 = 42;
+    let publisher_thread = thread::spawn(move || -> Result<()> {
+        // give the subscriber time to register the waker before the sample is published
+        thread::sleep(Duration::from_millis(100));
+        let mut sample = publisher.loan()?;
+        *sample = SEND_COUNTER;
+        publisher.publish(sample);
+        Ok(())
+    });
+
+    match block_on(sample_receiver.next()) {
+        Some(sample) => assert_eq!(*sample, SEND_COUNTER),
+        _ => return Err(anyhow!("Could not read sample")),
+    }
+
+    publisher_thread
+        .join()
+        .map_err(|_| anyhow!("Could not join publisher thread"))??;
+
+    subscriber.unsubscribe(sample_receiver);
+
+    Ok(())
+}
+
+#[test]
+fn stream_of_multiple_sample_receivers() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("async_sample_receiver");
+
+    let (subscriber_a, sample_receive_token_a) =
+        SubscriberBuilder::<u32>::new("Test", "Async", "A").create_mt()?;
+    let sample_receiver_a = subscriber_a.get_sample_receiver(sample_receive_token_a);
+    let (subscriber_b, sample_receive_token_b) =
+        SubscriberBuilder::<[u8]>::new("Test", "Async", "B").create_mt()?;
+    let sample_receiver_b = subscriber_b.get_sample_receiver(sample_receive_token_b);
+
+    let publisher_a = PublisherBuilder::<u32>::new("Test", "Async", "A").create()?;
+    let publisher_b = PublisherBuilder::<[u8]>::new("Test", "Async", "B").create()?;
+
+    const NUMBER_OF_SAMPLES: usize = 3;
+    let publisher_thread = thread::spawn(move || -> Result<()> {
+        for counter in 0..NUMBER_OF_SAMPLES {
+            thread::sleep(Duration::from_millis(10));
+            let mut sample = publisher_a.loan()?;
+            *sample = counter as u32;
+            publisher_a.publish(sample);
+            publisher_b.publish(publisher_b.loan_slice(counter + 1)?);
+        }
+        Ok(())
+    });
+
+    let (samples_a, samples_b) = block_on(async {
+        futures::join!(
+            sample_receiver_a
+                .take(NUMBER_OF_SAMPLES)
+                .map(|sample| *sample)
+                .collect::<Vec<_>>(),
+            sample_receiver_b
+                .take(NUMBER_OF_SAMPLES)
+                .map(|sample| sample.len())
+                .collect::<Vec<_>>()
+        )
+    });
+
+    assert_eq!(samples_a, vec![0, 1, 2]);
+    assert_eq!(samples_b, vec![1, 2, 3]);
+
+    publisher_thread
+        .join()
+        .map_err(|_| anyhow!("Could not join publisher thread"))??;
+
+    Ok(())
+}
+
+#[test]
+fn next_returns_none_when_sample_receiver_is_stopped() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("async_sample_receiver");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "Async", "Counter").create_mt()?;
+    let mut sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    subscriber.stop_sample_receiver();
+
+    assert!(block_on(sample_receiver.next()).is_none());
+
+    Ok(())
+}
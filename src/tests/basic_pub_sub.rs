@@ -81,7 +81,7 @@ fn multi_threaded_subscriber() -> Result<()> {
 
         match sample_receiver.take() {
             Some(sample) => assert_eq!(sample.counter, SEND_COUNTER),
-            _ => assert!(false, "no sample received"),
+            _ => panic!("no sample received"),
         }
 
         sample_receiver
@@ -210,7 +210,7 @@ fn loan_byte_slice_and_publish() -> Result<()> {
     assert!(sample_receiver.has_data());
 
     match sample_receiver.take() {
-        Some(sample) => assert_eq!(sample.as_ref().get_u32_le(), SEND_COUNTER),
+        Some(sample) => assert_eq!((&sample[..]).get_u32_le(), SEND_COUNTER),
         _ => return Err(anyhow!("Could not read sample")),
     }
 
@@ -251,7 +251,7 @@ fn loan_uninit_byte_slice_and_publish() -> Result<()> {
     match sample_receiver.take() {
         Some(sample) => {
             assert_eq!(sample.len(), std::mem::size_of::<u32>());
-            assert_eq!(sample.as_ref().get_u32_le(), SEND_COUNTER);
+            assert_eq!((&sample[..]).get_u32_le(), SEND_COUNTER);
         }
         _ => return Err(anyhow!("Could not read sample")),
     }
@@ -281,9 +281,9 @@ fn loan_uninit_byte_slice_with_type_cast_and_publish() -> Result<()> {
         std::mem::align_of::<Counter>(),
     )?;
     let sample = unsafe {
-        sample
-            .try_as_uninit::<Counter>()
-            .map(|sample| (*sample.as_mut_ptr()).counter = SEND_COUNTER);
+        if let Some(sample) = sample.try_as_uninit::<Counter>() {
+            (*sample.as_mut_ptr()).counter = SEND_COUNTER;
+        }
         sample.assume_init()
     };
     publisher.publish(sample);
@@ -296,7 +296,9 @@ fn loan_uninit_byte_slice_with_type_cast_and_publish() -> Result<()> {
         Some(sample) => unsafe {
             let sample = sample.try_as::<Counter>();
             assert!(sample.is_some());
-            sample.map(|sample| assert_eq!(sample.counter, SEND_COUNTER));
+            if let Some(sample) = sample {
+                assert_eq!(sample.counter, SEND_COUNTER);
+            }
         },
         _ => return Err(anyhow!("Could not read sample")),
     }
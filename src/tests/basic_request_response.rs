@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::testing::RouDiEnvironment;
+use crate::ClientBuilder;
+use crate::ConnectionState;
+use crate::Runtime;
+use crate::ServerBuilder;
+use crate::{ClientSendError, IceoryxError};
+
+use anyhow::{anyhow, Result};
+
+#[test]
+fn request_and_response_with_matching_sequence_id() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("basic_request_response");
+
+    let server = ServerBuilder::<u64, u64>::new("Test", "BasicRequestResponse", "Add").create()?;
+    let client = ClientBuilder::<u64, u64>::new("Test", "BasicRequestResponse", "Add").create()?;
+
+    assert_eq!(client.connection_state(), ConnectionState::Connected);
+    assert!(server.has_clients());
+
+    let first_request = client.loan()?;
+    let first_sequence_id = first_request.sequence_id();
+    drop(first_request);
+
+    let mut request = client.loan()?;
+    let sequence_id = request.sequence_id();
+    assert_ne!(sequence_id, first_sequence_id);
+    *request = 42;
+    client.send(request)?;
+
+    assert!(server.has_requests());
+    let request = server
+        .take_request()
+        .ok_or_else(|| anyhow!("Could not read request"))?;
+    assert_eq!(*request, 42);
+    assert_eq!(request.sequence_id(), sequence_id);
+
+    let mut response = server.loan_response(&request)?;
+    assert_eq!(response.sequence_id(), sequence_id);
+    *response = *request + 1;
+    server.send(response)?;
+    drop(request);
+
+    assert!(client.has_responses());
+    match client.take_response() {
+        Some(response) => {
+            assert_eq!(*response, 43);
+            assert_eq!(response.sequence_id(), sequence_id);
+        }
+        _ => return Err(anyhow!("Could not read response")),
+    }
+
+    assert!(client.take_response().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn sending_request_without_connect_fails() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("basic_request_response");
+
+    let client = ClientBuilder::<u64, u64>::new("Test", "BasicRequestResponse", "Add")
+        .create_without_connect()?;
+
+    assert_eq!(client.connection_state(), ConnectionState::NotConnected);
+
+    let request = client.loan()?;
+    match client.send(request) {
+        Err(IceoryxError::SendRequestFailed(ClientSendError::NoConnectRequested)) => (),
+        _ => return Err(anyhow!("Sending request should have failed")),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn requests_and_responses_with_mismatching_type_are_rejected() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("basic_request_response");
+
+    let server = ServerBuilder::<u64, u16>::new("Test", "BasicRequestResponse", "Add").create()?;
+    let client =
+        ClientBuilder::<[u64; 2], u64>::new("Test", "BasicRequestResponse", "Add").create()?;
+    let matching_client =
+        ClientBuilder::<u64, u64>::new("Test", "BasicRequestResponse", "Add").create()?;
+
+    client.send(client.loan()?)?;
+    client.send(client.loan()?)?;
+
+    match server.try_take_request() {
+        Err(IceoryxError::PayloadSizeMismatch {
+            expected: 8,
+            received: 16,
+        }) => (),
+        _ => return Err(anyhow!("Expected a payload size mismatch")),
+    }
+    assert!(server.take_request().is_none());
+    assert!(!server.has_requests());
+
+    matching_client.send(matching_client.loan()?)?;
+    let request = server
+        .take_request()
+        .ok_or_else(|| anyhow!("Could not read request"))?;
+    server.send(server.loan_response(&request)?)?;
+
+    match matching_client.try_take_response() {
+        Err(IceoryxError::AlignmentMismatch {
+            min_required: 8,
+            received: 2,
+        }) => (),
+        _ => return Err(anyhow!("Expected an alignment mismatch")),
+    }
+    assert!(!matching_client.has_responses());
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::codec::Codec;
+use crate::testing::RouDiEnvironment;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Config {
+    name: String,
+    sensors: Vec<String>,
+    rate: u32,
+}
+
+fn config() -> Config {
+    Config {
+        name: "hypnotoad".to_string(),
+        sensors: vec!["lidar".to_string(), "radar".to_string()],
+        rate: 42,
+    }
+}
+
+fn transfer_config(codec: impl Codec + Copy) -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("codec");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<[u8]>::new("Test", "Codec", "Config").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<[u8]>::new("Test", "Codec", "Config").create()?;
+    publisher.publish_serialized(&config(), codec)?;
+
+    let sample = sample_receiver
+        .take()
+        .ok_or_else(|| anyhow!("No sample received"))?;
+    assert_eq!(Some(sample.len()), codec.serialized_size(&config()));
+    assert_eq!(sample.deserialize::<Config>(codec)?, config());
+
+    Ok(())
+}
+
+#[cfg(feature = "postcard")]
+#[test]
+fn serialized_value_is_transferred_with_postcard() -> Result<()> {
+    transfer_config(crate::codec::Postcard)
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn serialized_value_is_transferred_with_bincode() -> Result<()> {
+    transfer_config(crate::codec::Bincode)
+}
+
+/// Uses postcard without providing the serialized size upfront
+#[cfg(feature = "postcard")]
+#[derive(Clone, Copy)]
+struct UnknownSizeCodec;
+
+#[cfg(feature = "postcard")]
+impl Codec for UnknownSizeCodec {
+    fn serialize_into<T: Serialize + ?Sized>(
+        &self,
+        _value: &T,
+        _buffer: &mut [u8],
+    ) -> Result<(), IceoryxError> {
+        unreachable!("The serialized size is unknown")
+    }
+
+    fn serialize<T: Serialize + ?Sized>(&self, value: &T) -> Result<Vec<u8>, IceoryxError> {
+        crate::codec::Postcard.serialize(value)
+    }
+
+    fn deserialize<'de, T: Deserialize<'de>>(&self, bytes: &'de [u8]) -> Result<T, IceoryxError> {
+        crate::codec::Postcard.deserialize(bytes)
+    }
+}
+
+#[cfg(feature = "postcard")]
+#[test]
+fn serialized_value_with_unknown_size_is_transferred() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("codec");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<[u8]>::new("Test", "Codec", "Config").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<[u8]>::new("Test", "Codec", "Config").create()?;
+    publisher.publish_serialized(&config(), UnknownSizeCodec)?;
+
+    let sample = sample_receiver
+        .take()
+        .ok_or_else(|| anyhow!("No sample received"))?;
+    assert_eq!(
+        sample.deserialize::<Config>(crate::codec::Postcard)?,
+        config()
+    );
+
+    Ok(())
+}
+
+fn deserialize_different_type(codec: impl Codec + Copy) -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("codec");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<[u8]>::new("Test", "Codec", "Config").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<[u8]>::new("Test", "Codec", "Config").create()?;
+    publisher.publish_serialized(&42u8, codec)?;
+
+    let sample = sample_receiver
+        .take()
+        .ok_or_else(|| anyhow!("No sample received"))?;
+    match sample.deserialize::<Config>(codec) {
+        Err(IceoryxError::DeserializationFailed { .. }) => Ok(()),
+        Err(e) => Err(e.into()),
+        Ok(_) => Err(anyhow!("Deserialized a config from a single byte")),
+    }
+}
+
+#[cfg(feature = "postcard")]
+#[test]
+fn deserializing_a_different_type_fails_with_postcard() -> Result<()> {
+    deserialize_different_type(crate::codec::Postcard)
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn deserializing_a_different_type_fails_with_bincode() -> Result<()> {
+    deserialize_different_type(crate::codec::Bincode)
+}
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::containers::{FixedString, FixedVec};
+use crate::marker::ShmSend;
+use crate::testing::RouDiEnvironment;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+
+use std::convert::TryFrom;
+use std::mem::{align_of, size_of};
+
+#[repr(C)]
+#[derive(Default, ShmSend)]
+struct Message {
+    name: FixedString<15>,
+    values: FixedVec<u32, 3>,
+}
+
+#[test]
+fn layout_matches_iceoryx_cxx_containers() {
+    // `char m_rawstring[Capacity + 1U]` followed by `uint64_t m_rawstringSize`
+    assert_eq!(size_of::<FixedString<15>>(), 16 + 8);
+    assert_eq!(size_of::<FixedString<16>>(), 24 + 8);
+    assert_eq!(align_of::<FixedString<16>>(), align_of::<u64>());
+
+    // `alignas(T) element_t m_data[Capacity]` followed by `uint64_t m_size`
+    assert_eq!(size_of::<FixedVec<u32, 3>>(), 16 + 8);
+    assert_eq!(size_of::<FixedVec<u8, 8>>(), 8 + 8);
+    assert_eq!(align_of::<FixedVec<u8, 8>>(), align_of::<u64>());
+}
+
+#[test]
+fn fixed_string_respects_capacity() -> Result<()> {
+    let mut string = FixedString::<8>::try_from("hypno")?;
+    assert_eq!(string, "hypno");
+    assert_eq!(string.capacity(), 8);
+
+    assert!(matches!(
+        string.push_str("toad"),
+        Err(IceoryxError::CapacityExceeded {
+            capacity: 8,
+            required: 9
+        })
+    ));
+    assert_eq!(string, "hypno");
+
+    string.push_str("toa")?;
+    assert_eq!(string.push('d'), Err('d'));
+    assert_eq!(string.pop(), Some('a'));
+    assert_eq!(&*string, "hypnoto");
+
+    assert_eq!(FixedString::<4>::from_str_truncated("hypnotoad"), "hypn");
+    // 'ä' has two bytes and is not split
+    assert_eq!(FixedString::<4>::from_str_truncated("ääää"), "ää");
+    assert_eq!(FixedString::<3>::from_str_truncated("ääää"), "ä");
+
+    assert!(FixedString::<4>::try_from("hypnotoad").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn fixed_vec_respects_capacity() -> Result<()> {
+    let mut vec = FixedVec::<u32, 3>::try_from(&[13, 37][..])?;
+    assert_eq!(vec.len(), 2);
+    assert_eq!(vec.capacity(), 3);
+
+    assert!(matches!(
+        vec.extend_from_slice(&[42, 73]),
+        Err(IceoryxError::CapacityExceeded {
+            capacity: 3,
+            required: 4
+        })
+    ));
+
+    vec.push(42).map_err(|_| anyhow!("Capacity exceeded"))?;
+    assert!(vec.is_full());
+    assert_eq!(vec.push(73), Err(73));
+    assert_eq!(&*vec, &[13, 37, 42]);
+
+    vec[0] = 1;
+    assert_eq!(vec.pop(), Some(42));
+    assert_eq!(vec.iter().copied().collect::<Vec<_>>(), vec![1, 37]);
+
+    vec.clear();
+    assert!(vec.is_empty());
+    assert_eq!(vec.pop(), None);
+
+    Ok(())
+}
+
+#[test]
+fn containers_can_be_transferred() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("containers");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<Message>::new("Test", "Containers", "Message").create()?;
+
+    let publisher = PublisherBuilder::<Message>::new("Test", "Containers", "Message").create()?;
+
+    let mut sample = publisher.loan()?;
+    sample.name.push_str("hypnotoad")?;
+    sample.values.extend_from_slice(&[13, 37, 42])?;
+    publisher.publish(sample);
+
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    match sample_receiver.take() {
+        Some(sample) => {
+            assert_eq!(sample.name, "hypnotoad");
+            assert_eq!(&*sample.values, &[13, 37, 42]);
+        }
+        _ => return Err(anyhow!("Could not read sample")),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::discovery;
+use crate::testing::RouDiEnvironment;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SubscribeState;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+
+use std::time::{Duration, Instant};
+
+const TIMEOUT: Duration = Duration::from_secs(1);
+
+#[test]
+fn waiting_for_offer_and_subscription_returns_when_processed() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("discovery");
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "Discovery", "Wait")
+        .create_without_offer()?
+        .offer();
+    publisher.wait_until_offered(TIMEOUT)?;
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "Discovery", "Wait")
+            .create_without_subscribe()?
+            .subscribe();
+    subscriber.wait_until_subscribed(TIMEOUT)?;
+    assert_eq!(subscriber.subscription_state(), SubscribeState::Subscribed);
+    assert!(publisher.has_subscribers());
+
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+    let subscriber = subscriber.unsubscribe(sample_receiver);
+    subscriber.wait_until_unsubscribed(TIMEOUT)?;
+    assert_eq!(
+        subscriber.subscription_state(),
+        SubscribeState::NotSubscribed
+    );
+
+    let publisher = publisher.stop_offer();
+    publisher.wait_until_stopped_offer(TIMEOUT)?;
+
+    Ok(())
+}
+
+#[test]
+fn waiting_for_unprocessed_request_times_out() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("discovery");
+
+    const SHORT_TIMEOUT: Duration = Duration::from_millis(100);
+    let start = Instant::now();
+    match discovery::wait_for_discovery_loop(SHORT_TIMEOUT, || false) {
+        Err(IceoryxError::DiscoveryTimeout { timeout }) if timeout == SHORT_TIMEOUT => {
+            assert!(start.elapsed() >= SHORT_TIMEOUT);
+            Ok(())
+        }
+        Err(e) => Err(e.into()),
+        Ok(_) => Err(anyhow!("Unprocessed request was reported as processed")),
+    }
+}
+
+#[test]
+fn publisher_waits_for_subscribers_and_counts_them() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("discovery");
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "Discovery", "Subscribers").create()?;
+    assert_eq!(publisher.number_of_subscribers(), 0);
+    assert!(matches!(
+        publisher.wait_for_subscribers(Duration::from_millis(100)),
+        Err(IceoryxError::DiscoveryTimeout { .. })
+    ));
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "Discovery", "Subscribers")
+            .create_without_subscribe()?
+            .subscribe();
+    publisher.wait_for_subscribers(TIMEOUT)?;
+    assert_eq!(publisher.number_of_subscribers(), 1);
+
+    let (_other_subscriber, _other_sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "Discovery", "Subscribers").create()?;
+    assert_eq!(publisher.number_of_subscribers(), 2);
+
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+    subscriber.unsubscribe(sample_receiver);
+    discovery::wait_for_discovery_loop(TIMEOUT, || publisher.number_of_subscribers() == 1)?;
+
+    Ok(())
+}
+
+#[test]
+fn discovery_loop_watcher_signals_consecutive_runs() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("discovery");
+
+    let mut watcher = discovery::DiscoveryLoopWatcher::new()?;
+    for _ in 0..5 {
+        assert!(watcher.wait(Instant::now() + TIMEOUT)?);
+    }
+
+    Ok(())
+}
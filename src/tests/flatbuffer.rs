@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::testing::RouDiEnvironment;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+use flatbuffers::{ForwardsUOffset, Vector};
+
+#[test]
+fn flatbuffer_is_built_in_the_chunk_and_read_in_place() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("flatbuffer");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<[u8]>::new("Test", "FlatBuffer", "Names").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<[u8]>::new("Test", "FlatBuffer", "Names").create()?;
+    let mut builder = publisher.flatbuffer_builder(256)?;
+    let names = ["lidar", "radar", "camera"].map(|name| builder.create_string(name));
+    let names = builder.create_vector(&names);
+    builder.finish_minimal(names);
+    publisher.publish_flatbuffer(builder);
+
+    let sample = sample_receiver
+        .take()
+        .ok_or_else(|| anyhow!("No sample received"))?;
+    let names = sample.flatbuffer_root::<Vector<ForwardsUOffset<&str>>>()?;
+    assert_eq!(
+        names.iter().collect::<Vec<_>>(),
+        ["lidar", "radar", "camera"]
+    );
+
+    let data = sample.flatbuffer_data()?;
+    assert!(sample.as_ptr_range().contains(&data.as_ptr()));
+
+    Ok(())
+}
+
+#[test]
+fn flatbuffer_exceeding_the_capacity_grows_into_a_larger_chunk() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("flatbuffer");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<[u8]>::new("Test", "FlatBuffer", "Payload").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<[u8]>::new("Test", "FlatBuffer", "Payload").create()?;
+    let payload = (0..1000u32).collect::<Vec<_>>();
+    let mut builder = publisher.flatbuffer_builder(16)?;
+    let vector = builder.create_vector(&payload);
+    builder.finish_minimal(vector);
+    publisher.publish_flatbuffer(builder);
+
+    let sample = sample_receiver
+        .take()
+        .ok_or_else(|| anyhow!("No sample received"))?;
+    let vector = sample.flatbuffer_root::<Vector<u32>>()?;
+    assert_eq!(vector.iter().collect::<Vec<_>>(), payload);
+
+    Ok(())
+}
+
+#[test]
+fn sample_without_flatbuffer_is_rejected() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("flatbuffer");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<[u8]>::new("Test", "FlatBuffer", "Invalid").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<[u8]>::new("Test", "FlatBuffer", "Invalid").create()?;
+    publisher.publish(publisher.loan_slice(4)?);
+    let mut sample = publisher.loan_slice(16)?;
+    sample.fill(0xff);
+    publisher.publish(sample);
+
+    for _ in 0..2 {
+        let sample = sample_receiver
+            .take()
+            .ok_or_else(|| anyhow!("No sample received"))?;
+        match sample.flatbuffer_root::<&str>() {
+            Err(IceoryxError::InvalidFlatBuffer { .. }) => (),
+            Err(e) => return Err(e.into()),
+            Ok(_) => return Err(anyhow!("Invalid FlatBuffer was accepted")),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::testing::RouDiEnvironment;
+use crate::Listener;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SubscriberBuilder;
+
+use anyhow::Result;
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+#[test]
+fn listener_calls_callback_on_received_samples() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("listener");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "Listener", "Counter")
+            .queue_capacity(5)
+            .create_mt()?;
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "Listener", "Counter").create()?;
+
+    // a sample published before the attachment is also delivered to the callback
+    let mut sample = publisher.loan()?;
+    *sample = 13;
+    publisher.publish(sample);
+
+    let (tx, rx) = mpsc::channel();
+    let listener = Listener::new();
+    let attachment =
+        listener.attach_subscriber(&subscriber, sample_receive_token, move |sample_receiver| {
+            while let Some(sample) = sample_receiver.take() {
+                tx.send(*sample).expect("Sending the received value");
+            }
+        })?;
+
+    assert_eq!(rx.recv_timeout(TIMEOUT)?, 13);
+
+    let mut sample = publisher.loan()?;
+    *sample = 42;
+    publisher.publish(sample);
+
+    assert_eq!(rx.recv_timeout(TIMEOUT)?, 42);
+
+    let sample_receiver = attachment.detach();
+
+    let mut sample = publisher.loan()?;
+    *sample = 73;
+    publisher.publish(sample);
+
+    assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+    assert!(sample_receiver.has_data());
+
+    publisher.stop_offer();
+    subscriber.unsubscribe(sample_receiver);
+
+    Ok(())
+}
+
+#[test]
+fn listener_calls_callback_on_publisher_subscription_state_change() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("listener");
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "Listener", "Counter").create()?;
+
+    let (tx, rx) = mpsc::channel();
+    let listener = Listener::new();
+    let attachment = listener.attach_publisher(&publisher, move |has_subscribers| {
+        tx.send(has_subscribers)
+            .expect("Sending the subscription state");
+    })?;
+
+    assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "Listener", "Counter").create_mt()?;
+
+    assert!(rx.recv_timeout(TIMEOUT)?);
+
+    drop(attachment);
+    publisher.stop_offer();
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+    subscriber.unsubscribe(sample_receiver);
+
+    Ok(())
+}
+
+#[test]
+fn listener_calls_callback_on_repeated_publisher_subscription_state_changes() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("listener");
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "Listener", "Repeated").create()?;
+
+    let (tx, rx) = mpsc::channel();
+    let listener = Listener::new();
+    let attachment = listener.attach_publisher(&publisher, move |has_subscribers| {
+        tx.send(has_subscribers)
+            .expect("Sending the subscription state");
+    })?;
+
+    let mut subscriber =
+        SubscriberBuilder::<u32>::new("Test", "Listener", "Repeated").create_without_subscribe()?;
+    for _ in 0..3 {
+        let (active_subscriber, sample_receive_token) = subscriber.subscribe_mt();
+        assert!(rx.recv_timeout(TIMEOUT)?);
+
+        let sample_receiver = active_subscriber.get_sample_receiver(sample_receive_token);
+        subscriber = active_subscriber.unsubscribe(sample_receiver);
+        assert!(!rx.recv_timeout(TIMEOUT)?);
+    }
+
+    drop(attachment);
+    publisher.stop_offer();
+
+    Ok(())
+}
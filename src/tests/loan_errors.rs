@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::testing::RouDiEnvironment;
+use crate::ClientBuilder;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+
+use anyhow::{anyhow, Result};
+
+#[test]
+fn loaning_more_samples_than_available_in_the_mempool_fails() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("loan_errors");
+
+    // the largest mempool of the default config has 10 chunks with 4 MiB
+    const LEN: usize = 4 * 1024 * 1024;
+    const CHUNKS_PER_PUBLISHER: usize = 6;
+
+    let publisher_a = PublisherBuilder::<[u8]>::new("Test", "LoanErrors", "A").create()?;
+    let publisher_b = PublisherBuilder::<[u8]>::new("Test", "LoanErrors", "B").create()?;
+
+    let mut samples = Vec::new();
+    for _ in 0..CHUNKS_PER_PUBLISHER {
+        samples.push(publisher_a.loan_uninit_slice(LEN)?);
+    }
+    for _ in 0..CHUNKS_PER_PUBLISHER {
+        match publisher_b.loan_uninit_slice(LEN) {
+            Ok(sample) => samples.push(sample),
+            Err(IceoryxError::MempoolsExhausted { size, alignment }) => {
+                assert_eq!(size, LEN);
+                assert_eq!(alignment, 1);
+
+                samples.pop();
+                assert!(publisher_b.loan_uninit_slice(LEN).is_ok());
+
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Err(anyhow!("Loaning samples did not fail"))
+}
+
+#[test]
+fn loaning_too_many_samples_in_parallel_fails() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("loan_errors");
+
+    let publisher = PublisherBuilder::<u64>::new("Test", "LoanErrors", "Counter").create()?;
+
+    const MAX_LOAN_ATTEMPTS: usize = 1000;
+    let mut samples = Vec::new();
+    for _ in 0..MAX_LOAN_ATTEMPTS {
+        match publisher.loan() {
+            Ok(sample) => samples.push(sample),
+            Err(IceoryxError::TooManySamplesLoanedInParallel { size, alignment }) => {
+                assert!(!samples.is_empty());
+                assert_eq!(size, 8);
+                assert_eq!(alignment, 8);
+
+                samples.pop();
+                assert!(publisher.loan().is_ok());
+
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Err(anyhow!("Loaning samples did not fail"))
+}
+
+#[test]
+fn loaning_too_many_requests_in_parallel_fails() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("loan_errors");
+
+    let client =
+        ClientBuilder::<u32, u32>::new("Test", "LoanErrors", "Request").create_without_connect()?;
+
+    const MAX_LOAN_ATTEMPTS: usize = 1000;
+    let mut requests = Vec::new();
+    for _ in 0..MAX_LOAN_ATTEMPTS {
+        match client.loan() {
+            Ok(request) => requests.push(request),
+            Err(IceoryxError::TooManySamplesLoanedInParallel { size, alignment }) => {
+                assert!(!requests.is_empty());
+                assert_eq!(size, 4);
+                assert_eq!(alignment, 4);
+
+                requests.pop();
+                assert!(client.loan().is_ok());
+
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Err(anyhow!("Loaning requests did not fail"))
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::testing::RouDiEnvironment;
+use crate::LogLevel;
+use crate::Runtime;
+use crate::RuntimeBuilder;
+
+use anyhow::Result;
+
+use std::time::Duration;
+
+#[test]
+fn runtime_builder_with_custom_options_registers_at_roudi() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    let runtime = RuntimeBuilder::new("log")
+        .log_level(LogLevel::Off)
+        .roudi_waiting_timeout(Duration::from_secs(1))
+        .try_init()?;
+    assert_eq!(runtime.app_name(), "log");
+
+    Ok(())
+}
+
+#[test]
+fn log_sink_cannot_be_set_while_a_runtime_is_initialized() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    let _runtime = Runtime::try_init("log")?;
+
+    assert_eq!(
+        ffi::set_log_sink(|_, _| ()),
+        Err(ffi::LogSinkError::RuntimeAlreadyInitialized)
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "log")]
+mod log_crate {
+    use super::*;
+
+    use anyhow::anyhow;
+
+    use std::sync::Mutex;
+    use std::thread;
+    use std::time::Instant;
+
+    struct TestLogger {
+        records: Mutex<Vec<(log::Level, String)>>,
+    }
+
+    impl log::Log for TestLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.target() == "iceoryx"
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                self.records
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .push((record.level(), record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: TestLogger = TestLogger {
+        records: Mutex::new(Vec::new()),
+    };
+
+    #[test]
+    fn iceoryx_log_output_is_forwarded_to_the_log_crate() -> Result<()> {
+        log::set_logger(&LOGGER).map_err(|e| anyhow!("{}", e))?;
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let _roudi = RouDiEnvironment::new();
+
+        // the RouDiEnvironment runs RouDi in the same process and its log output is also forwarded
+        let _runtime = RuntimeBuilder::new("log_forwarding")
+            .log_level(LogLevel::Verbose)
+            .forward_log_to_log_crate()
+            .try_init()?;
+
+        // RouDi might log the registration after the runtime already received the acknowledgement
+        let expected_record = (
+            log::Level::Debug,
+            "Registered new application log_forwarding".to_string(),
+        );
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            if LOGGER.records.lock().unwrap().contains(&expected_record) {
+                return Ok(());
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        Err(anyhow!("The registration was not logged"))
+    }
+}
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::st::WaitSet;
+use crate::testing::RouDiEnvironment;
+use crate::Publisher;
+use crate::PublisherBuilder;
+use crate::QueueFullPolicy;
+use crate::Runtime;
+use crate::SubscriberBuilder;
+
+use anyhow::Result;
+
+use std::time::Duration;
+
+const QUEUE_CAPACITY: u64 = 2;
+const NUMBER_OF_SAMPLES: u32 = 5;
+const TIMEOUT: Duration = Duration::from_secs(1);
+
+#[test]
+fn lost_samples_are_reported_by_the_sample_receiver() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("lost_samples");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "LostSamples", "Counter")
+            .queue_capacity(QUEUE_CAPACITY)
+            .queue_full_policy(QueueFullPolicy::DiscardOldestData)
+            .create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "LostSamples", "Counter").create()?;
+
+    assert!(!sample_receiver.has_lost_samples());
+
+    // the first sample is taken to have a sequence number for the publisher
+    publisher.publish(publisher.loan()?);
+    assert!(sample_receiver.take().is_some());
+
+    for counter in 1..=NUMBER_OF_SAMPLES {
+        let mut sample = publisher.loan()?;
+        *sample = counter;
+        publisher.publish(sample);
+    }
+
+    assert!(sample_receiver.has_lost_samples());
+    assert!(!sample_receiver.has_lost_samples());
+
+    let mut received = Vec::new();
+    while let Some(sample) = sample_receiver.take() {
+        received.push(*sample);
+    }
+    assert_eq!(received, vec![NUMBER_OF_SAMPLES - 1, NUMBER_OF_SAMPLES]);
+    assert_eq!(
+        sample_receiver.lost_samples(),
+        (NUMBER_OF_SAMPLES as u64) - QUEUE_CAPACITY
+    );
+
+    Ok(())
+}
+
+#[test]
+fn lost_samples_are_reported_by_the_wait_set() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("lost_samples");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "LostSamples", "Counter")
+            .queue_capacity(QUEUE_CAPACITY)
+            .create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let (other_subscriber, other_sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "LostSamples", "Counter")
+            .queue_capacity(NUMBER_OF_SAMPLES as u64)
+            .create()?;
+    let other_sample_receiver = other_subscriber.get_sample_receiver(other_sample_receive_token);
+
+    let wait_set = WaitSet::new();
+    let _attachment = wait_set.attach_sample_receiver(&sample_receiver, 13)?;
+    let _other_attachment = wait_set.attach_sample_receiver(&other_sample_receiver, 42)?;
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "LostSamples", "Counter").create()?;
+    for _ in 0..NUMBER_OF_SAMPLES {
+        publisher.publish(publisher.loan()?);
+    }
+
+    let mut ready = wait_set.wait();
+    ready.sort_unstable();
+    assert_eq!(ready, vec![13, 42]);
+    assert_eq!(wait_set.lost_samples(), vec![13]);
+    assert!(wait_set.lost_samples().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn restarted_publisher_is_not_reported_as_lost_samples() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("lost_samples");
+
+    const HISTORY_CAPACITY: u64 = 3;
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "LostSamples", "Restart")
+            .queue_capacity(2 * NUMBER_OF_SAMPLES as u64)
+            .history_request(HISTORY_CAPACITY)
+            .create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publish = |publisher: &Publisher<u32>, counter: u32| -> Result<()> {
+        let mut sample = publisher.loan()?;
+        *sample = counter;
+        publisher.publish(sample);
+        Ok(())
+    };
+    let take_all = || {
+        let mut received = Vec::new();
+        while let Some(sample) = sample_receiver.take() {
+            received.push(*sample);
+        }
+        received
+    };
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "LostSamples", "Restart")
+        .history_capacity(HISTORY_CAPACITY)
+        .create()?;
+    for counter in 1..=NUMBER_OF_SAMPLES {
+        publish(&publisher, counter)?;
+    }
+    assert_eq!(take_all().len(), NUMBER_OF_SAMPLES as usize);
+
+    // the reconnected publisher delivers its history again, i.e. the sequence numbers go backwards
+    let publisher = publisher.stop_offer();
+    publisher.wait_until_stopped_offer(TIMEOUT)?;
+    let publisher = publisher.offer();
+    publisher.wait_for_subscribers(TIMEOUT)?;
+    publish(&publisher, NUMBER_OF_SAMPLES + 1)?;
+    assert_eq!(
+        take_all(),
+        (NUMBER_OF_SAMPLES + 1 - HISTORY_CAPACITY as u32..=NUMBER_OF_SAMPLES + 1)
+            .collect::<Vec<_>>()
+    );
+
+    drop(publisher);
+    let publisher = PublisherBuilder::<u32>::new("Test", "LostSamples", "Restart").create()?;
+    publish(&publisher, 1)?;
+    assert_eq!(take_all(), vec![1]);
+
+    assert_eq!(sample_receiver.lost_samples(), 0);
+
+    Ok(())
+}
@@ -4,3 +4,52 @@
 
 // minimal setup with one publisher and one subscriber exchanging data
 mod basic_pub_sub;
+// minimal setup with one client and one server exchanging requests and responses
+mod basic_request_response;
+// waiting on multiple sample receivers and user triggers with a wait set
+mod wait_set;
+// callbacks executed by a listener on received samples and subscription state changes
+mod listener;
+// lookup of offered services
+mod service_discovery;
+// validation of service names and their use with builders and introspection types
+mod service_name;
+// user defined header transferred in front of the payload
+mod user_header;
+// waiting asynchronously for samples
+#[cfg(feature = "async")]
+mod async_sample_receiver;
+// types with derived ShmSend marker trait
+mod shm_send_derive;
+// detection of publisher and subscriber with different types
+mod type_identity;
+// errors when samples cannot be taken from the receiver queue
+mod take_errors;
+// detection of samples discarded due to a full receiver queue
+mod lost_samples;
+// fixed capacity containers which can be transferred via shared memory
+mod containers;
+// errors when samples cannot be loaned from the publisher
+mod loan_errors;
+// fallible registration at RouDi and re-initialization of the runtime
+mod runtime;
+// log level of iceoryx and forwarding of its log output to the log crate
+mod log;
+// RouDi launched in the process of the application
+mod roudi;
+// validation and TOML conversion of the RouDi config
+mod roudi_config;
+// waiting for RouDi to process offer and subscription requests and for subscribers to connect
+mod discovery;
+// RouDiEnvironment as test harness with a deterministically triggered discovery loop
+mod testing;
+// nodes which group ports and show up in the introspection
+mod node;
+// transfer of serde serializable types with [u8] publisher and subscriber
+#[cfg(any(feature = "postcard", feature = "bincode"))]
+mod codec;
+// FlatBuffers built and read directly in the chunks
+#[cfg(feature = "flatbuffers")]
+mod flatbuffer;
+// std::io and bytes adapter for writing and reading [u8] samples
+mod sample_io;
@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::introspection::{PortIntrospection, ProcessIntrospection};
+use crate::testing::RouDiEnvironment;
+use crate::ClientBuilder;
+use crate::IceoryxError;
+use crate::Node;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SampleReceiver;
+use crate::SampleReceiverWaitState;
+use crate::ServerBuilder;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+
+use std::time::{Duration, Instant};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+// the introspection topics are published periodically by `RouDi`
+fn wait_for_topic<T, S: ffi::SubscriberStrongRef>(
+    sample_receiver: &SampleReceiver<T, S>,
+    mut condition: impl FnMut(&T) -> bool,
+) -> bool {
+    let deadline = Instant::now() + TIMEOUT;
+    while let Some(timeout) = deadline.checked_duration_since(Instant::now()) {
+        match sample_receiver.take() {
+            Some(topic) if condition(&topic) => return true,
+            Some(_) => continue,
+            None => {
+                if !matches!(
+                    sample_receiver.wait_for_samples(timeout),
+                    SampleReceiverWaitState::SamplesAvailable
+                ) {
+                    return false;
+                }
+            }
+        }
+    }
+    false
+}
+
+#[test]
+fn node_shows_up_in_process_and_port_introspection() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("node");
+
+    let node = Node::new("camera")?;
+    assert_eq!(node.name(), "camera");
+
+    let _publisher = node
+        .publisher_builder::<u32>("Test", "Node", "Introspection")
+        .create()?;
+    let (_subscriber, _sample_receive_token) = node
+        .subscriber_builder::<u32>("Test", "Node", "Introspection")
+        .create()?;
+
+    let (process_subscriber, process_sample_receive_token) =
+        ProcessIntrospection::new()?.subscribe();
+    let process_sample_receiver =
+        process_subscriber.get_sample_receiver(process_sample_receive_token);
+    let node_is_listed = wait_for_topic(&process_sample_receiver, |topic| {
+        topic.processes().any(|process| {
+            process.name().as_deref() == Some("node")
+                && process.nodes().iter().any(|name| name == "camera")
+        })
+    });
+    if !node_is_listed {
+        return Err(anyhow!("Node not listed in the process introspection"));
+    }
+
+    let (port_subscriber, port_sample_receive_token) = PortIntrospection::new()?.subscribe();
+    let port_sample_receiver = port_subscriber.get_sample_receiver(port_sample_receive_token);
+    let ports_are_listed = wait_for_topic(&port_sample_receiver, |topic| {
+        topic.publisher_ports().any(|port| {
+            port.node_name().as_deref() == Some("camera")
+                && port
+                    .service_description()
+                    .is_some_and(|service| service.event_id == "Introspection")
+        }) && topic.subscriber_ports().any(|port| {
+            port.node_name().as_deref() == Some("camera")
+                && port
+                    .service_description()
+                    .is_some_and(|service| service.event_id == "Introspection")
+        })
+    });
+    if !ports_are_listed {
+        return Err(anyhow!(
+            "Ports of the node not listed in the port introspection"
+        ));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn creating_node_with_invalid_name_fails() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("node");
+
+    for name in ["", "came\nra", &"c".repeat(101)].iter() {
+        match Node::new(name) {
+            Err(IceoryxError::InvalidName { name: invalid_name }) if invalid_name == *name => (),
+            Err(e) => return Err(e.into()),
+            Ok(_) => return Err(anyhow!("Node with invalid name {:?} was created", name)),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn client_and_server_of_a_node_exchange_requests_and_responses() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("node");
+
+    let node = Node::new("planner")?;
+    let server = node
+        .server_builder::<u64, u64>("Test", "Node", "Add")
+        .create()?;
+    let client = node
+        .client_builder::<u64, u64>("Test", "Node", "Add")
+        .create()?;
+    drop(node);
+
+    let mut request = client.loan()?;
+    *request = 42;
+    client.send(request)?;
+
+    let request = server
+        .take_request()
+        .ok_or_else(|| anyhow!("No request received"))?;
+    let mut response = server.loan_response(&request)?;
+    *response = *request + 1;
+    server.send(response)?;
+
+    let response = client
+        .take_response()
+        .ok_or_else(|| anyhow!("No response received"))?;
+    assert_eq!(*response, 43);
+
+    Ok(())
+}
+
+fn is_invalid_name<T>(result: Result<T, IceoryxError>) -> bool {
+    matches!(result, Err(IceoryxError::InvalidName { .. }))
+}
+
+#[test]
+fn builders_reject_invalid_node_names() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("node");
+
+    assert!(is_invalid_name(
+        PublisherBuilder::<u32>::new("Test", "Node", "Names")
+            .node_name("came\0ra".to_string())
+            .create()
+    ));
+    assert!(is_invalid_name(
+        SubscriberBuilder::<u32>::new("Test", "Node", "Names")
+            .node_name(&"c".repeat(101))
+            .create()
+    ));
+    assert!(is_invalid_name(
+        ClientBuilder::<u32, u32>::new("Test", "Node", "Names")
+            .node_name("came\nra")
+            .create()
+    ));
+    assert!(is_invalid_name(
+        ServerBuilder::<u32, u32>::new("Test", "Node", "Names")
+            .node_name("came\0ra")
+            .create()
+    ));
+
+    Ok(())
+}
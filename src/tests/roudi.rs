@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::roudi::{MonitoringMode, RouDi, RouDiConfig};
+use crate::testing::RouDiEnvironment;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn small_config() -> RouDiConfig {
+    RouDiConfig::new()
+        .add_mempool(128, 100)
+        .add_mempool(1024, 10)
+        .monitoring_mode(MonitoringMode::Off)
+}
+
+#[test]
+fn applications_can_communicate_via_embedded_roudi() -> Result<()> {
+    let roudi = RouDi::launch(small_config())?;
+
+    let runtime = Runtime::try_init("embedded_roudi")?;
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "EmbeddedRouDi", "Counter")
+            .queue_capacity(5)
+            .create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "EmbeddedRouDi", "Counter").create()?;
+
+    // the discovery loop of RouDi connects the publisher and subscriber asynchronously
+    const SEND_COUNTER: u32 = 73;
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !sample_receiver.has_data() {
+        if Instant::now() > deadline {
+            return Err(anyhow!("Could not receive sample"));
+        }
+        let mut sample = publisher.loan()?;
+        *sample = SEND_COUNTER;
+        publisher.publish(sample);
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    match sample_receiver.take() {
+        Some(sample) => assert_eq!(*sample, SEND_COUNTER),
+        _ => return Err(anyhow!("Could not read sample")),
+    }
+
+    drop(sample_receiver);
+    drop(subscriber);
+    drop(publisher);
+    drop(runtime);
+    drop(roudi);
+
+    Ok(())
+}
+
+#[test]
+fn embedded_roudi_can_be_launched_again_after_drop() -> Result<()> {
+    let roudi = RouDi::launch(small_config())?;
+    drop(roudi);
+
+    let roudi = RouDi::launch(small_config())?;
+    let runtime = Runtime::try_init("embedded_roudi")?;
+    drop(runtime);
+    drop(roudi);
+
+    Ok(())
+}
+
+#[test]
+fn shutting_down_roudi_while_the_runtime_is_registered_fails() -> Result<()> {
+    let mut roudi = RouDi::launch(small_config())?;
+    let runtime = Runtime::try_init("embedded_roudi")?;
+
+    assert!(matches!(roudi.shutdown(), Err(IceoryxError::RouDiInUse)));
+
+    runtime.shutdown()?;
+    roudi.shutdown()?;
+    roudi.shutdown()?;
+
+    let roudi = RouDi::launch(small_config())?;
+    drop(roudi);
+
+    Ok(())
+}
+
+#[test]
+fn launching_roudi_while_roudi_is_running_fails() -> Result<()> {
+    let roudi = RouDi::launch(small_config())?;
+    assert!(matches!(
+        RouDi::launch(small_config()),
+        Err(IceoryxError::RouDiAlreadyRunning)
+    ));
+    drop(roudi);
+
+    let _roudi_environment = RouDiEnvironment::new();
+    assert!(matches!(
+        RouDi::launch(small_config()),
+        Err(IceoryxError::RouDiAlreadyRunning)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn launching_roudi_with_invalid_config_fails() -> Result<()> {
+    assert!(matches!(
+        RouDi::launch(RouDiConfig::new()),
+        Err(IceoryxError::InvalidRouDiConfig { .. })
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::roudi::RouDiConfig;
+use crate::testing::RouDiEnvironment;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+
+use anyhow::{anyhow, Result};
+
+fn is_invalid_config(result: Result<(), IceoryxError>) -> bool {
+    matches!(result, Err(IceoryxError::InvalidRouDiConfig { .. }))
+}
+
+#[test]
+fn default_config_is_valid() -> Result<()> {
+    RouDiConfig::default().validate()?;
+    Ok(())
+}
+
+#[test]
+fn invalid_configs_are_detected() -> Result<()> {
+    assert!(is_invalid_config(RouDiConfig::new().validate()));
+    assert!(is_invalid_config(
+        RouDiConfig::new()
+            .add_mempool(128, 10)
+            .add_segment("root", "root")
+            .validate()
+    ));
+    assert!(is_invalid_config(
+        RouDiConfig::new().add_mempool(0, 10).validate()
+    ));
+    assert!(is_invalid_config(
+        RouDiConfig::new().add_mempool(128, 0).validate()
+    ));
+    assert!(is_invalid_config(
+        RouDiConfig::new()
+            .add_segment("iceoryx_rs_non_existent_group", "root")
+            .add_mempool(128, 10)
+            .validate()
+    ));
+
+    const MAX_NUMBER_OF_MEMPOOLS: u32 = 32;
+    let config = (1..=MAX_NUMBER_OF_MEMPOOLS)
+        .fold(RouDiConfig::new(), |config, i| config.add_mempool(i * 8, 1));
+    config.validate()?;
+    assert!(is_invalid_config(config.add_mempool(1024, 1).validate()));
+
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn config_survives_toml_round_trip() -> Result<()> {
+    let config = RouDiConfig::new()
+        .add_mempool(128, 1000)
+        .add_mempool(1024, 100)
+        .add_segment("readers", "writers")
+        .add_mempool(64 * 1024, 10);
+
+    assert_eq!(RouDiConfig::from_toml(&config.to_toml())?, config);
+    assert_eq!(
+        RouDiConfig::from_toml(&RouDiConfig::default().to_toml())?,
+        RouDiConfig::default()
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn group_names_with_special_characters_survive_toml_round_trip() -> Result<()> {
+    let config = RouDiConfig::new()
+        .add_segment("quoted \"readers\"", "back\\slash # writers")
+        .add_mempool(128, 10);
+
+    assert_eq!(RouDiConfig::from_toml(&config.to_toml())?, config);
+
+    let config = RouDiConfig::from_toml(
+        r#"
+        [general]
+        version = 1
+
+        [[segment]]
+        reader = "quoted \"readers\""
+        writer = 'back\slash # writers'
+
+        [[segment.mempool]]
+        size = 128
+        count = 10
+        "#,
+    )?;
+    assert_eq!(
+        config,
+        RouDiConfig::new()
+            .add_segment("quoted \"readers\"", "back\\slash # writers")
+            .add_mempool(128, 10)
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn config_file_of_iox_roudi_can_be_parsed() -> Result<()> {
+    let config = RouDiConfig::from_toml(
+        r#"
+        # config for the sensor fusion
+        [general]
+        version = 1
+
+        [[segment]]
+        reader = "sensor_readers" # only these can read
+        writer = "sensor_writers"
+
+        [[segment.mempool]]
+        size = 1_024
+        count = 100
+
+        [[segment.mempool]]
+        size = 131072
+        count = 10
+        "#,
+    )?;
+
+    let expected_config = RouDiConfig::new()
+        .add_segment("sensor_readers", "sensor_writers")
+        .add_mempool(1024, 100)
+        .add_mempool(128 * 1024, 10);
+    assert_eq!(config, expected_config);
+
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn invalid_config_files_are_rejected() -> Result<()> {
+    let is_invalid = |toml: &str| {
+        matches!(
+            RouDiConfig::from_toml(toml),
+            Err(IceoryxError::InvalidRouDiConfig { .. })
+        )
+    };
+
+    const MEMPOOL: &str = "[[segment]]\n[[segment.mempool]]\nsize = 128\ncount = 10\n";
+
+    assert!(!is_invalid(&format!("[general]\nversion = 1\n{}", MEMPOOL)));
+    assert!(is_invalid(MEMPOOL));
+    assert!(is_invalid(&format!("[general]\nversion = 2\n{}", MEMPOOL)));
+    assert!(is_invalid("[general]\nversion = 1\n"));
+    assert!(is_invalid("[general]\nversion = 1\n[[segment]]\n"));
+    assert!(is_invalid(
+        "[general]\nversion = 1\n[[segment]]\n[[segment.mempool]]\nsize = 128\n"
+    ));
+    assert!(is_invalid(
+        "[general]\nversion = 1\n[[segment]]\n[[segment.mempool]]\ncount = 10\n"
+    ));
+    assert!(is_invalid(
+        "[general]\nversion = 1\n[[segment]]\n[[segment.mempool]]\nsize = -128\ncount = 10\n"
+    ));
+    assert!(is_invalid(
+        "[general]\nversion = 1\n[[segment]]\nreader = root\n[[segment.mempool]]\nsize = 128\ncount = 10\n"
+    ));
+    assert!(is_invalid(&format!("[general]\nversion\n{}", MEMPOOL)));
+
+    // unknown tables and keys are rejected
+    assert!(is_invalid(&format!(
+        "[general]\nversion = 1\n{}[unknown]\nfoo = 1\n",
+        MEMPOOL
+    )));
+    assert!(is_invalid(&format!(
+        "[general]\nversion = 1\nfoo = 1\n{}",
+        MEMPOOL
+    )));
+    assert!(is_invalid(
+        "[general]\nversion = 1\n[[segment]]\nreaders = \"root\"\n[[segment.mempool]]\nsize = 128\ncount = 10\n"
+    ));
+    assert!(is_invalid(&format!(
+        "[general]\nversion = 1\n{}alignment = 8\n",
+        MEMPOOL
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn roudi_environment_with_small_mempool_gets_exhausted() -> Result<()> {
+    const CHUNK_COUNT: u32 = 3;
+    let config = RouDiConfig::new().add_mempool(128, CHUNK_COUNT);
+    let _roudi = RouDiEnvironment::with_config(&config)?;
+
+    Runtime::init("roudi_config");
+
+    let publisher = PublisherBuilder::<u64>::new("Test", "RouDiConfig", "Counter").create()?;
+
+    let mut samples = Vec::new();
+    for _ in 0..CHUNK_COUNT {
+        samples.push(publisher.loan()?);
+    }
+    let result = publisher.loan();
+    match result {
+        Err(IceoryxError::MempoolsExhausted { .. }) => Ok(()),
+        Err(e) => Err(e.into()),
+        Ok(_) => Err(anyhow!("Loaning samples did not fail")),
+    }
+}
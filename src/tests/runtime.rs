@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::testing::RouDiEnvironment;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+
+use std::time::Duration;
+
+#[test]
+fn try_init_registers_at_roudi() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    let runtime = Runtime::try_init("runtime")?;
+    assert_eq!(runtime.app_name(), "runtime");
+
+    Ok(())
+}
+
+#[test]
+fn try_init_with_invalid_name_fails() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    let too_long_name = "a".repeat(101);
+    for name in [
+        "",
+        "/runtime",
+        "run/time",
+        "run time",
+        "runtime.",
+        "..",
+        &too_long_name,
+    ] {
+        assert!(
+            matches!(
+                Runtime::try_init(name),
+                Err(IceoryxError::InvalidName { name: n }) if n == name
+            ),
+            "name: '{}'",
+            name
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn try_init_without_roudi_fails_after_timeout() -> Result<()> {
+    const TIMEOUT: Duration = Duration::from_millis(200);
+
+    assert!(matches!(
+        Runtime::try_init_with_timeout("runtime", TIMEOUT),
+        Err(IceoryxError::RouDiNotAvailable { timeout: TIMEOUT })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn try_init_with_already_registered_name_fails() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("runtime");
+
+    assert!(matches!(
+        Runtime::try_init("runtime"),
+        Err(IceoryxError::NameAlreadyRegistered { name }) if name == "runtime"
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn dropping_the_runtime_handle_allows_to_init_again() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    let runtime = Runtime::try_init("runtime")?;
+    assert!(matches!(
+        Runtime::try_init("another_runtime"),
+        Err(IceoryxError::RuntimeAlreadyInitialized)
+    ));
+    drop(runtime);
+
+    let runtime = Runtime::try_init("another_runtime")?;
+    drop(runtime);
+
+    let runtime = Runtime::try_init("runtime")?;
+    assert_eq!(runtime.app_name(), "runtime");
+
+    Ok(())
+}
+
+#[test]
+fn ports_of_a_reinitialized_runtime_can_communicate() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    for app_name in ["runtime", "another_runtime"] {
+        let runtime = Runtime::try_init(app_name)?;
+
+        let (subscriber, sample_receive_token) =
+            SubscriberBuilder::<u32>::new("Test", "Runtime", "Counter").create()?;
+        let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+        let publisher = PublisherBuilder::<u32>::new("Test", "Runtime", "Counter").create()?;
+
+        const PAYLOAD: u32 = 42;
+        let mut sample = publisher.loan()?;
+        *sample = PAYLOAD;
+        publisher.publish(sample);
+
+        match sample_receiver.take() {
+            Some(sample) => assert_eq!(*sample, PAYLOAD),
+            _ => return Err(anyhow!("Could not read sample")),
+        }
+
+        drop(sample_receiver);
+        drop(subscriber);
+        drop(publisher);
+        drop(runtime);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn shutdown_with_alive_ports_is_deferred() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    let runtime = Runtime::try_init("runtime")?;
+    let publisher = PublisherBuilder::<u32>::new("Test", "Runtime", "Counter").create()?;
+
+    assert!(matches!(
+        runtime.shutdown(),
+        Err(IceoryxError::RuntimeInUse { count: 1, .. })
+    ));
+    assert!(matches!(
+        Runtime::try_init("another_runtime"),
+        Err(IceoryxError::RuntimeAlreadyInitialized)
+    ));
+
+    drop(publisher);
+    Runtime::try_init("another_runtime")?.shutdown()?;
+
+    Ok(())
+}
+
+#[test]
+fn samples_keep_the_runtime_alive() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    let runtime = Runtime::try_init("runtime")?;
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "Runtime", "Counter").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+    let publisher = PublisherBuilder::<u32>::new("Test", "Runtime", "Counter").create()?;
+
+    const PAYLOAD: u32 = 13;
+    let mut sample = publisher.loan()?;
+    *sample = PAYLOAD;
+    publisher.publish(sample);
+    let sample = sample_receiver
+        .take()
+        .ok_or_else(|| anyhow!("Could not read sample"))?;
+
+    drop(publisher);
+    drop(sample_receiver);
+    drop(subscriber);
+    drop(runtime);
+
+    assert!(matches!(
+        Runtime::try_init("another_runtime"),
+        Err(IceoryxError::RuntimeAlreadyInitialized)
+    ));
+    assert_eq!(*sample, PAYLOAD);
+
+    drop(sample);
+    Runtime::try_init("another_runtime")?;
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::testing::RouDiEnvironment;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SampleReader;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+
+use std::io::{BufRead, ErrorKind, Read, Write};
+
+#[test]
+fn written_bytes_are_read_with_std_io() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("sample_io");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<[u8]>::new("Test", "SampleIo", "Lines").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<[u8]>::new("Test", "SampleIo", "Lines").create()?;
+    let mut writer = publisher.loan_writer(16)?;
+    assert_eq!(writer.capacity(), 16);
+    assert!(writer.is_empty());
+
+    writeln!(writer, "lidar")?;
+    writeln!(writer, "radar")?;
+    assert_eq!(writer.len(), 12);
+    assert_eq!(writer.remaining(), 4);
+    assert_eq!(writer.written(), b"lidar\nradar\n");
+
+    match writer.write_all(b"camera") {
+        Err(e) if e.kind() == ErrorKind::WriteZero => (),
+        Err(e) => return Err(e.into()),
+        Ok(_) => return Err(anyhow!("Wrote beyond the capacity")),
+    }
+    assert_eq!(writer.remaining(), 0);
+    publisher.publish(writer.into_sample());
+
+    let sample = sample_receiver
+        .take()
+        .ok_or_else(|| anyhow!("No sample received"))?;
+    assert_eq!(&*sample, b"lidar\nradar\ncame");
+
+    let mut reader = SampleReader::new(sample);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    assert_eq!(line, "lidar\n");
+    assert_eq!(reader.position(), 6);
+
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest)?;
+    assert_eq!(rest, b"radar\ncame");
+    assert!(reader.unread().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn unwritten_bytes_are_set_to_zero() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("sample_io");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<[u8]>::new("Test", "SampleIo", "Partial").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<[u8]>::new("Test", "SampleIo", "Partial").create()?;
+    let mut writer = publisher.loan_writer(8)?;
+    writer.write_all(&[0xff; 3])?;
+    publisher.publish(writer.into_sample());
+
+    let sample = sample_receiver
+        .take()
+        .ok_or_else(|| anyhow!("No sample received"))?;
+    assert_eq!(&*sample, [0xff, 0xff, 0xff, 0, 0, 0, 0, 0]);
+
+    Ok(())
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn written_bytes_are_read_with_bytes_buf() -> Result<()> {
+    use bytes::{Buf, BufMut};
+
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("sample_io");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<[u8]>::new("Test", "SampleIo", "Counter").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<[u8]>::new("Test", "SampleIo", "Counter").create()?;
+    let mut writer = publisher.loan_writer(14)?;
+    writer.put_u32_le(3773);
+    writer.put_u64(42);
+    writer.put_slice(b"ok");
+    assert_eq!(writer.remaining_mut(), 0);
+    publisher.publish(writer.into_sample());
+
+    let sample = sample_receiver
+        .take()
+        .ok_or_else(|| anyhow!("No sample received"))?;
+    let mut reader = SampleReader::new(sample);
+    assert_eq!(reader.get_u32_le(), 3773);
+    assert_eq!(reader.get_u64(), 42);
+    assert_eq!(reader.chunk(), b"ok");
+    reader.advance(2);
+    assert!(!reader.has_remaining());
+
+    Ok(())
+}
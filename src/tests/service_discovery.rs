@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::introspection::ServiceDescription;
+use crate::testing::RouDiEnvironment;
+use crate::MessagingPattern;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::ServerBuilder;
+use crate::ServiceDiscovery;
+
+use anyhow::Result;
+
+use std::time::Duration;
+
+fn service_description(service: &str, instance: &str, event: &str) -> ServiceDescription {
+    ServiceDescription {
+        service_id: service.to_string(),
+        instance_id: instance.to_string(),
+        event_id: event.to_string(),
+    }
+}
+
+#[test]
+fn find_service_with_wildcards() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("service_discovery");
+
+    let service_discovery = ServiceDiscovery::new()?;
+    assert!(!service_discovery.has_changed());
+
+    let _publisher_a = PublisherBuilder::<u32>::new("Radar", "FrontLeft", "Counter").create()?;
+    let _publisher_b = PublisherBuilder::<u32>::new("Radar", "FrontRight", "Counter").create()?;
+    let _publisher_c = PublisherBuilder::<u32>::new("Lidar", "FrontLeft", "Counter").create()?;
+    let _server = ServerBuilder::<u32, u32>::new("Radar", "FrontLeft", "Reset").create()?;
+
+    assert!(service_discovery.wait_for_change(Duration::from_secs(2)));
+
+    let mut services = service_discovery.find_service(Some("Radar"), None, Some("Counter"))?;
+    services.sort();
+    assert_eq!(
+        services,
+        vec![
+            service_description("Radar", "FrontLeft", "Counter"),
+            service_description("Radar", "FrontRight", "Counter"),
+        ]
+    );
+
+    let services = service_discovery.find_service(None, Some("FrontLeft"), None)?;
+    assert_eq!(services.len(), 2);
+    assert!(services.contains(&service_description("Lidar", "FrontLeft", "Counter")));
+
+    let services = service_discovery.find_service(Some("Sonar"), None, None)?;
+    assert!(services.is_empty());
+
+    let services =
+        service_discovery.find_service_with_pattern(None, None, None, MessagingPattern::ReqRes)?;
+    assert_eq!(
+        services,
+        vec![service_description("Radar", "FrontLeft", "Reset")]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::introspection::ServiceDescription;
+use crate::testing::RouDiEnvironment;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::ServiceDiscovery;
+use crate::ServiceName;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+
+use std::convert::TryFrom;
+
+fn is_invalid_service_name<T>(result: Result<T, IceoryxError>) -> bool {
+    matches!(result, Err(IceoryxError::InvalidServiceName { .. }))
+}
+
+#[test]
+fn service_name_can_be_created_from_parts_and_string() -> Result<()> {
+    let service_name = ServiceName::new("Radar", "FrontLeft", "Counter")?;
+    assert_eq!(service_name.service(), "Radar");
+    assert_eq!(service_name.instance(), "FrontLeft");
+    assert_eq!(service_name.event(), "Counter");
+
+    assert_eq!(service_name.to_string(), "Radar/FrontLeft/Counter");
+    assert_eq!(
+        "Radar/FrontLeft/Counter".parse::<ServiceName>()?,
+        service_name
+    );
+    assert_eq!(
+        ServiceName::try_from("Radar/FrontLeft/Counter")?,
+        service_name
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_service_names_are_rejected() -> Result<()> {
+    let max_length_part = "a".repeat(ServiceName::MAX_PART_LENGTH);
+    ServiceName::new(&max_length_part, "instance", "event")?;
+
+    let too_long_part = "a".repeat(ServiceName::MAX_PART_LENGTH + 1);
+    assert!(is_invalid_service_name(ServiceName::new(
+        &too_long_part,
+        "instance",
+        "event"
+    )));
+    assert!(is_invalid_service_name(ServiceName::new(
+        "service", "", "event"
+    )));
+    assert!(is_invalid_service_name(ServiceName::new(
+        "service", "instance", "ev\0nt"
+    )));
+    assert!(is_invalid_service_name(ServiceName::new(
+        "service",
+        "in\nstance",
+        "event"
+    )));
+    assert!(is_invalid_service_name(ServiceName::new(
+        "ser/vice", "instance", "event"
+    )));
+
+    assert!(is_invalid_service_name(
+        "service/instance".parse::<ServiceName>()
+    ));
+    assert!(is_invalid_service_name(
+        "service/instance/event/".parse::<ServiceName>()
+    ));
+    assert!(is_invalid_service_name(
+        "service//event".parse::<ServiceName>()
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn builders_reject_invalid_service_names() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("service_name");
+
+    // without the validation the names would be truncated and both end up on the same topic
+    let prefix = "a".repeat(ServiceName::MAX_PART_LENGTH);
+    let long_name = format!("{}b", prefix);
+    let other_long_name = format!("{}c", prefix);
+
+    assert!(is_invalid_service_name(
+        PublisherBuilder::<u32>::new(&long_name, "instance", "event").create()
+    ));
+    assert!(is_invalid_service_name(
+        SubscriberBuilder::<u32>::new(&other_long_name, "instance", "event").create()
+    ));
+    assert!(is_invalid_service_name(
+        PublisherBuilder::<u32>::new("service", "inst\0ance", "event").create_without_offer()
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn service_name_is_accepted_by_builders_and_introspection_types() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("service_name");
+
+    let service_name: ServiceName = "Radar/FrontLeft/Counter".parse()?;
+    let publisher = PublisherBuilder::<u32>::from_service_name(&service_name).create()?;
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::from_service_name(&service_name).create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let mut sample = publisher.loan()?;
+    *sample = 42;
+    publisher.publish(sample);
+    let sample = sample_receiver
+        .take()
+        .ok_or_else(|| anyhow!("No sample received"))?;
+    assert_eq!(*sample, 42);
+
+    let service_discovery = ServiceDiscovery::new()?;
+    let services = service_discovery.find_service(Some("Radar"), None, None)?;
+    assert_eq!(services, vec![ServiceDescription::from(&service_name)]);
+    assert_eq!(ServiceName::try_from(&services[0])?, service_name);
+    assert!(services[0] == service_name);
+
+    Ok(())
+}
+
+#[test]
+fn service_discovery_rejects_invalid_search_pattern() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("service_name");
+
+    let service_discovery = ServiceDiscovery::new()?;
+    assert!(is_invalid_service_name(service_discovery.find_service(
+        Some("Ra\0dar"),
+        None,
+        None
+    )));
+    assert!(is_invalid_service_name(service_discovery.find_service(
+        None,
+        Some(""),
+        None
+    )));
+    match service_discovery.find_service(None, None, Some("Coun/ter")) {
+        Err(IceoryxError::InvalidServiceName { name, .. }) => assert_eq!(name, "*/*/Coun/ter"),
+        Err(e) => return Err(e.into()),
+        Ok(_) => return Err(anyhow!("Invalid search pattern was accepted")),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::marker::ShmSend;
+use crate::testing::RouDiEnvironment;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+
+use std::marker::PhantomData;
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ShmSend)]
+enum Unit {
+    Meter,
+    Millimeter,
+}
+
+#[repr(C)]
+#[derive(ShmSend)]
+struct Measurement<T, const N: usize> {
+    values: [T; N],
+    unit: Unit,
+    phantom: PhantomData<T>,
+}
+
+#[repr(transparent)]
+#[derive(ShmSend)]
+struct Wrapper(Measurement<f32, 3>);
+
+#[test]
+fn derived_types_can_be_transferred() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("shm_send_derive");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<Wrapper>::new("Test", "ShmSendDerive", "Measurement").create()?;
+
+    let publisher =
+        PublisherBuilder::<Wrapper>::new("Test", "ShmSendDerive", "Measurement").create()?;
+
+    let mut sample = publisher.loan_uninit()?;
+    sample.write(Wrapper(Measurement {
+        values: [1.0, 2.0, 3.0],
+        unit: Unit::Millimeter,
+        phantom: PhantomData,
+    }));
+    publisher.publish(unsafe { sample.assume_init() });
+
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    match sample_receiver.take() {
+        Some(sample) => {
+            assert_eq!(sample.0.values, [1.0, 2.0, 3.0]);
+            assert_eq!(sample.0.unit, Unit::Millimeter);
+            assert_ne!(sample.0.unit, Unit::Meter);
+        }
+        _ => return Err(anyhow!("Could not read sample")),
+    }
+
+    Ok(())
+}
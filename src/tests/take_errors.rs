@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::testing::RouDiEnvironment;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+
+#[test]
+fn empty_receiver_queue_is_not_an_error() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("take_errors");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "TakeErrors", "Counter").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    assert!(sample_receiver.try_take()?.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn payload_size_mismatch_is_reported() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("take_errors");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "TakeErrors", "Counter").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<u64>::new("Test", "TakeErrors", "Counter").create()?;
+    publisher.publish(publisher.loan()?);
+
+    assert!(matches!(
+        sample_receiver.try_take(),
+        Err(IceoryxError::PayloadSizeMismatch {
+            expected: 4,
+            received: 8
+        })
+    ));
+    assert!(!sample_receiver.has_data());
+
+    Ok(())
+}
+
+#[test]
+fn alignment_mismatch_is_reported() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("take_errors");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<[u64]>::new("Test", "TakeErrors", "Slice").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<[u8]>::new("Test", "TakeErrors", "Slice").create()?;
+    publisher.publish(publisher.loan_slice(16)?);
+
+    assert!(matches!(
+        sample_receiver.try_take(),
+        Err(IceoryxError::AlignmentMismatch {
+            min_required: 8,
+            received: 1
+        })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn user_header_size_mismatch_is_reported() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("take_errors");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "TakeErrors", "Counter")
+            .with_user_header::<u64>()
+            .create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "TakeErrors", "Counter")
+        .with_user_header::<u32>()
+        .create()?;
+    publisher.publish(publisher.loan()?);
+
+    assert!(matches!(
+        sample_receiver.try_take(),
+        Err(IceoryxError::UserHeaderSizeMismatch {
+            expected: 8,
+            received: 4
+        })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn holding_too_many_samples_is_reported() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("take_errors");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "TakeErrors", "Counter").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "TakeErrors", "Counter").create()?;
+
+    const MAX_ITERATIONS: usize = 1000;
+    let mut samples = Vec::new();
+    for _ in 0..MAX_ITERATIONS {
+        publisher.publish(publisher.loan()?);
+        match sample_receiver.try_take() {
+            Ok(Some(sample)) => samples.push(sample),
+            Err(IceoryxError::TooManySamplesHoldInParallel) => break,
+            _ => return Err(anyhow!("Unexpected result of 'try_take'")),
+        }
+    }
+    assert!(samples.len() < MAX_ITERATIONS);
+
+    // the sample which could not be taken is discarded
+    assert!(!sample_receiver.has_data());
+
+    samples.pop();
+    publisher.publish(publisher.loan()?);
+    assert!(sample_receiver.try_take()?.is_some());
+
+    Ok(())
+}
+
+#[test]
+fn take_does_not_drain_the_queue_when_too_many_samples_are_held() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("take_errors");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "TakeErrors", "Counter").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "TakeErrors", "Counter").create()?;
+
+    const MAX_ITERATIONS: usize = 1000;
+    let mut samples = Vec::new();
+    for _ in 0..MAX_ITERATIONS {
+        publisher.publish(publisher.loan()?);
+        match sample_receiver.try_take() {
+            Ok(Some(sample)) => samples.push(sample),
+            Err(IceoryxError::TooManySamplesHoldInParallel) => break,
+            _ => return Err(anyhow!("Unexpected result of 'try_take'")),
+        }
+    }
+    assert!(samples.len() < MAX_ITERATIONS);
+
+    publisher.publish(publisher.loan()?);
+    publisher.publish(publisher.loan()?);
+
+    // only the first sample is discarded and the remaining sample stays in the queue
+    assert!(sample_receiver.take().is_none());
+    assert!(sample_receiver.has_data());
+
+    samples.pop();
+    assert!(sample_receiver.take().is_some());
+    assert!(!sample_receiver.has_data());
+
+    Ok(())
+}
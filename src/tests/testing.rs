@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::roudi::RouDiConfig;
+use crate::testing::RouDiEnvironment;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SubscribeState;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+
+use std::time::{Duration, Instant};
+
+#[test]
+fn triggered_discovery_loop_processes_subscription_requests() -> Result<()> {
+    let roudi = RouDiEnvironment::new();
+
+    Runtime::init("testing");
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "Testing", "Discovery").create()?;
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "Testing", "Discovery")
+            .create_without_subscribe()?
+            .subscribe();
+
+    roudi.trigger_discovery_loop();
+    assert_eq!(subscriber.subscription_state(), SubscribeState::Subscribed);
+    assert!(publisher.has_subscribers());
+
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+    let subscriber = subscriber.unsubscribe(sample_receiver);
+    roudi.trigger_discovery_loop();
+    assert_eq!(
+        subscriber.subscription_state(),
+        SubscribeState::NotSubscribed
+    );
+    assert!(!publisher.has_subscribers());
+
+    Ok(())
+}
+
+#[test]
+fn inter_op_wait_blocks_for_the_waiting_time() -> Result<()> {
+    let mut roudi = RouDiEnvironment::new();
+
+    const WAITING_TIME: Duration = Duration::from_millis(20);
+    roudi.set_inter_op_waiting_time(WAITING_TIME);
+
+    let start = Instant::now();
+    roudi.inter_op_wait();
+    assert!(start.elapsed() >= WAITING_TIME);
+
+    Ok(())
+}
+
+#[test]
+fn roudi_environment_with_custom_config_can_be_created() -> Result<()> {
+    let config = RouDiConfig::new().add_mempool(128, 10);
+    let _roudi = RouDiEnvironment::with_config(&config)?;
+
+    match RouDiEnvironment::with_config(&config) {
+        Err(IceoryxError::RouDiAlreadyRunning) => (),
+        Err(e) => return Err(e.into()),
+        Ok(_) => return Err(anyhow!("Second RouDiEnvironment could be created")),
+    }
+
+    match RouDiEnvironment::with_config(&RouDiConfig::new()) {
+        Err(IceoryxError::InvalidRouDiConfig { .. }) => Ok(()),
+        Err(e) => Err(e.into()),
+        Ok(_) => Err(anyhow!(
+            "RouDiEnvironment with invalid config could be created"
+        )),
+    }
+}
@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::marker::ShmSend;
+use crate::testing::RouDiEnvironment;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SubscriberBuilder;
+use crate::TypeIdentity;
+
+use anyhow::{anyhow, Result};
+
+#[repr(C)]
+#[derive(Default, ShmSend)]
+struct Metadata {
+    timestamp: u64,
+}
+
+#[repr(C)]
+#[derive(Default, ShmSend)]
+#[shm_send(type_name = "Position")]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+#[derive(Default, ShmSend)]
+struct Velocity {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+#[derive(Default, ShmSend)]
+struct Point<T> {
+    x: T,
+    y: T,
+}
+
+#[repr(C)]
+struct Unnamed {
+    x: f32,
+    y: f32,
+}
+
+unsafe impl ShmSend for Unnamed {}
+
+#[test]
+fn type_identity_depends_on_name_size_and_alignment() {
+    let position = TypeIdentity::of::<Position>();
+    let velocity = TypeIdentity::of::<Velocity>();
+
+    assert_eq!(position.size(), 8);
+    assert_eq!(position.alignment(), 4);
+    assert_ne!(position.type_hash(), velocity.type_hash());
+    assert!(!position.is_compatible(&velocity));
+
+    // the FNV-1a hash of "u64" which must also be computed by C++ applications
+    assert_eq!(TypeIdentity::of::<u64>().type_hash(), 0x4d35_d319_3e8d_66f2);
+    assert!(!TypeIdentity::of::<u64>().is_compatible(&TypeIdentity::of::<i64>()));
+
+    // an unknown type name is compatible with any type of the same size and alignment
+    assert_eq!(TypeIdentity::of::<Unnamed>().type_hash(), 0);
+    assert_eq!(TypeIdentity::of::<[Unnamed; 2]>().type_hash(), 0);
+    assert!(TypeIdentity::of::<Unnamed>().is_compatible(&position));
+    assert!(!TypeIdentity::of::<[f32; 2]>().is_compatible(&position));
+}
+
+#[test]
+fn type_names_of_generic_types_contain_the_generic_arguments() {
+    assert_eq!(<[u32; 2]>::TYPE_NAME, "[u32; 2]");
+    assert_eq!(<[u32]>::TYPE_NAME, "[u32]");
+    assert_eq!(Option::<u8>::TYPE_NAME, "Option<u8>");
+    assert_eq!(Result::<u8, i8>::TYPE_NAME, "Result<u8, i8>");
+    assert_eq!(<(u8, Option<f32>)>::TYPE_NAME, "(u8, Option<f32>)");
+    assert_eq!(Point::<f32>::TYPE_NAME, "Point<f32>");
+    assert_eq!(Point::<[i16; 4]>::TYPE_NAME, "Point<[i16; 4]>");
+    assert_eq!(Point::<Unnamed>::TYPE_NAME, "");
+
+    assert_ne!(
+        TypeIdentity::of::<Point<f32>>(),
+        TypeIdentity::of::<Point<u32>>()
+    );
+    assert_ne!(
+        TypeIdentity::of::<Option<u32>>(),
+        TypeIdentity::of::<Option<i32>>()
+    );
+}
+
+#[test]
+fn samples_with_matching_type_identity_are_received() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("type_identity");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<Position>::new("Test", "TypeIdentity", "Position")
+            .with_type_identity()
+            .with_user_header::<Metadata>()
+            .create()?;
+
+    let publisher = PublisherBuilder::<Position>::new("Test", "TypeIdentity", "Position")
+        .with_user_header::<Metadata>()
+        .with_type_identity()
+        .create()?;
+
+    const TIMESTAMP: u64 = 42;
+    let mut sample = publisher.loan()?;
+    sample.user_header_mut().timestamp = TIMESTAMP;
+    sample.x = 13.0;
+    sample.y = 37.0;
+    publisher.publish(sample);
+
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    match sample_receiver.try_take()? {
+        Some(sample) => {
+            assert_eq!(sample.user_header().timestamp, TIMESTAMP);
+            assert_eq!(sample.x, 13.0);
+            assert_eq!(sample.y, 37.0);
+        }
+        _ => return Err(anyhow!("Could not read sample")),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn samples_with_different_type_are_rejected() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("type_identity");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<Position>::new("Test", "TypeIdentity", "Position")
+            .with_type_identity()
+            .create()?;
+
+    let publisher = PublisherBuilder::<Velocity>::new("Test", "TypeIdentity", "Position")
+        .with_type_identity()
+        .create()?;
+
+    publisher.publish(publisher.loan()?);
+    publisher.publish(publisher.loan()?);
+
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    match sample_receiver.try_take() {
+        Err(IceoryxError::TypeMismatch { expected, received }) => {
+            assert_eq!(expected, TypeIdentity::of::<Position>());
+            assert_eq!(received, TypeIdentity::of::<Velocity>());
+        }
+        _ => return Err(anyhow!("Expected a type mismatch")),
+    }
+
+    assert!(sample_receiver.take().is_none());
+    assert!(!sample_receiver.has_data());
+
+    Ok(())
+}
+
+#[test]
+fn samples_without_type_identity_are_rejected() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("type_identity");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<[u32]>::new("Test", "TypeIdentity", "Slice")
+            .with_type_identity()
+            .create()?;
+
+    let publisher = PublisherBuilder::<[u32]>::new("Test", "TypeIdentity", "Slice").create()?;
+
+    publisher.publish(publisher.loan_slice(3)?);
+
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    match sample_receiver.try_take() {
+        Err(IceoryxError::TypeMismatch { expected, received }) => {
+            assert_eq!(expected, TypeIdentity::of::<u32>());
+            assert_eq!(received, TypeIdentity::UNKNOWN);
+        }
+        _ => return Err(anyhow!("Expected a type mismatch")),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn samples_with_different_generic_arguments_are_rejected() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("type_identity");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<Point<f32>>::new("Test", "TypeIdentity", "Point")
+            .with_type_identity()
+            .create()?;
+
+    let publisher = PublisherBuilder::<Point<u32>>::new("Test", "TypeIdentity", "Point")
+        .with_type_identity()
+        .create()?;
+
+    publisher.publish(publisher.loan()?);
+
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    match sample_receiver.try_take() {
+        Err(IceoryxError::TypeMismatch { expected, received }) => {
+            assert_eq!(expected, TypeIdentity::of::<Point<f32>>());
+            assert_eq!(received, TypeIdentity::of::<Point<u32>>());
+        }
+        _ => return Err(anyhow!("Expected a type mismatch")),
+    }
+
+    Ok(())
+}
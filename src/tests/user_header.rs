@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::marker::ShmSend;
+use crate::testing::RouDiEnvironment;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+
+#[repr(C)]
+#[derive(Default)]
+struct Metadata {
+    timestamp: u64,
+    sequence_number: u32,
+}
+
+unsafe impl ShmSend for Metadata {}
+
+#[repr(C, align(16))]
+#[derive(Default)]
+struct OverAlignedHeader {
+    data: u64,
+}
+
+unsafe impl ShmSend for OverAlignedHeader {}
+
+#[test]
+fn user_header_is_transferred_with_the_payload() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("user_header");
+
+    let (subscriber, sample_receive_token) =
+        SubscriberBuilder::<u32>::new("Test", "UserHeader", "Counter")
+            .with_user_header::<Metadata>()
+            .queue_capacity(5)
+            .create()?;
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "UserHeader", "Counter")
+        .with_user_header::<Metadata>()
+        .create()?;
+
+    let mut sample = publisher.loan()?;
+    assert_eq!(sample.user_header().timestamp, 0);
+
+    const TIMESTAMP: u64 = 13;
+    const SEQUENCE_NUMBER: u32 = 37;
+    const PAYLOAD: u32 = 42;
+    sample.user_header_mut().timestamp = TIMESTAMP;
+    sample.user_header_mut().sequence_number = SEQUENCE_NUMBER;
+    *sample = PAYLOAD;
+    publisher.publish(sample);
+
+    let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+
+    match sample_receiver.take() {
+        Some(sample) => {
+            assert_eq!(*sample, PAYLOAD);
+            assert_eq!(sample.user_header().timestamp, TIMESTAMP);
+            assert_eq!(sample.user_header().sequence_number, SEQUENCE_NUMBER);
+        }
+        _ => return Err(anyhow!("Could not read sample")),
+    }
+
+    publisher.stop_offer();
+    subscriber.unsubscribe(sample_receiver);
+
+    Ok(())
+}
+
+#[test]
+fn samples_with_mismatching_user_header_are_discarded() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("user_header");
+
+    let (subscriber_with_header, sample_receive_token_with_header) =
+        SubscriberBuilder::<u32>::new("Test", "UserHeader", "Counter")
+            .with_user_header::<Metadata>()
+            .create()?;
+    let (subscriber_without_header, sample_receive_token_without_header) =
+        SubscriberBuilder::<u32>::new("Test", "UserHeader", "Counter").create()?;
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "UserHeader", "Counter").create()?;
+
+    const PAYLOAD: u32 = 42;
+    let mut sample = publisher.loan()?;
+    *sample = PAYLOAD;
+    publisher.publish(sample);
+
+    let sample_receiver_with_header =
+        subscriber_with_header.get_sample_receiver(sample_receive_token_with_header);
+    let sample_receiver_without_header =
+        subscriber_without_header.get_sample_receiver(sample_receive_token_without_header);
+
+    assert!(sample_receiver_with_header.has_data());
+    assert_eq!(sample_receiver_with_header.discarded_samples(), 0);
+    assert!(sample_receiver_with_header.take().is_none());
+    assert!(!sample_receiver_with_header.has_data());
+    assert_eq!(sample_receiver_with_header.discarded_samples(), 1);
+
+    match sample_receiver_without_header.take() {
+        Some(sample) => assert_eq!(*sample, PAYLOAD),
+        _ => return Err(anyhow!("Could not read sample")),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn user_header_with_too_large_alignment_fails() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("user_header");
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "UserHeader", "Counter")
+        .with_user_header::<OverAlignedHeader>()
+        .create();
+    assert!(matches!(
+        publisher,
+        Err(IceoryxError::InvalidUserHeaderAlignment {
+            requested: 16,
+            max_supported: 8
+        })
+    ));
+
+    let subscriber = SubscriberBuilder::<u32>::new("Test", "UserHeader", "Counter")
+        .with_user_header::<OverAlignedHeader>()
+        .create();
+    assert!(matches!(
+        subscriber,
+        Err(IceoryxError::InvalidUserHeaderAlignment { .. })
+    ));
+
+    Ok(())
+}
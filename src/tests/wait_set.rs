@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::mt;
+use crate::st;
+use crate::testing::RouDiEnvironment;
+use crate::IceoryxError;
+use crate::PublisherBuilder;
+use crate::Runtime;
+use crate::SampleReceiverWaitState;
+use crate::SubscriberBuilder;
+
+use anyhow::{anyhow, Result};
+
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn wait_set_returns_notification_ids_of_ready_sample_receivers() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("wait_set");
+
+    let (subscriber_a, token_a) = SubscriberBuilder::<u32>::new("Test", "WaitSet", "A").create()?;
+    let (subscriber_b, token_b) = SubscriberBuilder::<u32>::new("Test", "WaitSet", "B").create()?;
+    let sample_receiver_a = subscriber_a.get_sample_receiver(token_a);
+    let sample_receiver_b = subscriber_b.get_sample_receiver(token_b);
+
+    let publisher = PublisherBuilder::<u32>::new("Test", "WaitSet", "B").create()?;
+
+    let wait_set = st::WaitSet::new();
+    let attachment_a = wait_set.attach_sample_receiver(&sample_receiver_a, 1)?;
+    let attachment_b = wait_set.attach_sample_receiver(&sample_receiver_b, 2)?;
+
+    assert!(wait_set.timed_wait(Duration::from_millis(10)).is_empty());
+
+    let mut sample = publisher.loan()?;
+    *sample = 42;
+    publisher.publish(sample);
+
+    assert_eq!(wait_set.timed_wait(Duration::from_secs(2)), vec![2]);
+    match sample_receiver_b.take() {
+        Some(sample) => assert_eq!(*sample, 42),
+        _ => return Err(anyhow!("Could not read sample")),
+    }
+    assert!(wait_set.timed_wait(Duration::from_millis(10)).is_empty());
+
+    drop(attachment_a);
+    drop(attachment_b);
+
+    let mut sample = publisher.loan()?;
+    *sample = 73;
+    publisher.publish(sample);
+
+    assert!(matches!(
+        sample_receiver_b.wait_for_samples(Duration::from_secs(2)),
+        SampleReceiverWaitState::SamplesAvailable
+    ));
+
+    publisher.stop_offer();
+    subscriber_a.unsubscribe(sample_receiver_a);
+    subscriber_b.unsubscribe(sample_receiver_b);
+
+    Ok(())
+}
+
+#[test]
+fn sample_receiver_can_only_be_attached_once() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("wait_set");
+
+    let (subscriber, token) = SubscriberBuilder::<u32>::new("Test", "WaitSet", "A").create()?;
+    let sample_receiver = subscriber.get_sample_receiver(token);
+
+    let wait_set = st::WaitSet::new();
+    let other_wait_set = st::WaitSet::new();
+    let attachment = wait_set.attach_sample_receiver(&sample_receiver, 1)?;
+
+    assert!(matches!(
+        other_wait_set.attach_sample_receiver(&sample_receiver, 1),
+        Err(IceoryxError::AlreadyAttached)
+    ));
+
+    drop(attachment);
+    let _attachment = other_wait_set.attach_sample_receiver(&sample_receiver, 1)?;
+
+    Ok(())
+}
+
+#[test]
+fn user_trigger_wakes_up_wait_set_from_other_thread() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("wait_set");
+
+    let wait_set = mt::WaitSet::new();
+    let trigger = wait_set.attach_user_trigger(42)?;
+    let _idle_trigger = wait_set.attach_user_trigger(13)?;
+
+    let th = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        trigger.trigger();
+    });
+
+    assert_eq!(wait_set.wait(), vec![42]);
+    assert!(wait_set.timed_wait(Duration::from_millis(10)).is_empty());
+
+    th.join().map_err(|_| anyhow!("could not join threads"))?;
+
+    Ok(())
+}
+
+#[test]
+fn wait_set_capacity_is_limited() -> Result<()> {
+    let _roudi = RouDiEnvironment::new();
+
+    Runtime::init("wait_set");
+
+    let wait_set = mt::WaitSet::new();
+    let mut triggers = (0..mt::WaitSet::CAPACITY as u64)
+        .map(|id| wait_set.attach_user_trigger(id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert!(matches!(
+        wait_set.attach_user_trigger(666),
+        Err(IceoryxError::TooManyAttachments)
+    ));
+
+    triggers.pop();
+    let _trigger = wait_set.attach_user_trigger(666)?;
+
+    Ok(())
+}
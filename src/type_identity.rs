@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::marker::ShmSend;
+use crate::IceoryxError;
+
+use std::fmt;
+use std::mem::{align_of, size_of};
+
+/// The identity of the payload type which is transferred with each sample
+///
+/// The type identity is enabled with
+/// [`PublisherBuilder::with_type_identity`](crate::PublisherBuilder::with_type_identity) and
+/// [`SubscriberBuilder::with_type_identity`](crate::SubscriberBuilder::with_type_identity) and detects when
+/// publisher and subscriber use different types for the same service.
+///
+/// The type hash is the 64 bit FNV-1a hash of [`ShmSend::TYPE_NAME`] or 0 if the name is empty. Generic types
+/// include the names of their generic arguments, e.g. `Position<f32>` or `[u32; 4]`. For slices the identity of the
+/// element type is used.
+///
+/// When communicating with C++ applications, the user header starts with this struct, i.e. a `uint64_t` with
+/// the type hash, followed by the size and alignment of the type as `uint64_t`. The user defined header is placed
+/// directly after it.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ShmSend)]
+pub struct TypeIdentity {
+    type_hash: u64,
+    size: u64,
+    alignment: u64,
+}
+
+impl TypeIdentity {
+    /// The identity of samples without type identity
+    pub const UNKNOWN: TypeIdentity = TypeIdentity {
+        type_hash: 0,
+        size: 0,
+        alignment: 0,
+    };
+
+    /// The identity of `T`
+    pub const fn of<T: ShmSend>() -> Self {
+        Self {
+            type_hash: type_hash(T::TYPE_NAME),
+            size: size_of::<T>() as u64,
+            alignment: align_of::<T>() as u64,
+        }
+    }
+
+    /// The hash of the type name or 0 if the name is unknown
+    pub fn type_hash(&self) -> u64 {
+        self.type_hash
+    }
+
+    /// The size of the type
+    pub fn size(&self) -> usize {
+        self.size as usize
+    }
+
+    /// The alignment of the type
+    pub fn alignment(&self) -> usize {
+        self.alignment as usize
+    }
+
+    /// Checks whether samples with the `other` identity can be interpreted as this type
+    ///
+    /// Size and alignment must always match. The type hashes are only compared if both are known.
+    pub fn is_compatible(&self, other: &TypeIdentity) -> bool {
+        let is_hash_compatible =
+            self.type_hash == 0 || other.type_hash == 0 || self.type_hash == other.type_hash;
+        is_hash_compatible && self.size == other.size && self.alignment == other.alignment
+    }
+}
+
+impl fmt::Display for TypeIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == Self::UNKNOWN {
+            return write!(f, "unknown type");
+        }
+        write!(
+            f,
+            "type hash {:#018x} with size {} and alignment {}",
+            self.type_hash, self.size, self.alignment
+        )
+    }
+}
+
+/// The 64 bit FNV-1a hash of the type name
+const fn type_hash(type_name: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let bytes = type_name.as_bytes();
+    if bytes.is_empty() {
+        return 0;
+    }
+
+    let mut hash = OFFSET_BASIS;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(PRIME);
+        i += 1;
+    }
+    hash
+}
+
+/// The user header of a chunk with type identity
+#[repr(C)]
+pub(crate) struct TypeIdentityHeader<H> {
+    type_identity: TypeIdentity,
+    user_header: H,
+}
+
+impl<H> TypeIdentityHeader<H> {
+    /// The offset of the user defined header from the start of the user header of the chunk
+    pub(crate) const USER_HEADER_OFFSET: usize = std::mem::offset_of!(Self, user_header);
+}
+
+/// Writes the type identity to the user header of the chunk
+///
+/// # Safety
+///
+/// The chunk must have been allocated with a `TypeIdentityHeader` as user header.
+pub(crate) unsafe fn write_type_identity(
+    chunk_header: &ffi::ChunkHeader,
+    type_identity: TypeIdentity,
+) {
+    let ptr = chunk_header.user_header() as *mut TypeIdentity;
+    debug_assert!(!ptr.is_null(), "The chunk must have a user header!");
+    unsafe { ptr.write(type_identity) };
+}
+
+/// Checks whether the type identity of the chunk matches the `expected` one
+///
+/// Chunks without a `TypeIdentityHeader<H>` are reported with [`TypeIdentity::UNKNOWN`].
+pub(crate) fn check_type_identity<H>(
+    chunk_header: &ffi::ChunkHeader,
+    expected: TypeIdentity,
+) -> Result<(), IceoryxError> {
+    let user_header = chunk_header.user_header() as *const TypeIdentity;
+    let received = if !user_header.is_null()
+        && chunk_header.get_user_header_size() == size_of::<TypeIdentityHeader<H>>()
+    {
+        // SAFETY: the user header is large enough and the alignment of the chunk user header is sufficient
+        unsafe { user_header.read() }
+    } else {
+        TypeIdentity::UNKNOWN
+    };
+
+    if expected.is_compatible(&received) {
+        Ok(())
+    } else {
+        Err(IceoryxError::TypeMismatch { expected, received })
+    }
+}
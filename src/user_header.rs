@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::marker::ShmSend;
+use crate::type_identity::TypeIdentityHeader;
+use crate::IceoryxError;
+
+use std::ptr::NonNull;
+
+/// The default user header of publisher and subscriber which indicates that no user header is used
+///
+/// A subscriber with `NoUserHeader` also receives samples from publisher with a user header but
+/// cannot access it.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ShmSend)]
+pub struct NoUserHeader {}
+
+/// Checks whether `H` can be placed in the user header of a chunk
+pub(crate) fn check_user_header<H>() -> Result<(), IceoryxError> {
+    let align = std::mem::align_of::<H>();
+    if align > ffi::ChunkHeader::MAX_USER_HEADER_ALIGNMENT {
+        return Err(IceoryxError::InvalidUserHeaderAlignment {
+            requested: align,
+            max_supported: ffi::ChunkHeader::MAX_USER_HEADER_ALIGNMENT,
+        });
+    }
+    Ok(())
+}
+
+/// Gets a ptr to the user header of type `H` from the chunk header
+///
+/// Zero-sized types like [`NoUserHeader`] do not occupy memory in the chunk and a dangling but
+/// well aligned ptr is returned. With `type_identity`, the user header of the chunk is a
+/// `TypeIdentityHeader<H>` and the ptr to the contained `H` is returned.
+pub(crate) fn user_header_ptr<H>(chunk_header: &ffi::ChunkHeader, type_identity: bool) -> *mut H {
+    if std::mem::size_of::<H>() == 0 {
+        return NonNull::<H>::dangling().as_ptr();
+    }
+
+    let (expected_size, offset) = if type_identity {
+        (
+            std::mem::size_of::<TypeIdentityHeader<H>>(),
+            TypeIdentityHeader::<H>::USER_HEADER_OFFSET,
+        )
+    } else {
+        (std::mem::size_of::<H>(), 0)
+    };
+
+    let user_header = chunk_header.user_header();
+    debug_assert!(
+        !user_header.is_null() && chunk_header.get_user_header_size() == expected_size,
+        "The size of the user header must have been checked when the chunk was acquired!"
+    );
+    // SAFETY: the offset is within the user header
+    unsafe { user_header.cast::<u8>().add(offset) as *mut H }
+}
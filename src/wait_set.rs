@@ -0,0 +1,301 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: © Contributors to the iceoryx-rs project
+// SPDX-FileContributor: Mathias Kraus
+
+use crate::runtime::RuntimeResource;
+use crate::IceoryxError;
+use crate::NoUserHeader;
+use crate::SampleReceiver;
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// flags for the state of a `UserTrigger`; a trigger which happened before the detach is still reported
+const TRIGGER_IDLE: u8 = 0;
+const TRIGGER_TRIGGERED: u8 = 0b01;
+const TRIGGER_DETACHED: u8 = 0b10;
+
+struct WaitSetShared {
+    condition_variable: Box<ffi::ConditionVariable>,
+    _runtime_resource: RuntimeResource,
+    trigger_states: Vec<AtomicU8>,
+}
+
+enum AttachmentKind<S: ffi::SubscriberStrongRef> {
+    SampleReceiver(S),
+    UserTrigger,
+}
+
+struct Attachment<S: ffi::SubscriberStrongRef> {
+    notification_id: u64,
+    kind: AttachmentKind<S>,
+}
+
+/// Blocks a thread until one of the attached [`SampleReceiver`] or [`UserTrigger`] is ready
+///
+/// Each attachment is registered with a user defined notification ID which is returned by
+/// [`wait`](Self::wait) and [`timed_wait`](Self::timed_wait) when the attachment is ready.
+/// A `SampleReceiver` is ready as long as there are samples in its queue, a `UserTrigger` is ready
+/// once after each call to [`UserTrigger::trigger`].
+///
+/// # Example
+/// ```
+/// # use iceoryx_rs::Runtime;
+/// use iceoryx_rs::mt::WaitSet;
+/// use iceoryx_rs::SubscriberBuilder;
+/// # use ffi::RouDiEnvironment;
+/// #
+/// # use anyhow::{anyhow, Result};
+/// # fn main() -> Result<()> {
+/// # let _roudi = RouDiEnvironment::new();
+/// #
+/// # Runtime::init("wait_set");
+///
+/// let (subscriber, sample_receive_token) =
+///     SubscriberBuilder::<u32>::new("all", "glory", "hypnotoad").create_mt()?;
+/// let sample_receiver = subscriber.get_sample_receiver(sample_receive_token);
+///
+/// let wait_set = WaitSet::new();
+/// let _attachment = wait_set.attach_sample_receiver(&sample_receiver, 13)?;
+/// let trigger = wait_set.attach_user_trigger(42)?;
+///
+/// trigger.trigger();
+/// assert_eq!(wait_set.wait(), vec![42]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct WaitSet<S: ffi::SubscriberStrongRef> {
+    shared: Arc<WaitSetShared>,
+    attachments: RefCell<Vec<Option<Attachment<S>>>>,
+}
+
+impl<S: ffi::SubscriberStrongRef> WaitSet<S> {
+    /// The maximum number of attachments of a single `WaitSet`
+    pub const CAPACITY: usize = 128;
+
+    /// Creates a new `WaitSet`
+    ///
+    /// # Panics
+    ///
+    /// [`Runtime::init`](crate::Runtime::init) must have been called otherwise this method will panic.
+    pub fn new() -> Self {
+        let trigger_states = (0..Self::CAPACITY)
+            .map(|_| AtomicU8::new(TRIGGER_IDLE))
+            .collect();
+
+        Self {
+            shared: Arc::new(WaitSetShared {
+                condition_variable: ffi::ConditionVariable::new(),
+                _runtime_resource: RuntimeResource::new(),
+                trigger_states,
+            }),
+            attachments: RefCell::new((0..Self::CAPACITY).map(|_| None).collect()),
+        }
+    }
+
+    /// Attaches a [`SampleReceiver`] with the specified `notification_id`
+    ///
+    /// The `SampleReceiver` is detached when the returned [`SampleReceiverAttachment`] is dropped.
+    /// A `SampleReceiver` can only be attached to a single `WaitSet` at a time.
+    pub fn attach_sample_receiver<'w, 'r, T: ?Sized, H>(
+        &'w self,
+        sample_receiver: &'r SampleReceiver<T, S, H>,
+        notification_id: u64,
+    ) -> Result<SampleReceiverAttachment<'w, 'r, T, S, H>, IceoryxError> {
+        let mut attachments = self.attachments.borrow_mut();
+        let index = Self::acquire_slot(&self.shared, &mut attachments)?;
+
+        sample_receiver.attach_condition_variable(&self.shared.condition_variable, index as u64)?;
+
+        attachments[index] = Some(Attachment {
+            notification_id,
+            kind: AttachmentKind::SampleReceiver(sample_receiver.ffi_sub().clone()),
+        });
+
+        Ok(SampleReceiverAttachment {
+            wait_set: self,
+            sample_receiver,
+            index,
+        })
+    }
+
+    /// Attaches a new [`UserTrigger`] with the specified `notification_id`
+    ///
+    /// The `UserTrigger` is detached when it is dropped.
+    pub fn attach_user_trigger(&self, notification_id: u64) -> Result<UserTrigger, IceoryxError> {
+        let mut attachments = self.attachments.borrow_mut();
+        let index = Self::acquire_slot(&self.shared, &mut attachments)?;
+
+        self.shared.trigger_states[index].store(TRIGGER_IDLE, Ordering::Relaxed);
+        attachments[index] = Some(Attachment {
+            notification_id,
+            kind: AttachmentKind::UserTrigger,
+        });
+
+        Ok(UserTrigger {
+            shared: self.shared.clone(),
+            index,
+        })
+    }
+
+    /// Blocks until at least one attachment is ready and returns the notification IDs of all ready attachments
+    pub fn wait(&self) -> Vec<u64> {
+        self.wait_impl(None)
+    }
+
+    /// Blocks until at least one attachment is ready or the timeout elapsed
+    ///
+    /// Returns the notification IDs of all ready attachments or an empty `Vec` if the timeout elapsed.
+    pub fn timed_wait(&self, timeout: Duration) -> Vec<u64> {
+        self.wait_impl(Some(timeout))
+    }
+
+    /// Returns the notification IDs of all attached [`SampleReceiver`] which lost samples since the last call
+    ///
+    /// This can be used to detect overruns of the receiver queues after [`wait`](Self::wait) returned. The flag
+    /// is shared with and reset like [`SampleReceiver::has_lost_samples`].
+    pub fn lost_samples(&self) -> Vec<u64> {
+        self.attachments
+            .borrow()
+            .iter()
+            .flatten()
+            .filter_map(|attachment| match &attachment.kind {
+                AttachmentKind::SampleReceiver(ffi_sub) if ffi_sub.as_ref().has_lost_chunks() => {
+                    Some(attachment.notification_id)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn wait_impl(&self, timeout: Option<Duration>) -> Vec<u64> {
+        let entry_time = Instant::now();
+        loop {
+            let ready = self.collect_ready_notification_ids();
+            if !ready.is_empty() {
+                return ready;
+            }
+
+            let condition_variable = &self.shared.condition_variable;
+            match timeout {
+                None => {
+                    condition_variable.wait_for_notifications();
+                }
+                Some(timeout) => match timeout.checked_sub(entry_time.elapsed()) {
+                    Some(remaining_timeout) if !remaining_timeout.is_zero() => {
+                        condition_variable.timed_wait_for_notifications(remaining_timeout);
+                    }
+                    _ => return ready,
+                },
+            }
+        }
+    }
+
+    fn collect_ready_notification_ids(&self) -> Vec<u64> {
+        let mut attachments = self.attachments.borrow_mut();
+        let mut ready = Vec::new();
+        for (index, slot) in attachments.iter_mut().enumerate() {
+            let attachment = match slot {
+                Some(attachment) => attachment,
+                None => continue,
+            };
+
+            let is_ready = match &attachment.kind {
+                AttachmentKind::SampleReceiver(ffi_sub) => ffi_sub.as_ref().has_chunks(),
+                AttachmentKind::UserTrigger => {
+                    let state = self.shared.trigger_states[index]
+                        .fetch_and(!TRIGGER_TRIGGERED, Ordering::AcqRel);
+                    if state & TRIGGER_TRIGGERED != 0 {
+                        ready.push(attachment.notification_id);
+                    }
+                    if state & TRIGGER_DETACHED != 0 {
+                        *slot = None;
+                    }
+                    continue;
+                }
+            };
+
+            if is_ready {
+                ready.push(attachment.notification_id);
+            }
+        }
+        ready
+    }
+
+    fn acquire_slot(
+        shared: &WaitSetShared,
+        attachments: &mut [Option<Attachment<S>>],
+    ) -> Result<usize, IceoryxError> {
+        attachments
+            .iter_mut()
+            .enumerate()
+            .find_map(|(index, slot)| match slot {
+                None => Some(index),
+                Some(Attachment {
+                    kind: AttachmentKind::UserTrigger,
+                    ..
+                }) if shared.trigger_states[index].load(Ordering::Acquire) & TRIGGER_DETACHED
+                    != 0 =>
+                {
+                    *slot = None;
+                    Some(index)
+                }
+                _ => None,
+            })
+            .ok_or(IceoryxError::TooManyAttachments)
+    }
+}
+
+impl<S: ffi::SubscriberStrongRef> Default for WaitSet<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The attachment of a [`SampleReceiver`] to a [`WaitSet`]
+///
+/// The `SampleReceiver` is detached from the `WaitSet` when this is dropped.
+pub struct SampleReceiverAttachment<
+    'w,
+    'r,
+    T: ?Sized,
+    S: ffi::SubscriberStrongRef,
+    H = NoUserHeader,
+> {
+    wait_set: &'w WaitSet<S>,
+    sample_receiver: &'r SampleReceiver<T, S, H>,
+    index: usize,
+}
+
+impl<'w, 'r, T: ?Sized, S: ffi::SubscriberStrongRef, H> Drop
+    for SampleReceiverAttachment<'w, 'r, T, S, H>
+{
+    fn drop(&mut self) {
+        self.sample_receiver.detach_condition_variable();
+        self.wait_set.attachments.borrow_mut()[self.index] = None;
+    }
+}
+
+/// A trigger to wake up a [`WaitSet`] from any thread
+///
+/// The `UserTrigger` is detached from the `WaitSet` when it is dropped.
+pub struct UserTrigger {
+    shared: Arc<WaitSetShared>,
+    index: usize,
+}
+
+impl UserTrigger {
+    /// Wakes up the `WaitSet` and marks this trigger as ready
+    pub fn trigger(&self) {
+        self.shared.trigger_states[self.index].fetch_or(TRIGGER_TRIGGERED, Ordering::AcqRel);
+        self.shared.condition_variable.notify(self.index as u64);
+    }
+}
+
+impl Drop for UserTrigger {
+    fn drop(&mut self) {
+        self.shared.trigger_states[self.index].fetch_or(TRIGGER_DETACHED, Ordering::AcqRel);
+    }
+}